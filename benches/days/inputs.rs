@@ -0,0 +1,692 @@
+//! Puzzle inputs generated at a given size, one generator per day
+//!
+//! Each input follows the puzzle's format along with the guarantees its solution relies on,
+//! such as Day 25's wires splitting into 2 groups, and is the same for the same size on every run.
+//! What the size counts is up to the day, picked as what its solution scales with,
+//! such as Day 12's row length or Day 23's junction count.
+
+use std::{
+    fmt::Display,
+    ops::RangeInclusive,
+};
+
+/// A xorshift generator, so the inputs need no dependency and stay the same between runs
+struct Rng(u64);
+
+impl Rng {
+    fn new(size: usize) -> Self {
+        Self(0x9E37_79B9_7F4A_7C15 ^ size as u64)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn int(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().abs_diff(*range.start()) + 1;
+        range.start() + (self.next() % span) as i64
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    /// Whether an event that happens `1` in `n` times happened
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+fn grid(rng: &mut Rng, alphabet: &[u8], n_rows: usize, n_cols: usize) -> Vec<Vec<u8>> {
+    (0..n_rows)
+        .map(|_| (0..n_cols)
+            .map(|_| rng.pick(alphabet))
+            .collect()
+        )
+        .collect()
+}
+
+fn show_grid(grid: &[Vec<u8>]) -> String {
+    grid.iter()
+        .map(|row| String::from_utf8_lossy(row).into_owned())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn join<T: Display>(items: &[T], sep: &str) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(sep)
+}
+
+/// A distinct lowercase name for every index, such as `aab`
+fn name(index: usize, len: usize) -> String {
+    let mut name = vec![b'a'; len];
+    let mut rest = index;
+    for byte in name.iter_mut().rev() {
+        *byte += u8::try_from(rest % 26).unwrap_or_default();
+        rest /= 26;
+    }
+    String::from_utf8_lossy(&name).into_owned()
+}
+
+/// `size` lines of calibration values
+pub fn day1(size: usize) -> String {
+    const TOKENS: [&str; 20] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        "1", "2", "3", "4", "5", "6", "7", "8", "9", "x", "e",
+    ];
+    let mut rng = Rng::new(size);
+    (0..size)
+        .map(|_| {
+            let mut line = (0..rng.int(0..=8))
+                .map(|_| rng.pick(&TOKENS))
+                .collect::<String>();
+            // every line has a digit for part one
+            let at = rng.below(line.len() + 1);
+            line.insert(at, char::from(b'1' + rng.below(9) as u8));
+            line
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` games
+pub fn day2(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    (1..=size)
+        .map(|game| {
+            let sets = (0..rng.int(1..=6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    colors[..=rng.below(3)]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.int(1..=20)))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .collect::<Vec<String>>();
+            format!("Game {game}: {}", sets.join("; "))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A `size` by `size` schematic of numbers up to 3 digits long
+pub fn day3(size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+    let mut rng = Rng::new(size);
+    (0..size)
+        .map(|_| {
+            let mut row = String::new();
+            while row.len() < size {
+                match rng.below(6) {
+                    0 => {
+                        row.push_str(&rng.int(1..=999).to_string());
+                        row.push('.');
+                    },
+                    1 => row.push(char::from(rng.pick(SYMBOLS))),
+                    _ => row.push('.'),
+                }
+            }
+            row.truncate(size);
+            row
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` cards, every fourth winning nothing so the copies of part two stay bounded
+pub fn day4(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    (1..=size)
+        .map(|card| {
+            let mut numbers = (1..=99).collect::<Vec<i64>>();
+            rng.shuffle(&mut numbers);
+            let (winning, rest) = numbers.split_at(10);
+
+            let n_matches = if card % 4 == 0 { 0 } else { rng.below(4) };
+            let mut mine = winning[..n_matches].to_vec();
+            mine.extend(&rest[..25 - n_matches]);
+            rng.shuffle(&mut mine);
+
+            let show = |numbers: &[i64]| numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<String>>()
+                .join(" ");
+            format!("Card {card:>4}: {} | {}", show(winning), show(&mine))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` ranges in each map, which shuffle the numbers below `2^32` between them
+pub fn day5(size: usize) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light",
+        "light-to-temperature", "temperature-to-humidity", "humidity-to-location",
+    ];
+    const END: i64 = 1 << 32;
+    let mut rng = Rng::new(size);
+
+    let seeds = (0..10)
+        .map(|_| format!("{} {}", rng.int(0..=END / 2), rng.int(1..=END / 64)))
+        .collect::<Vec<String>>();
+
+    let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+    for map in MAPS {
+        let mut bounds = (1..size)
+            .map(|_| rng.int(1..=END - 1))
+            .collect::<Vec<i64>>();
+        bounds.extend([0, END]);
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let sources = bounds
+            .windows(2)
+            .map(|bounds| (bounds[0], bounds[1] - bounds[0]))
+            .collect::<Vec<(i64, i64)>>();
+        let mut order = sources.clone();
+        rng.shuffle(&mut order);
+
+        // the sources laid out again in the shuffled order, as their destinations
+        let mut destination = 0;
+        let mut ranges = Vec::new();
+        for (source, len) in order {
+            ranges.push(format!("{destination} {source} {len}"));
+            destination += len;
+        }
+        sections.push(format!("{map} map:\n{}", ranges.join("\n")));
+    }
+    sections.join("\n\n")
+}
+
+/// `size` races, each of which can be won
+pub fn day6(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+    for _ in 0..size {
+        let time = rng.int(10..=99);
+        times.push(format!("{time:>4}"));
+        distances.push(format!("{:>4}", rng.int(0..=time * time / 4 - 1)));
+    }
+    format!("Time:     {}\nDistance: {}", times.join(" "), distances.join(" "))
+}
+
+/// `size` hands
+pub fn day7(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    (0..size)
+        .map(|_| {
+            let hand = (0..5)
+                .map(|_| char::from(rng.pick(b"AKQJT98765432")))
+                .collect::<String>();
+            format!("{hand} {}", rng.int(1..=1000))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` nodes, every step leading further along so every start reaches an end
+pub fn day8(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    // part one's ends and part two's other start and end, in the order that every path follows
+    let mut names = vec!["AAA".to_string(), "BBA".to_string()];
+    names.extend((0..)
+        .map(|i| name(i, 3).to_uppercase())
+        .filter(|name| !["AAA", "BBA", "BBZ", "ZZZ"].contains(&name.as_str()))
+        .take(size.saturating_sub(4))
+    );
+    names.extend(["BBZ".to_string(), "ZZZ".to_string()]);
+    let n_nodes = names.len();
+
+    let instructions = (0..rng.int(50..=300))
+        .map(|_| rng.pick(&['L', 'R']))
+        .collect::<String>();
+    let nodes = names
+        .iter()
+        .enumerate()
+        .map(|(i, node)| {
+            let next = &names[(i + 1).min(n_nodes - 1)];
+            // only ever a few nodes ahead, so the ends take a while to reach
+            let ahead = &names[(i + 1 + rng.below(3)).min(n_nodes - 1)];
+            let (left, right) = if rng.one_in(2) { (next, ahead) } else { (ahead, next) };
+            format!("{node} = ({left}, {right})")
+        })
+        .collect::<Vec<String>>();
+    format!("{instructions}\n\n{}", nodes.join("\n"))
+}
+
+/// `size` sequences, each of a polynomial up to the 5th degree
+pub fn day9(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    (0..size)
+        .map(|_| {
+            let coefficients = (0..rng.int(1..=6))
+                .map(|_| rng.int(-9..=9))
+                .collect::<Vec<i64>>();
+            let values = (0..21)
+                .map(|x| coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                )
+                .collect::<Vec<i64>>();
+            join(&values, " ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A `size` by `size` field of pipes, with the start on a loop around most of it
+pub fn day10(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let mut grid = grid(&mut rng, b"|-LJ7F...", size, size);
+
+    let (top, left) = (rng.below(size / 4), rng.below(size / 4));
+    let (bottom, right) = (size - 1 - rng.below(size / 4), size - 1 - rng.below(size / 4));
+    let path = (left..=right)
+        .flat_map(|col| [(top, col), (bottom, col)])
+        .chain((top..=bottom).flat_map(|row| [(row, left), (row, right)]))
+        .collect::<Vec<(usize, usize)>>();
+    for &(row, col) in &path {
+        grid[row][col] = if row == top || row == bottom { b'-' } else { b'|' };
+    }
+    grid[top][left] = b'F';
+    grid[top][right] = b'7';
+    grid[bottom][left] = b'L';
+    grid[bottom][right] = b'J';
+
+    // the start is on a side of the loop, with only the loop connecting to it
+    let (row, col) = (top, left + 1 + rng.below(right - left - 1));
+    grid[row][col] = b'S';
+    if row > 0 {
+        grid[row - 1][col] = b'.';
+    }
+    grid[row + 1][col] = b'.';
+    show_grid(&grid)
+}
+
+/// A `size` by `size` image, with every tenth row and column empty
+pub fn day11(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let mut grid = grid(&mut rng, b".", size, size);
+    for (i, row) in grid
+        .iter_mut()
+        .enumerate()
+        .filter(|(i, _)| i % 10 != 0)
+    {
+        for (j, cell) in row.iter_mut().enumerate() {
+            if j % 10 != 0 && rng.one_in(40) {
+                *cell = b'#';
+            }
+        }
+        // the rows and columns are emptied apart from one another
+        if i % 10 == 5 {
+            row[size / 2] = b'#';
+        }
+    }
+    show_grid(&grid)
+}
+
+/// 200 rows of `size` springs, up to 8 of which are unknown
+pub fn day12(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    (0..200)
+        .map(|_| {
+            let mut springs = Vec::new();
+            let mut groups = Vec::new();
+            while springs.len() < size {
+                springs.extend((0..rng.int(1..=3)).map(|_| b'.'));
+                let group = rng.int(1..=5) as usize;
+                if springs.len() + group > size {
+                    break;
+                }
+                springs.extend((0..group).map(|_| b'#'));
+                groups.push(group);
+            }
+            springs.resize(size, b'.');
+            for _ in 0..8 {
+                let at = rng.below(size);
+                springs[at] = b'?';
+            }
+            format!("{} {}", String::from_utf8_lossy(&springs), join(&groups, ","))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` patterns, each reflected across a line with a smudge in half of them
+pub fn day13(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    (0..size)
+        .map(|_| {
+            let (n_rows, n_cols) = (rng.int(5..=17) as usize, rng.int(5..=17) as usize);
+            let mut pattern = grid(&mut rng, b".#", n_rows, n_cols);
+            // reflected across a row, and across a column by transposing it
+            let line = 1 + rng.below(n_rows - 1);
+            for i in 0..line.min(n_rows - line) {
+                pattern[line + i] = pattern[line - 1 - i].clone();
+            }
+            if rng.one_in(2) {
+                let at = rng.below(line);
+                let col = rng.below(n_cols);
+                pattern[at][col] = if pattern[at][col] == b'#' { b'.' } else { b'#' };
+            }
+            if rng.one_in(2) {
+                pattern = (0..n_cols)
+                    .map(|col| pattern
+                        .iter()
+                        .map(|row| row[col])
+                        .collect()
+                    )
+                    .collect();
+            }
+            show_grid(&pattern)
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+/// A `size` by `size` platform
+pub fn day14(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    show_grid(&grid(&mut rng, b"O#.....", size, size))
+}
+
+/// `size` steps
+pub fn day15(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    (0..size)
+        .map(|_| {
+            let label = (0..rng.int(2..=6))
+                .map(|_| char::from(rng.pick(b"abcdefghij")))
+                .collect::<String>();
+            if rng.one_in(3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.int(1..=9))
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// A `size` by `size` contraption
+pub fn day16(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    show_grid(&grid(&mut rng, b"..............|-/\\", size, size))
+}
+
+/// A `size` by `size` city
+pub fn day17(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    show_grid(&grid(&mut rng, b"123456789", size, size))
+}
+
+/// `size` moves in each plan, which dig out a staircase
+pub fn day18(size: usize) -> String {
+    /// A staircase down and to the right and back, mirrored at random,
+    /// as the directions and distances of its `2 * steps + 2` moves
+    fn staircase(rng: &mut Rng, steps: usize, scale: i64) -> Vec<(u8, i64)> {
+        let (right, left) = if rng.one_in(2) { (b'R', b'L') } else { (b'L', b'R') };
+        let (down, up) = if rng.one_in(2) { (b'D', b'U') } else { (b'U', b'D') };
+        let mut moves = Vec::new();
+        for _ in 0..steps {
+            moves.push((right, rng.int(1..=9) * scale));
+            moves.push((down, rng.int(1..=9) * scale));
+        }
+        let width = moves.iter().step_by(2).map(|&(_, distance)| distance).sum();
+        let height = moves.iter().skip(1).step_by(2).map(|&(_, distance)| distance).sum();
+        moves.extend([(left, width), (up, height)]);
+        moves
+    }
+
+    let mut rng = Rng::new(size);
+    let steps = size.saturating_sub(2).max(2) / 2;
+    let part_one = staircase(&mut rng, steps, 1);
+    let part_two = staircase(&mut rng, steps, 1000);
+    part_one
+        .iter()
+        .zip(&part_two)
+        .map(|(&(dir, distance), &(hex_dir, hex_distance))| format!(
+            "{} {distance} (#{hex_distance:05x}{})",
+            char::from(dir),
+            "RDLU".bytes().position(|d| d == hex_dir).unwrap_or_default(),
+        ))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` workflows, each sent parts from just one other, along with `size` parts
+pub fn day19(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let names = (0..size)
+        .map(|i| if i == 0 { "in".to_string() } else { name(i, 3) })
+        .collect::<Vec<String>>();
+
+    let mut next = 1;
+    let mut workflows = Vec::new();
+    for workflow in &names {
+        let mut target = |rng: &mut Rng| if next < size && !rng.one_in(3) {
+            next += 1;
+            names[next - 1].clone()
+        } else {
+            rng.pick(&["A", "R"]).to_string()
+        };
+        let mut rules = (0..rng.int(1..=3))
+            .map(|_| format!(
+                "{}{}{}:{}",
+                char::from(rng.pick(b"xmas")),
+                char::from(rng.pick(b"<>")),
+                rng.int(1..=4000),
+                target(&mut rng),
+            ))
+            .collect::<Vec<String>>();
+        rules.push(target(&mut rng));
+        workflows.push(format!("{workflow}{{{}}}", rules.join(",")));
+    }
+
+    let parts = (0..size)
+        .map(|_| format!(
+            "{{x={},m={},a={},s={}}}",
+            rng.int(1..=4000),
+            rng.int(1..=4000),
+            rng.int(1..=4000),
+            rng.int(1..=4000),
+        ))
+        .collect::<Vec<String>>();
+    format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+}
+
+/// 4 counters of flip-flops, the largest `size` bits wide,
+/// each turning its inverter on every so many presses,
+/// which together feed the conjunction before `rx` like the puzzle's
+pub fn day20(size: usize) -> String {
+    let mut starts = Vec::new();
+    let mut modules = Vec::new();
+    for counter in 0..4 {
+        let n_bits = size.saturating_sub(counter).max(1);
+        let bits = (0..n_bits)
+            .map(|bit| name(counter * 100 + bit, 2))
+            .collect::<Vec<String>>();
+        let inverter = name(600 + counter, 2);
+        starts.push(bits[0].clone());
+        for (bit, flip_flop) in bits.iter().enumerate() {
+            modules.push(format!("%{flip_flop} -> {}", bits.get(bit + 1).unwrap_or(&inverter)));
+        }
+        modules.push(format!("&{inverter} -> zz"));
+    }
+    modules.push("&zz -> rx".to_string());
+    modules.insert(0, format!("broadcaster -> {}", starts.join(", ")));
+    modules.join("\n")
+}
+
+/// A `size` by `size` garden, clear along the edges and the start's row and column like the puzzle's
+///
+/// The size should leave the same remainder of part two's steps as the puzzle's,
+/// half the size rounded down, such as `11`, `33`, `67` and `131`
+pub fn day21(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let mut grid = grid(&mut rng, b".......#", size, size);
+    let middle = size / 2;
+    for i in 0..size {
+        for (row, col) in [(0, i), (size - 1, i), (i, 0), (i, size - 1), (middle, i), (i, middle)] {
+            grid[row][col] = b'.';
+        }
+    }
+    grid[middle][middle] = b'S';
+    show_grid(&grid)
+}
+
+/// `size` bricks stacked in a 10 by 10 column, none of them overlapping
+pub fn day22(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let mut z = 1;
+    (0..size)
+        .map(|_| {
+            let start = [rng.int(0..=9), rng.int(0..=9), z];
+            let mut end = start;
+            let axis = rng.below(3);
+            end[axis] = (end[axis] + rng.int(0..=3)).min(if axis == 2 { i64::MAX } else { 9 });
+            z = end[2] + 1;
+            format!("{}~{}", join(&start, ","), join(&end, ","))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// A maze of about `size` junctions, laid out in a square lattice like the puzzle's
+/// with its slopes leading down and to the right
+pub fn day23(size: usize) -> String {
+    const SPACING: usize = 8;
+    let mut rng = Rng::new(size);
+    let n_junctions = (1..).find(|n| n * n >= size).unwrap_or(1).max(2);
+    let last = 2 + (n_junctions - 1) * SPACING;
+    let n_cells = last + 3;
+
+    let mut grid = vec![vec![b'#'; n_cells]; n_cells];
+    // from the start, and to the end
+    for (row, col) in [(0, 1), (1, 1), (2, 1), (last, last + 1), (last + 1, last + 1), (last + 2, last + 1)] {
+        grid[row][col] = b'.';
+    }
+    for i in 0..n_junctions {
+        for j in 0..n_junctions {
+            let (row, col) = (2 + i * SPACING, 2 + j * SPACING);
+            grid[row][col] = b'.';
+            // the corridors right and down to the next junctions, though not every one
+            if j + 1 < n_junctions && !rng.one_in(8) {
+                grid[row][col + 1..col + SPACING].fill(b'.');
+                grid[row][col + 1] = b'>';
+                grid[row][col + SPACING - 1] = b'>';
+            }
+            if i + 1 < n_junctions && !rng.one_in(8) {
+                for step in 1..SPACING {
+                    grid[row + step][col] = b'.';
+                }
+                grid[row + 1][col] = b'v';
+                grid[row + SPACING - 1][col] = b'v';
+            }
+        }
+    }
+    // the lattice's outer corridors are always there, so the end can be reached
+    for k in 0..last {
+        for (row, col) in [(2, 2 + k), (2 + k, last)] {
+            if grid[row][col] == b'#' {
+                grid[row][col] = b'.';
+            }
+        }
+    }
+    show_grid(&grid)
+}
+
+/// `size` hailstones, all of which a rock thrown from somewhere hits
+pub fn day24(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let start = [
+        rng.int(250_000_000_000_000..=350_000_000_000_000),
+        rng.int(250_000_000_000_000..=350_000_000_000_000),
+        rng.int(250_000_000_000_000..=350_000_000_000_000),
+    ];
+    let velocity = [rng.int(-100..=100), rng.int(-100..=100), rng.int(-100..=100)];
+
+    let mut times = (0..size)
+        .map(|_| rng.int(1_000_000_000..=500_000_000_000))
+        .collect::<Vec<i64>>();
+    times.sort_unstable();
+    times.dedup();
+    times
+        .into_iter()
+        .map(|time| {
+            let mut position = [0; 3];
+            let mut hail_velocity = [0; 3];
+            for axis in 0..3 {
+                hail_velocity[axis] = rng.int(-300..=300);
+                position[axis] = start[axis] + time * (velocity[axis] - hail_velocity[axis]);
+            }
+            format!("{} @ {}", join(&position, ", "), join(&hail_velocity, ", "))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `size` components in 2 groups joined by 3 wires, the ones to cut,
+/// each component wired to at least 5 others in its group
+pub fn day25(size: usize) -> String {
+    let mut rng = Rng::new(size);
+    let names = (0..size)
+        .map(|i| name(i, 3))
+        .collect::<Vec<String>>();
+    let groups = [0..size / 2, size / 2..size];
+
+    let mut connections = names
+        .iter()
+        .map(|name| (name.clone(), Vec::new()))
+        .collect::<Vec<(String, Vec<String>)>>();
+    for group in &groups {
+        for i in group.clone().skip(1) {
+            let mut earlier = group
+                .clone()
+                .take_while(|&j| j < i)
+                .collect::<Vec<usize>>();
+            rng.shuffle(&mut earlier);
+            for &j in earlier.iter().take(5) {
+                connections[i].1.push(names[j].clone());
+            }
+        }
+    }
+    for _ in 0..3 {
+        let (a, b) = (
+            groups[0].start + rng.below(groups[0].len()),
+            groups[1].start + rng.below(groups[1].len()),
+        );
+        if !connections[b].1.contains(&names[a]) {
+            connections[b].1.push(names[a].clone());
+        }
+    }
+    connections
+        .into_iter()
+        .filter(|(_, connected)| !connected.is_empty())
+        .map(|(component, connected)| format!("{component}: {}", connected.join(" ")))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,151 @@
+//! Benchmarks of every day's parsing and parts, on the puzzle input when there is one
+//! and on inputs generated at several sizes, see [`inputs`], to show how each solution scales
+//!
+//!     cargo bench
+//!     cargo bench -- day12
+//!     cargo bench -- day23/part_two
+//!
+//! Each day is a group, with a benchmark per stage (`parse`, `part_one` and `part_two`)
+//! and input (`puzzle`, or the generated input's size)
+//!
+//! `cargo test --all-targets` runs each benchmark once as a smoke test,
+//! and only on the smallest generated size
+
+mod inputs;
+
+use std::{
+    env,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+use criterion::{
+    criterion_group, criterion_main,
+    BenchmarkId, Criterion, SamplingMode, Throughput,
+};
+use aoc_2023::{days::*, Solution, Parse, Part, InputVariant, read_input};
+
+/// Above which a single run is slow enough to take fewer, flat samples of
+const SLOW_RUN: Duration = Duration::from_millis(20);
+
+const BOTH_PARTS: &[Part] = &[Part::One, Part::Two];
+
+/// Benchmarks the day's parsing and parts on its puzzle input and an input generated at each size,
+/// or just the smallest when not run by `cargo bench`
+///
+/// # Panics
+///
+/// If a generated input makes the solution panic
+fn bench_day<D: Solution + Parse>(
+    c: &mut Criterion,
+    day: u8,
+    solution: &D,
+    generate: fn(usize) -> String,
+    sizes: &[usize],
+    parts: &[Part],
+) {
+    // criterion only benchmarks when passed `--bench`, and otherwise runs each benchmark once
+    let sizes = if env::args().any(|arg| arg == "--bench") {
+        sizes
+    } else {
+        &sizes[..1]
+    };
+    let mut inputs = read_input(day, &InputVariant::Puzzle)
+        .ok()
+        .map(|input| ("puzzle".to_string(), input))
+        .into_iter()
+        .collect::<Vec<(String, String)>>();
+    inputs.extend(sizes
+        .iter()
+        .map(|&size| (size.to_string(), generate(size)))
+    );
+
+    let mut group = c.benchmark_group(format!("day{day}"));
+    for (label, input) in &inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        configure_for(&mut group, || parse(solution, input));
+        group.bench_with_input(BenchmarkId::new("parse", label), input.as_str(), |b, input| {
+            b.iter(|| parse(solution, black_box(input)));
+        });
+        for &part in parts {
+            let solve = |input: &str| match part {
+                Part::One => solution.part_one(input),
+                Part::Two => solution.part_two(input),
+            };
+            configure_for(&mut group, || solve(input));
+            group.bench_with_input(BenchmarkId::new(part.span_name(), label), input.as_str(), |b, input| {
+                b.iter(|| solve(black_box(input)));
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Times a single run of the benchmark, which also makes sure it doesn't panic,
+/// to take fewer and flat samples of it over a longer time if it's slow,
+/// rather than criterion warning that it couldn't take the usual 100 in time
+fn configure_for<R>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    run: impl FnOnce() -> R,
+) {
+    let start = Instant::now();
+    black_box(run());
+    let elapsed = start.elapsed();
+
+    if elapsed > SLOW_RUN {
+        group
+            .sample_size(10)
+            .sampling_mode(SamplingMode::Flat)
+            .measurement_time((elapsed * 15).max(Duration::from_secs(5)));
+    } else {
+        group
+            .sample_size(100)
+            .sampling_mode(SamplingMode::Auto)
+            .measurement_time(Duration::from_secs(5));
+    }
+}
+
+/// # Panics
+///
+/// If the input is malformed
+fn parse<D: Parse>(solution: &D, input: &str) {
+    if let Err(err) = black_box(solution.parse_input(input)) {
+        panic!("{err}");
+    }
+}
+
+fn days(c: &mut Criterion) {
+    bench_day(c, 1, &day1::Day1, inputs::day1, &[100, 1000, 10_000], BOTH_PARTS);
+    bench_day(c, 2, &day2::Day2, inputs::day2, &[100, 1000, 10_000], BOTH_PARTS);
+    bench_day(c, 3, &day3::Day3, inputs::day3, &[20, 140, 500], BOTH_PARTS);
+    bench_day(c, 4, &day4::Day4, inputs::day4, &[100, 1000, 10_000], BOTH_PARTS);
+    bench_day(c, 5, &day5::Day5, inputs::day5, &[10, 100, 1000], BOTH_PARTS);
+    bench_day(c, 6, &day6::Day6, inputs::day6, &[1, 2, 4], BOTH_PARTS);
+    bench_day(c, 7, &day7::Day7, inputs::day7, &[100, 1000, 10_000], BOTH_PARTS);
+    bench_day(c, 8, &day8::Day8, inputs::day8, &[100, 1000, 10_000], BOTH_PARTS);
+    bench_day(c, 9, &day9::Day9, inputs::day9, &[100, 1000, 10_000], BOTH_PARTS);
+    bench_day(c, 10, &day10::Day10, inputs::day10, &[20, 140, 500], BOTH_PARTS);
+    bench_day(c, 11, &day11::Day11::default(), inputs::day11, &[20, 140, 500], BOTH_PARTS);
+    bench_day(c, 12, &day12::Day12, inputs::day12, &[20, 40, 80, 160], BOTH_PARTS);
+    bench_day(c, 13, &day13::Day13, inputs::day13, &[10, 100, 1000], BOTH_PARTS);
+    bench_day(c, 14, &day14::Day14::default(), inputs::day14, &[20, 50, 100, 200], BOTH_PARTS);
+    bench_day(c, 15, &day15::Day15, inputs::day15, &[100, 1000, 10_000], BOTH_PARTS);
+    bench_day(c, 16, &day16::Day16, inputs::day16, &[20, 50, 110, 200], BOTH_PARTS);
+    bench_day(c, 17, &day17::Day17, inputs::day17, &[20, 50, 100, 141], BOTH_PARTS);
+    bench_day(c, 18, &day18::Day18, inputs::day18, &[10, 100, 1000], BOTH_PARTS);
+    bench_day(c, 19, &day19::Day19, inputs::day19, &[10, 100, 1000], BOTH_PARTS);
+    bench_day(c, 20, &day20::Day20, inputs::day20, &[4, 8, 12], BOTH_PARTS);
+    bench_day(c, 21, &day21::Day21::default(), inputs::day21, &[11, 33, 67, 131], BOTH_PARTS);
+    bench_day(c, 22, &day22::Day22, inputs::day22, &[100, 1000, 5000], BOTH_PARTS);
+    bench_day(c, 23, &day23::Day23, inputs::day23, &[9, 16, 25, 36], BOTH_PARTS);
+    bench_day(c, 24, &day24::Day24::default(), inputs::day24, &[10, 100, 300], BOTH_PARTS);
+    // part two is the last star, which has no puzzle
+    bench_day(c, 25, &day25::Day25, inputs::day25, &[100, 500, 1500], &[Part::One]);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().warm_up_time(Duration::from_secs(1));
+    targets = days
+}
+criterion_main!(benches);
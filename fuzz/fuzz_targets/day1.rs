@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day1::Day1;
+
+aoc_2023_fuzz::fuzz_day!(Day1, aoc_2023_fuzz::inputs::day1);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day10::Day10;
+
+aoc_2023_fuzz::fuzz_day!(Day10, aoc_2023_fuzz::inputs::day10);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day11::Day11;
+
+aoc_2023_fuzz::fuzz_day!(Day11::default(), aoc_2023_fuzz::inputs::day11);
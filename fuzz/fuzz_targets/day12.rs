@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day12::Day12;
+
+aoc_2023_fuzz::fuzz_day!(Day12, aoc_2023_fuzz::inputs::day12);
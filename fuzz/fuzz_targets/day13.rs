@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day13::Day13;
+
+aoc_2023_fuzz::fuzz_day!(Day13, aoc_2023_fuzz::inputs::day13);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day14::Day14;
+
+aoc_2023_fuzz::fuzz_day!(Day14::default(), aoc_2023_fuzz::inputs::day14);
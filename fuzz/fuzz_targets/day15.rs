@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day15::Day15;
+
+aoc_2023_fuzz::fuzz_day!(Day15, aoc_2023_fuzz::inputs::day15);
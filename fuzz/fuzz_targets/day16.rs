@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day16::Day16;
+
+aoc_2023_fuzz::fuzz_day!(Day16, aoc_2023_fuzz::inputs::day16);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day17::Day17;
+
+aoc_2023_fuzz::fuzz_day!(Day17, aoc_2023_fuzz::inputs::day17);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day18::Day18;
+
+aoc_2023_fuzz::fuzz_day!(Day18, aoc_2023_fuzz::inputs::day18);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day19::Day19;
+
+aoc_2023_fuzz::fuzz_day!(Day19, aoc_2023_fuzz::inputs::day19);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day2::Day2;
+
+aoc_2023_fuzz::fuzz_day!(Day2, aoc_2023_fuzz::inputs::day2);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day20::Day20;
+
+aoc_2023_fuzz::fuzz_day!(Day20, aoc_2023_fuzz::inputs::day20);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day21::Day21;
+
+aoc_2023_fuzz::fuzz_day!(Day21::default(), aoc_2023_fuzz::inputs::day21);
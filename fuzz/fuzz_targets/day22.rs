@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day22::Day22;
+
+aoc_2023_fuzz::fuzz_day!(Day22, aoc_2023_fuzz::inputs::day22);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day23::Day23;
+
+aoc_2023_fuzz::fuzz_day!(Day23, aoc_2023_fuzz::inputs::day23);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day24::Day24;
+
+aoc_2023_fuzz::fuzz_day!(Day24::default(), aoc_2023_fuzz::inputs::day24);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day25::Day25;
+
+aoc_2023_fuzz::fuzz_day!(Day25, aoc_2023_fuzz::inputs::day25, part_one);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day3::Day3;
+
+aoc_2023_fuzz::fuzz_day!(Day3, aoc_2023_fuzz::inputs::day3);
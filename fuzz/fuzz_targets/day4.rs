@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day4::Day4;
+
+aoc_2023_fuzz::fuzz_day!(Day4, aoc_2023_fuzz::inputs::day4);
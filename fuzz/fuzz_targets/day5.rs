@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day5::Day5;
+
+aoc_2023_fuzz::fuzz_day!(Day5, aoc_2023_fuzz::inputs::day5);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day6::Day6;
+
+aoc_2023_fuzz::fuzz_day!(Day6, aoc_2023_fuzz::inputs::day6);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day7::Day7;
+
+aoc_2023_fuzz::fuzz_day!(Day7, aoc_2023_fuzz::inputs::day7);
@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day8::Day8;
+
+aoc_2023_fuzz::fuzz_day!(Day8, aoc_2023_fuzz::inputs::day8);
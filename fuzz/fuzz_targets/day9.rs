@@ -0,0 +1,5 @@
+#![no_main]
+
+use aoc_2023::days::day9::Day9;
+
+aoc_2023_fuzz::fuzz_day!(Day9, aoc_2023_fuzz::inputs::day9);
@@ -0,0 +1,462 @@
+//! Well-formed puzzle inputs generated from fuzzed bytes, one generator per day
+//!
+//! The inputs are kept small, so a slow run points at a hang rather than a large input
+
+use std::{
+    fmt::Display,
+    ops::RangeInclusive,
+};
+use crate::{Result, Unstructured};
+
+fn pick<T: Copy>(u: &mut Unstructured, items: &[T]) -> Result<T> {
+    u.choose(items).copied()
+}
+
+/// Random bytes of the alphabet, where repeating a byte makes it more likely
+fn word(u: &mut Unstructured, alphabet: &[u8], len: RangeInclusive<usize>) -> Result<String> {
+    let len = u.int_in_range(len)?;
+    (0..len)
+        .map(|_| pick(u, alphabet).map(char::from))
+        .collect()
+}
+
+fn grid(u: &mut Unstructured, alphabet: &[u8], rows: RangeInclusive<usize>, cols: RangeInclusive<usize>) -> Result<Vec<Vec<u8>>> {
+    let n_rows = u.int_in_range(rows)?;
+    let n_cols = u.int_in_range(cols)?;
+    (0..n_rows)
+        .map(|_| (0..n_cols)
+            .map(|_| pick(u, alphabet))
+            .collect()
+        )
+        .collect()
+}
+
+fn show_grid(grid: &[Vec<u8>]) -> String {
+    grid.iter()
+        .map(|row| String::from_utf8_lossy(row).into_owned())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// `count` lines made by `line`, given the index of each
+fn lines<F>(u: &mut Unstructured, count: RangeInclusive<usize>, mut line: F) -> Result<String>
+where
+    F: FnMut(&mut Unstructured, usize) -> Result<String>,
+{
+    let count = u.int_in_range(count)?;
+    (0..count)
+        .map(|i| line(u, i))
+        .collect::<Result<Vec<String>>>()
+        .map(|lines| lines.join("\n"))
+}
+
+fn join<T: Display>(items: &[T], sep: &str) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(sep)
+}
+
+/// A distinct lowercase name for every index, such as `aab`
+fn name(index: usize, len: usize) -> String {
+    let mut name = vec![b'a'; len];
+    let mut rest = index;
+    for byte in name.iter_mut().rev() {
+        *byte += u8::try_from(rest % 26).unwrap_or_default();
+        rest /= 26;
+    }
+    String::from_utf8_lossy(&name).into_owned()
+}
+
+pub fn day1(u: &mut Unstructured) -> Result<String> {
+    const TOKENS: [&str; 20] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        "1", "2", "3", "4", "5", "6", "7", "8", "9", "x", "e",
+    ];
+    lines(u, 1..=20, |u, _| {
+        let n_tokens = u.int_in_range(0..=6)?;
+        let mut line = (0..n_tokens)
+            .map(|_| pick(u, &TOKENS))
+            .collect::<Result<String>>()?;
+        // every line has a digit for part one
+        let at = u.int_in_range(0..=line.len())?;
+        line.insert(at, char::from(u.int_in_range(b'1'..=b'9')?));
+        Ok(line)
+    })
+}
+
+pub fn day2(u: &mut Unstructured) -> Result<String> {
+    lines(u, 1..=10, |u, i| {
+        let sets = lines(u, 1..=4, |u, _| {
+            let mut colors = vec!["red", "green", "blue"];
+            let n_colors = u.int_in_range(1..=3)?;
+            let mut cubes = Vec::new();
+            for _ in 0..n_colors {
+                let color = colors.remove(u.choose_index(colors.len())?);
+                cubes.push(format!("{} {color}", u.int_in_range(1..=20)?));
+            }
+            Ok(cubes.join(", "))
+        })?;
+        Ok(format!("Game {}: {}", i + 1, sets.replace('\n', "; ")))
+    })
+}
+
+pub fn day3(u: &mut Unstructured) -> Result<String> {
+    grid(u, b"......0123456789*#+$/@=%&-", 1..=20, 1..=20)
+        .map(|grid| show_grid(&grid))
+}
+
+pub fn day4(u: &mut Unstructured) -> Result<String> {
+    let numbers = |u: &mut Unstructured, count: RangeInclusive<usize>| -> Result<String> {
+        let count = u.int_in_range(count)?;
+        (0..count)
+            .map(|_| u.int_in_range(1..=99).map(|n: u8| format!("{n:>2}")))
+            .collect::<Result<Vec<String>>>()
+            .map(|numbers| numbers.join(" "))
+    };
+    lines(u, 1..=15, |u, i| Ok(format!(
+        "Card {:>3}: {} | {}",
+        i + 1,
+        numbers(u, 1..=10)?,
+        numbers(u, 1..=25)?,
+    )))
+}
+
+pub fn day5(u: &mut Unstructured) -> Result<String> {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light",
+        "light-to-temperature", "temperature-to-humidity", "humidity-to-location",
+    ];
+    let n_seeds = u.int_in_range(1..=5)?;
+    let seeds = (0..n_seeds)
+        .map(|_| Ok(format!(
+            "{} {}",
+            u.int_in_range(0..=u32::MAX)?,
+            u.int_in_range(1..=1u32 << 28)?,
+        )))
+        .collect::<Result<Vec<String>>>()?;
+
+    let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+    for map in MAPS {
+        let ranges = lines(u, 1..=5, |u, _| Ok(format!(
+            "{} {} {}",
+            u.int_in_range(0..=u32::MAX)?,
+            u.int_in_range(0..=u32::MAX)?,
+            u.int_in_range(1..=1u32 << 30)?,
+        )))?;
+        sections.push(format!("{map} map:\n{ranges}"));
+    }
+    Ok(sections.join("\n\n"))
+}
+
+pub fn day6(u: &mut Unstructured) -> Result<String> {
+    let n_races = u.int_in_range(1..=4)?;
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+    for _ in 0..n_races {
+        times.push(format!("{:>4}", u.int_in_range(1..=99u32)?));
+        distances.push(format!("{:>4}", u.int_in_range(0..=2500u32)?));
+    }
+    Ok(format!("Time:     {}\nDistance: {}", times.join(" "), distances.join(" ")))
+}
+
+pub fn day7(u: &mut Unstructured) -> Result<String> {
+    lines(u, 1..=20, |u, _| Ok(format!(
+        "{} {}",
+        word(u, b"AKQJT98765432", 5..=5)?,
+        u.int_in_range(1..=1000u32)?,
+    )))
+}
+
+pub fn day8(u: &mut Unstructured) -> Result<String> {
+    // part one's ends and part two's other start and end, in the order that every path follows
+    let n_nodes = u.int_in_range(4..=10)?;
+    let mut names = vec!["AAA".to_string(), "BBA".to_string()];
+    names.extend((2..n_nodes - 2).map(|i| name(i, 3).to_uppercase()));
+    names.extend(["BBZ".to_string(), "ZZZ".to_string()]);
+
+    // every step leads further along, so every start reaches an end
+    let instructions = word(u, b"LR", 1..=10)?;
+    let mut nodes = Vec::new();
+    for (i, node) in names.iter().enumerate() {
+        let next = &names[(i + 1).min(n_nodes - 1)];
+        let ahead = u.choose(&names[(i + 1).min(n_nodes - 1)..])?;
+        let (left, right) = if u.arbitrary()? { (next, ahead) } else { (ahead, next) };
+        nodes.push(format!("{node} = ({left}, {right})"));
+    }
+    Ok(format!("{instructions}\n\n{}", nodes.join("\n")))
+}
+
+pub fn day9(u: &mut Unstructured) -> Result<String> {
+    lines(u, 1..=10, |u, _| {
+        let len = u.int_in_range(2..=21)?;
+        (0..len)
+            .map(|_| u.int_in_range(-1000..=1000i64))
+            .collect::<Result<Vec<i64>>>()
+            .map(|values| join(&values, " "))
+    })
+}
+
+pub fn day10(u: &mut Unstructured) -> Result<String> {
+    let mut grid = grid(u, b"|-LJ7F...", 3..=15, 3..=15)?;
+    let (n_rows, n_cols) = (grid.len(), grid[0].len());
+
+    // a rectangular loop, for the start to be on
+    let top = u.int_in_range(0..=n_rows - 2)?;
+    let left = u.int_in_range(0..=n_cols - 2)?;
+    let bottom = u.int_in_range(top + 1..=n_rows - 1)?;
+    let right = u.int_in_range(left + 1..=n_cols - 1)?;
+    let path = (left..=right)
+        .flat_map(|col| [(top, col), (bottom, col)])
+        .chain((top..=bottom).flat_map(|row| [(row, left), (row, right)]))
+        .collect::<Vec<(usize, usize)>>();
+    for &(row, col) in &path {
+        grid[row][col] = if row == top || row == bottom { b'-' } else { b'|' };
+    }
+    grid[top][left] = b'F';
+    grid[top][right] = b'7';
+    grid[bottom][left] = b'L';
+    grid[bottom][right] = b'J';
+
+    let (row, col) = *u.choose(&path)?;
+    grid[row][col] = b'S';
+    Ok(show_grid(&grid))
+}
+
+pub fn day11(u: &mut Unstructured) -> Result<String> {
+    grid(u, b".....#", 1..=15, 1..=15)
+        .map(|grid| show_grid(&grid))
+}
+
+pub fn day12(u: &mut Unstructured) -> Result<String> {
+    lines(u, 1..=10, |u, _| {
+        let n_groups = u.int_in_range(1..=4)?;
+        let groups = (0..n_groups)
+            .map(|_| u.int_in_range(1..=4u8))
+            .collect::<Result<Vec<u8>>>()?;
+        Ok(format!("{} {}", word(u, b".#??", 1..=12)?, join(&groups, ",")))
+    })
+}
+
+pub fn day13(u: &mut Unstructured) -> Result<String> {
+    let n_patterns = u.int_in_range(1..=4)?;
+    (0..n_patterns)
+        .map(|_| grid(u, b".#", 1..=9, 1..=9).map(|grid| show_grid(&grid)))
+        .collect::<Result<Vec<String>>>()
+        .map(|patterns| patterns.join("\n\n"))
+}
+
+pub fn day14(u: &mut Unstructured) -> Result<String> {
+    grid(u, b"O#...", 1..=12, 1..=12)
+        .map(|grid| show_grid(&grid))
+}
+
+pub fn day15(u: &mut Unstructured) -> Result<String> {
+    let n_steps = u.int_in_range(1..=30)?;
+    (0..n_steps)
+        .map(|_| {
+            let label = word(u, b"abcdefghij", 1..=6)?;
+            Ok(if u.arbitrary()? {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", u.int_in_range(1..=9u8)?)
+            })
+        })
+        .collect::<Result<Vec<String>>>()
+        .map(|steps| steps.join(","))
+}
+
+pub fn day16(u: &mut Unstructured) -> Result<String> {
+    grid(u, b"......|-/\\", 1..=12, 1..=12)
+        .map(|grid| show_grid(&grid))
+}
+
+pub fn day17(u: &mut Unstructured) -> Result<String> {
+    grid(u, b"123456789", 1..=12, 1..=12)
+        .map(|grid| show_grid(&grid))
+}
+
+pub fn day18(u: &mut Unstructured) -> Result<String> {
+    /// A staircase down and to the right and back, mirrored at random,
+    /// as the directions and distances of its `2 * steps + 2` moves
+    fn staircase(u: &mut Unstructured, steps: usize) -> Result<Vec<(u8, u32)>> {
+        let (right, left) = if u.arbitrary()? { (b'R', b'L') } else { (b'L', b'R') };
+        let (down, up) = if u.arbitrary()? { (b'D', b'U') } else { (b'U', b'D') };
+        let mut moves = Vec::new();
+        for _ in 0..steps {
+            moves.push((right, u.int_in_range(1..=9)?));
+            moves.push((down, u.int_in_range(1..=9)?));
+        }
+        let width = moves.iter().step_by(2).map(|&(_, distance)| distance).sum();
+        let height = moves.iter().skip(1).step_by(2).map(|&(_, distance)| distance).sum();
+        moves.extend([(left, width), (up, height)]);
+        Ok(moves)
+    }
+
+    let steps = u.int_in_range(1..=4)?;
+    let part_one = staircase(u, steps)?;
+    let part_two = staircase(u, steps)?;
+    Ok(part_one
+        .iter()
+        .zip(&part_two)
+        .map(|(&(dir, distance), &(hex_dir, hex_distance))| format!(
+            "{} {distance} (#{:05x}{})",
+            char::from(dir),
+            hex_distance * 1000,
+            "RDLU".bytes().position(|d| d == hex_dir).unwrap_or_default(),
+        ))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+pub fn day19(u: &mut Unstructured) -> Result<String> {
+    let n_workflows = u.int_in_range(1..=6)?;
+    let names = (0..n_workflows)
+        .map(|i| if i == 0 { "in".to_string() } else { name(i, 3) })
+        .collect::<Vec<String>>();
+
+    let mut workflows = Vec::new();
+    for (i, workflow) in names.iter().enumerate() {
+        // only sending parts on to later workflows, so none loops
+        let mut targets = vec!["A", "R"];
+        targets.extend(names[i + 1..].iter().map(String::as_str));
+        let n_rules = u.int_in_range(0..=3)?;
+        let mut rules = (0..n_rules)
+            .map(|_| Ok(format!(
+                "{}{}{}:{}",
+                char::from(pick(u, b"xmas")?),
+                char::from(pick(u, b"<>")?),
+                u.int_in_range(1..=4000u16)?,
+                pick(u, &targets)?,
+            )))
+            .collect::<Result<Vec<String>>>()?;
+        rules.push(pick(u, &targets)?.to_string());
+        workflows.push(format!("{workflow}{{{}}}", rules.join(",")));
+    }
+
+    let parts = lines(u, 1..=5, |u, _| Ok(format!(
+        "{{x={},m={},a={},s={}}}",
+        u.int_in_range(1..=4000u16)?,
+        u.int_in_range(1..=4000u16)?,
+        u.int_in_range(1..=4000u16)?,
+        u.int_in_range(1..=4000u16)?,
+    )))?;
+    Ok(format!("{}\n\n{parts}", workflows.join("\n")))
+}
+
+pub fn day20(u: &mut Unstructured) -> Result<String> {
+    // counters of flip-flops, each turning its inverter on every so many presses,
+    // which together feed the conjunction before `rx` like the puzzle's
+    let n_counters = u.int_in_range(1..=4)?;
+    let mut starts = Vec::new();
+    let mut modules = Vec::new();
+    for counter in 0..n_counters {
+        let n_bits = u.int_in_range(1..=6)?;
+        let bits = (0..n_bits)
+            .map(|bit| name(counter * 8 + bit, 2))
+            .collect::<Vec<String>>();
+        let inverter = name(200 + counter, 2);
+        starts.push(bits[0].clone());
+        for (bit, flip_flop) in bits.iter().enumerate() {
+            modules.push(format!("%{flip_flop} -> {}", bits.get(bit + 1).unwrap_or(&inverter)));
+        }
+        modules.push(format!("&{inverter} -> zz"));
+    }
+    modules.push("&zz -> rx".to_string());
+    modules.insert(0, format!("broadcaster -> {}", starts.join(", ")));
+    Ok(modules.join("\n"))
+}
+
+pub fn day21(u: &mut Unstructured) -> Result<String> {
+    let size = u.int_in_range(2..=7)? * 2 + 1;
+    let mut grid = grid(u, b".....#", size..=size, size..=size)?;
+    // clear like the puzzle's: the edges and the start's row and column
+    let middle = size / 2;
+    for i in 0..size {
+        for (row, col) in [(0, i), (size - 1, i), (i, 0), (i, size - 1), (middle, i), (i, middle)] {
+            grid[row][col] = b'.';
+        }
+    }
+    grid[middle][middle] = b'S';
+    Ok(show_grid(&grid))
+}
+
+pub fn day22(u: &mut Unstructured) -> Result<String> {
+    lines(u, 1..=15, |u, _| {
+        let start = [
+            u.int_in_range(0..=4u8)?,
+            u.int_in_range(0..=4u8)?,
+            u.int_in_range(1..=20u8)?,
+        ];
+        let mut end = start;
+        end[u.choose_index(3)?] += u.int_in_range(0..=3)?;
+        Ok(format!("{}~{}", join(&start, ","), join(&end, ",")))
+    })
+}
+
+pub fn day23(u: &mut Unstructured) -> Result<String> {
+    // the longest path is searched for exhaustively, so the grid is kept smaller still
+    let mut grid = grid(u, b"...##><^v", 3..=9, 3..=9)?;
+    let (n_rows, n_cols) = (grid.len(), grid[0].len());
+    for row in &mut grid {
+        row[0] = b'#';
+        row[n_cols - 1] = b'#';
+    }
+    grid[0].fill(b'#');
+    grid[n_rows - 1].fill(b'#');
+    grid[0][1] = b'.';
+    grid[n_rows - 1][n_cols - 2] = b'.';
+    Ok(show_grid(&grid))
+}
+
+pub fn day24(u: &mut Unstructured) -> Result<String> {
+    // a rock thrown from `start` at `velocity` hits every hailstone, as part two expects
+    let start = [
+        u.int_in_range(0..=1_000_000i64)?,
+        u.int_in_range(0..=1_000_000i64)?,
+        u.int_in_range(0..=1_000_000i64)?,
+    ];
+    let velocity = [
+        u.int_in_range(-100..=100i64)?,
+        u.int_in_range(-100..=100i64)?,
+        u.int_in_range(-100..=100i64)?,
+    ];
+    lines(u, 3..=6, |u, i| {
+        let time = i64::try_from(i).unwrap_or_default() * 100 + u.int_in_range(1..=99)?;
+        let mut position = [0; 3];
+        let mut hail_velocity = [0; 3];
+        for axis in 0..3 {
+            hail_velocity[axis] = u.int_in_range(-100..=100)?;
+            position[axis] = start[axis] + time * (velocity[axis] - hail_velocity[axis]);
+        }
+        Ok(format!("{} @ {}", join(&position, ", "), join(&hail_velocity, ", ")))
+    })
+}
+
+pub fn day25(u: &mut Unstructured) -> Result<String> {
+    // two fully connected clusters joined by three wires, the ones to cut
+    let sizes = [u.int_in_range(5..=7)?, u.int_in_range(5..=7)?];
+    let clusters = [
+        (0..sizes[0]).map(|i| name(i, 3)).collect::<Vec<String>>(),
+        (sizes[0]..sizes[0] + sizes[1]).map(|i| name(i, 3)).collect::<Vec<String>>(),
+    ];
+
+    let mut connections = Vec::new();
+    for cluster in &clusters {
+        for (i, component) in cluster.iter().enumerate() {
+            if i + 1 < cluster.len() {
+                connections.push((component.clone(), cluster[i + 1..].to_vec()));
+            }
+        }
+    }
+    // the first cluster's first components are listed first, with room to spare
+    for (_, connected) in connections.iter_mut().take(3) {
+        connected.push(u.choose(&clusters[1])?.clone());
+    }
+    Ok(connections
+        .iter()
+        .map(|(component, connected)| format!("{component}: {}", connected.join(" ")))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
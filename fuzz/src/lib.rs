@@ -0,0 +1,78 @@
+//! Shared pieces of the fuzz targets, one per day in `fuzz_targets`
+//!
+//! Each target takes either
+//! - the fuzzed bytes as is, when they are UTF-8, and parses them with the day's [`Parse`](aoc_2023::Parse),
+//!   which may reject them but never panic, then solves them if they parse,
+//!   as parsing rejects whatever the solver assumes of its input
+//! - or a well-formed input generated from the bytes by [`inputs`], when they start with [`STRUCTURED`],
+//!   which it parses and then solves, to exercise the solver itself
+//!
+//! Some of the puzzles' guarantees, such as a path existing, are only found out by solving,
+//! so a finding may need checking against the puzzle's statement.
+//! Each day's example input in `seeds` starts off its corpus.
+
+pub use arbitrary::{Result, Unstructured};
+pub use libfuzzer_sys::fuzz_target;
+
+pub mod inputs;
+
+/// Marks the bytes to be turned into a generated input, as no UTF-8 text starts with it
+pub const STRUCTURED: u8 = 0xFF;
+
+/// What a target was given to work with
+pub enum Input {
+    /// The fuzzed bytes, to be parsed and, if they parse, solved
+    Raw(String),
+    /// A well-formed input, to be parsed and solved
+    Generated(String),
+}
+
+/// The input for the fuzzed bytes, if any
+pub fn input_for(data: &[u8], generate: fn(&mut Unstructured) -> Result<String>) -> Option<Input> {
+    match data.split_first() {
+        Some((&STRUCTURED, rest)) => generate(&mut Unstructured::new(rest))
+            .ok()
+            .map(Input::Generated),
+        _ => std::str::from_utf8(data)
+            .ok()
+            .map(|input| Input::Raw(input.to_string())),
+    }
+}
+
+/// Defines the fuzz target of a day's solution,
+/// with the generator of its well-formed inputs from [`inputs`]
+/// and the parts to solve them with, both unless given
+///
+/// ```ignore
+/// aoc_2023_fuzz::fuzz_day!(aoc_2023::days::day5::Day5, aoc_2023_fuzz::inputs::day5);
+/// aoc_2023_fuzz::fuzz_day!(aoc_2023::days::day25::Day25, aoc_2023_fuzz::inputs::day25, part_one);
+/// ```
+///
+/// # Panics
+///
+/// If a generated input fails to parse
+#[macro_export]
+macro_rules! fuzz_day {
+    ($solution:expr, $generate:path) => {
+        $crate::fuzz_day!($solution, $generate, part_one, part_two);
+    };
+    ($solution:expr, $generate:path, $($part:ident),+) => {
+        $crate::fuzz_target!(|data: &[u8]| {
+            let solution = $solution;
+            match $crate::input_for(data, $generate) {
+                Some($crate::Input::Raw(input)) => {
+                    if aoc_2023::Parse::parse_input(&solution, &input).is_ok() {
+                        $(let _ = aoc_2023::Solution::$part(&solution, &input);)+
+                    }
+                },
+                Some($crate::Input::Generated(input)) => {
+                    if let Err(err) = aoc_2023::Parse::parse_input(&solution, &input) {
+                        panic!("Failed to parse a generated input: {err}");
+                    }
+                    $(let _ = aoc_2023::Solution::$part(&solution, &input);)+
+                },
+                None => {},
+            }
+        });
+    };
+}
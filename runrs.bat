@@ -2,4 +2,4 @@
 @ECHO off
 
 cargo build --release
-"./target/release/aoc-2023.exe" %1
\ No newline at end of file
+"./target/release/aoc-2023.exe" %*
\ No newline at end of file
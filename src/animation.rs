@@ -0,0 +1,127 @@
+//! Frame-by-frame recording of simulations, for terminal playback or numbered images
+
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+#[cfg(feature = "fs")]
+use std::{fs, path::Path};
+use crate::Canvas;
+#[cfg(feature = "fs")]
+use crate::{Image, Palette};
+
+/// A sequence of frames recorded from a simulation,
+/// optionally keeping only every `stride`-th frame offered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    frames: Vec<Canvas>,
+    stride: usize,
+    n_offered: usize,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Animation {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_stride(1)
+    }
+
+    /// # Panics
+    ///
+    /// If `stride` is 0
+    #[must_use]
+    pub const fn with_stride(stride: usize) -> Self {
+        assert!(stride > 0, "Animation stride must be positive");
+        Self {
+            frames: Vec::new(),
+            stride,
+            n_offered: 0,
+        }
+    }
+
+    /// Offers a frame, only drawing it if it falls on the stride
+    pub fn record<F>(&mut self, draw: F)
+    where
+        F: FnOnce() -> Canvas,
+    {
+        if self.n_offered.is_multiple_of(self.stride) {
+            self.frames.push(draw());
+        }
+        self.n_offered += 1;
+    }
+
+    /// Adds a frame regardless of the stride, such as for the final state
+    pub fn push(&mut self, frame: Canvas) {
+        self.frames.push(frame);
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn frames(&self) -> &[Canvas] {
+        &self.frames
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Draws each frame over the last in the terminal, waiting `delay` between them
+    ///
+    /// # Errors
+    ///
+    /// If failed to write to stdout
+    pub fn play(&self, delay: Duration) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+
+        for (i, frame) in self.frames
+            .iter()
+            .enumerate()
+        {
+            // move the cursor home and clear the screen
+            writeln!(stdout, "\x1b[H\x1b[2J{frame}Frame {}/{}", i + 1, self.len())?;
+            stdout.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    /// Writes every frame into the directory as `frame_0000.png`, `frame_0001.png`, ...
+    ///
+    /// # Errors
+    ///
+    /// If failed to create the directory or write a frame
+    #[cfg(feature = "fs")]
+    pub fn save_frames<P: AsRef<Path>>(&self, dir: P, palette: &Palette, scale: usize) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        for (i, frame) in self.frames
+            .iter()
+            .enumerate()
+        {
+            Image::from_canvas(frame, palette, scale)
+                .save(dir.join(format!("frame_{i:04}.png")))?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Animate {
+    /// Replays the puzzle's simulation, recording its intermediate states as frames
+    fn animate<T: Display>(&self, inp: T, animation: &mut Animation);
+}
@@ -1,115 +1,5 @@
-//! Day 15: Lens Library
-//!
-//! <https://adventofcode.com/2023/day/15>
-use std::fmt::Display;
-use aoc_2023::Solution;
-
-pub struct Day15;
-
-impl Day15 {
-    /// Hashing algorithm
-    /// turns any string into an 8-bit integer
-    ///
-    /// `((current_value + ascii_value(character)) * 17) % 256`
-    fn hash<T>(string: T) -> u8
-    where
-        T: AsRef<str>
-    {
-        string
-            .as_ref()
-            .bytes()
-            .fold(0u8, |acc, next| acc
-                .wrapping_add(next)
-                .wrapping_mul(17)
-            )
-    }
-}
-
-impl Solution for Day15 {
-    const NAME: &'static str = "Lens Library";
-
-    fn part_one<T: Display>(&self, inp: T) -> usize {
-        inp
-            .to_string()
-            .split(',')
-            .map(|s| usize::from(Self::hash(s)))
-            .sum()
-    }
-
-    /// # Panics
-    ///
-    /// If neither a '=' or '-' exist in an entry in the input
-    fn part_two<T: Display>(&self, inp: T) -> usize {
-        let inp = inp.to_string();
-        let mut boxes: [Vec<(&str, usize)>; 256] =
-            std::array::from_fn(|_| Vec::new());
-
-        for string in inp
-            .split(',')
-        {
-            let (label, focus) = string
-                .split_once('=')
-                .unwrap_or_else(||
-                    string
-                        .split_once('-')
-                        .unwrap()
-                );
-            if let Some(map) =
-                boxes.get_mut(Self::hash(label) as usize)
-            {
-                match (
-                    // index of the entry (label, _) if exists
-                    map
-                        .iter()
-                        .position(|(l, _)| *l == label),
-                    focus.parse::<usize>()
-                ) {
-                    // label already exists, focus is a number
-                    // update entry
-                    (Some(i), Ok(focus)) => {
-                        map[i] = (label, focus);
-                    },
-                    // label already exists, focus is none (needs to be removed; ending with '-')
-                    // remove the entry
-                    (Some(i), Err(_)) => {
-                        map.remove(i);
-                    },
-                    // label does not exist, focus is a number
-                    // create new entry
-                    (None, Ok(focus)) => {
-                        map.push((label, focus));
-                    }
-                    // last condition (label doesnt exist, and focus is none (needs to be removed; ending with '-')
-                    // We cant remove a non existing entry anyways
-                    // therefore this will never happen, so we do nothing
-                    _ => (),
-                }
-            }
-        }
-
-        boxes
-            .into_iter()
-            .zip(1..)
-            .flat_map(|(map, i)|
-                map
-                    .into_iter()
-                    .zip(1..)
-                    .map(move |((_, focus), j)| i * j * focus)
-            )
-            .sum()
-    }
-
-    fn run(&self, inp: String) {
-        let p1 = self.part_one(&inp);
-        let p2 = self.part_two(&inp);
-
-        println!("Part 1: {p1}");
-        println!("Part 2: {p2}");
-
-        assert_eq!(p1, 508_498);
-        assert_eq!(p2, 279_116);
-    }
-}
+//! Runs Day 15, see [`aoc_2023::days::day15`]
+use aoc_2023::days::day15::Day15;
 
 fn main() {
     aoc_2023::run_day(15, &Day15);
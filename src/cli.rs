@@ -0,0 +1,972 @@
+//! The `aoc-2023` command line, which builds and drives the days' binaries
+
+use std::{
+    env,
+    fmt,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    process::{Command as Process, Output, Stdio},
+    time::{Duration, Instant},
+};
+mod watch;
+mod serve;
+
+use crate::{
+    config::{config_path, Config, Profile},
+    dump::Stage,
+    perf::{PerfHistory, PerfRecord, DEFAULT_HISTORY_PATH},
+    days, AnswersOutput, OutputFormat, Part, RunOptions, Verbosity,
+};
+
+/// The event year, for fetching inputs and submitting answers
+pub const YEAR: u16 = 2023;
+
+/// The port `serve` listens on when `--port` is not provided
+pub const DEFAULT_PORT: u16 = 8023;
+
+/// How many runs `bench` does when `--runs` is not provided
+pub const DEFAULT_BENCH_RUNS: usize = 10;
+
+/// How long one fuzzed input may run before `fuzz` reports it as a hang
+pub const FUZZ_TIMEOUT_SECS: u64 = 10;
+
+/// Each command's usage and what it does
+const COMMANDS: [(&str, &str, &str); 15] = [
+    ("run", "run [day|all]", "Run solutions, for every day if no day is provided (the default command)"),
+    ("bench", "bench [day|all]", "Time each part over several runs (--runs <n>, 10 by default)"),
+    ("test", "test [day|all]", "Run the days' tests through cargo"),
+    ("check", "check [day|all]", "Check every answer against the puzzle input, summarizing which days pass"),
+    ("repl", "repl <day>", "Explore the day's parsed input interactively (days 14, 19, 20 and 21)"),
+    ("dump", "dump <day>", "Write the day's model of its input as JSON (--stage <parsed|intermediate>, the latter for days 5, 19, 22 and 23)"),
+    ("serve", "serve", "Serve the solvers over HTTP on localhost (--port <port>, 8023 by default)"),
+    ("watch", "watch <day>", "Rebuild and re-run the day whenever its source, the library or its inputs change"),
+    ("fuzz", "fuzz <day>", "Fuzz the day's parsing and solving with cargo-fuzz, seeding the corpus from its example and inputs"),
+    ("list", "list", "List the solved days"),
+    ("new", "new <day>", "Create a solution from a template (--name <title>)"),
+    ("fetch", "fetch <day>", "Download the day's input, with the session cookie in AOC_SESSION (--force overwrites it)"),
+    ("submit", "submit <day> <part> [answer]", "Submit an answer, solving the part first if not provided"),
+    ("perf-report", "perf-report [day]", "Show the timings recorded with --record"),
+    ("help", "help [command]", "Show this help, or a command's"),
+];
+
+const OPTIONS: &str = "\
+Options:
+  --part <1|2>           Only solve one part
+  --input <variant>      `puzzle` (the default), a name such as `example` for inputs/day<day>.<name>.txt, a path or `-` for stdin
+  --format <text|json>   Output format
+  -q, --quiet            Only print the answers
+  -v, --verbose          Also print each part's time and the commands being run
+  --features <features>  Cargo features to build with, such as `alloc-stats`
+
+Any other options, such as --visualize, --trace or --record, are passed on to each day";
+
+/// A line of `list --format json`
+#[derive(serde::Serialize)]
+struct ListedDay {
+    day: u8,
+    name: &'static str,
+    /// Whether the day's puzzle input has been fetched
+    input: bool,
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    /// The arguments were invalid
+    Usage(String),
+    Io(io::Error),
+    /// A command ran but did not succeed
+    Failed(String),
+}
+
+impl CliError {
+    #[must_use]
+    pub const fn exit_code(&self) -> i32 {
+        match self {
+            Self::Usage(_) => 2,
+            Self::Io(_) | Self::Failed(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage(message) => write!(f, "{message}\n\nRun `aoc-2023 help` for usage"),
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Days {
+    All,
+    One(u8),
+}
+
+impl Days {
+    /// The days with a solution, erroring if a single day has none yet
+    ///
+    /// # Errors
+    ///
+    /// If the day has no solution
+    pub fn solved(self) -> Result<Vec<u8>, CliError> {
+        match self {
+            Self::All => Ok(solved_days()),
+            Self::One(day) if is_solved(day) => Ok(vec![day]),
+            Self::One(day) => Err(CliError::Failed(format!("Solution does not exist yet for day {day}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(Days),
+    Bench(Days),
+    Test(Days),
+    Check(Days),
+    Watch(u8),
+    Fuzz(u8),
+    Repl(u8),
+    Dump { day: u8, stage: Stage },
+    Serve(u16),
+    List,
+    New { day: u8, name: Option<String> },
+    Fetch { day: u8, force: bool },
+    Submit { day: u8, part: Part, answer: Option<String> },
+    PerfReport(Option<u8>),
+    Help(Option<String>),
+}
+
+/// A parsed command line: the command, the options the CLI itself reads,
+/// and the arguments passed on to each day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cli {
+    pub command: Command,
+    pub options: RunOptions,
+    /// The project's `aoc.toml`, which the days' binaries read for themselves
+    pub config: Config,
+    /// `--features <features>` for cargo
+    pub features: Option<String>,
+    pub day_args: Vec<String>,
+}
+
+/// # Errors
+///
+/// If the day is not a number from 1 to 25
+pub fn parse_day(day: &str) -> Result<u8, CliError> {
+    day.parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| CliError::Usage(format!("Invalid day `{day}`, expected a number from 1 to 25")))
+}
+
+fn parse_days(day: Option<&String>) -> Result<Days, CliError> {
+    match day.map(String::as_str) {
+        None | Some("all") => Ok(Days::All),
+        Some(day) => parse_day(day).map(Days::One),
+    }
+}
+
+impl Cli {
+    /// Parses the arguments following the program name
+    ///
+    /// # Errors
+    ///
+    /// If the command or any of its arguments are invalid
+    pub fn parse(args: &[String]) -> Result<Self, CliError> {
+        let config_path = config_path(root());
+        let config = Config::load(&config_path)
+            .map_err(|err| CliError::Failed(format!("Invalid config {}: {err}", config_path.display())))?;
+        let mut options = RunOptions::parse(args.iter().cloned())
+            .map_err(CliError::Usage)?;
+        if !args.iter().any(|arg| arg == "--format") {
+            options.format = config.format();
+        }
+
+        // split the positional arguments from the options, keeping the options' values with them
+        let mut positional = Vec::new();
+        let mut day_args = Vec::new();
+        let mut features = None;
+        let mut name = None;
+        let mut port = None;
+        let mut stage = Stage::default();
+        let mut force = false;
+        let mut help = false;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => help = true,
+                "--force" => force = true,
+                "--features" | "--name" | "--port" | "--stage" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| CliError::Usage(format!("Missing value for {arg}")))?;
+                    match arg.as_str() {
+                        "--features" => features = Some(value.clone()),
+                        "--name" => name = Some(value.clone()),
+                        "--stage" => stage = value
+                            .parse()
+                            .map_err(CliError::Usage)?,
+                        _ => port = Some(value
+                            .parse::<u16>()
+                            .map_err(|_| CliError::Usage(format!("Invalid port `{value}`")))?
+                        ),
+                    }
+                },
+                flag if flag.starts_with('-') => {
+                    day_args.push(arg.clone());
+                    // the value of a flag is never a command or day of its own
+                    if let Some(value) = args
+                        .clone()
+                        .next()
+                        .filter(|value| (*value == "-" || !value.starts_with('-')) && takes_value(flag))
+                    {
+                        day_args.push(value.clone());
+                        args.next();
+                    }
+                },
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        let mut positional = positional.iter();
+        let first = positional.next().map(String::as_str);
+        // `aoc-2023 <day>` is a shorthand for `aoc-2023 run <day>`
+        let is_day = first.is_some_and(|first| first.starts_with(|c: char| c.is_ascii_digit()));
+
+        let command = match first {
+            _ if help => Command::Help(Some(if is_day { "run" } else { first.unwrap_or("help") }.to_string())
+                .filter(|command| command != "help")
+            ),
+            None => Command::Run(Days::All),
+            Some("run") => Command::Run(parse_days(positional.next())?),
+            Some("bench") => Command::Bench(parse_days(positional.next())?),
+            Some("test") => Command::Test(parse_days(positional.next())?),
+            Some("check") => Command::Check(parse_days(positional.next())?),
+            Some("serve") => Command::Serve(port.unwrap_or(DEFAULT_PORT)),
+            Some("repl") => Command::Repl(parse_day(required(positional.next(), "day")?)?),
+            Some("dump") => Command::Dump {
+                day: parse_day(required(positional.next(), "day")?)?,
+                stage,
+            },
+            Some("watch") => Command::Watch(parse_day(required(positional.next(), "day")?)?),
+            Some("fuzz") => Command::Fuzz(parse_day(required(positional.next(), "day")?)?),
+            Some("list") => Command::List,
+            Some("new") => Command::New {
+                day: parse_day(required(positional.next(), "day")?)?,
+                name,
+            },
+            Some("fetch") => Command::Fetch {
+                day: parse_day(required(positional.next(), "day")?)?,
+                force,
+            },
+            Some("submit") => Command::Submit {
+                day: parse_day(required(positional.next(), "day")?)?,
+                part: required(positional.next(), "part")?
+                    .parse()
+                    .map_err(CliError::Usage)?,
+                answer: positional.next().cloned(),
+            },
+            Some("perf-report") => Command::PerfReport(positional
+                .next()
+                .map(|day| parse_day(day))
+                .transpose()?
+            ),
+            Some("help") => Command::Help(positional.next().cloned()),
+            Some(day) if is_day => Command::Run(Days::One(parse_day(day)?)),
+            Some(command) => return Err(CliError::Usage(format!("Unknown command `{command}`"))),
+        };
+        if let Some(extra) = positional.next() {
+            return Err(CliError::Usage(format!("Unexpected argument `{extra}`")));
+        }
+
+        Ok(Self { command, options, config, features, day_args })
+    }
+}
+
+fn required<'a>(arg: Option<&'a String>, name: &str) -> Result<&'a str, CliError> {
+    arg.map(String::as_str)
+        .ok_or_else(|| CliError::Usage(format!("Missing {name}")))
+}
+
+/// Whether a flag passed on to the days is followed by a value
+fn takes_value(flag: &str) -> bool {
+    matches!(
+        flag,
+        "--part" | "--input" | "--format" | "--runs" | "--trace" | "--history" | "--threshold" | "--baseline"
+            | "--image" | "--scale" | "--palette" | "--frames" | "--every" | "--delay"
+    )
+}
+
+/// The repository root, where the days' sources and inputs are
+fn root() -> PathBuf {
+    PathBuf::from(option_env!("CARGO_MANIFEST_DIR").unwrap_or("."))
+}
+
+/// The day's solution, in the library
+fn source_path(day: u8) -> PathBuf {
+    root().join(format!("src/days/day{day}.rs"))
+}
+
+/// The day's binary, which runs its solution
+fn bin_path(day: u8) -> PathBuf {
+    root().join(format!("src/bin/day{day}.rs"))
+}
+
+#[must_use]
+pub fn is_solved(day: u8) -> bool {
+    solution_name(day).is_some()
+}
+
+/// The days that have a solution in the library's registry, in order
+#[must_use]
+pub fn solved_days() -> Vec<u8> {
+    days()
+        .into_iter()
+        .map(|(day, _)| day)
+        .collect()
+}
+
+/// The puzzle's title, the `NAME` of the day's solution in the library's registry
+#[must_use]
+pub fn solution_name(day: u8) -> Option<&'static str> {
+    days()
+        .into_iter()
+        .find_map(|(other, name)| (other == day).then_some(name))
+}
+
+impl Cli {
+    fn cargo(&self) -> Process {
+        let mut cargo = Process::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+        cargo.current_dir(root());
+        if self.options.verbosity < Verbosity::Verbose {
+            cargo.arg("--quiet");
+        }
+        cargo
+    }
+
+    fn features_args(&self) -> Vec<String> {
+        self.features
+            .iter()
+            .flat_map(|features| ["--features".to_string(), features.clone()])
+            .collect()
+    }
+
+    /// `--release` unless `aoc.toml` sets the dev profile
+    fn profile_args(&self) -> &'static [&'static str] {
+        match self.config.profile() {
+            Profile::Release => &["--release"],
+            Profile::Dev => &[],
+        }
+    }
+
+    /// Builds every day's binary, or only the one day's
+    fn build(&self, day: Option<u8>) -> Result<(), CliError> {
+        let mut cargo = self.cargo();
+        cargo
+            .arg("build")
+            .args(self.profile_args());
+        match day {
+            Some(day) => cargo.args(["--bin", &format!("day{day}")]),
+            None => cargo.arg("--bins"),
+        };
+        cargo.args(self.features_args());
+        self.log(&cargo);
+
+        if cargo.status()?.success() {
+            Ok(())
+        } else {
+            Err(CliError::Failed("Failed to build the solutions".to_string()))
+        }
+    }
+
+    fn log(&self, process: &Process) {
+        if self.options.verbosity == Verbosity::Verbose {
+            eprintln!("> {process:?}");
+        }
+    }
+
+    fn day_binary(&self, day: u8, args: &[String]) -> (PathBuf, Process) {
+        let target = env::var("CARGO_TARGET_DIR")
+            .map_or_else(|_| root().join("target"), PathBuf::from);
+        let path = target
+            .join(self.config.profile().target_dir())
+            .join(format!("day{day}{}", env::consts::EXE_SUFFIX));
+        let mut binary = Process::new(&path);
+        binary
+            .current_dir(root())
+            .args(args);
+        self.log(&binary);
+        (path, binary)
+    }
+
+    /// Runs the day with its output shown as it goes, for animations and such
+    fn run_day(&self, day: u8, args: &[String]) -> Result<bool, CliError> {
+        let (path, mut binary) = self.day_binary(day, args);
+        binary
+            .status()
+            .map(|status| status.success())
+            .map_err(|err| CliError::Failed(format!("Failed to run {}: {err}", path.display())))
+    }
+
+    fn output_day(&self, day: u8, args: &[String]) -> Result<Output, CliError> {
+        let (path, mut binary) = self.day_binary(day, args);
+        binary
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|err| CliError::Failed(format!("Failed to run {}: {err}", path.display())))
+    }
+
+    /// Runs the command
+    ///
+    /// # Errors
+    ///
+    /// If the command failed
+    pub fn execute(&self) -> Result<(), CliError> {
+        match &self.command {
+            Command::Run(days) => self.execute_run(*days),
+            Command::Bench(days) => self.execute_bench(*days),
+            Command::Test(days) => self.execute_test(*days),
+            Command::Check(days) => self.execute_check(*days),
+            Command::Watch(day) => self.execute_watch(*day),
+            Command::Fuzz(day) => self.execute_fuzz(*day),
+            Command::Repl(day) => self.execute_repl(*day),
+            Command::Dump { day, stage } => self.execute_dump(*day, *stage),
+            Command::Serve(port) => self.execute_serve(*port),
+            Command::List => {
+                self.execute_list();
+                Ok(())
+            },
+            Command::New { day, name } => execute_new(*day, name.as_deref()),
+            Command::Fetch { day, force } => execute_fetch(&self.config, *day, *force),
+            Command::Submit { day, part, answer } => self.execute_submit(*day, *part, answer.as_deref()),
+            Command::PerfReport(day) => {
+                let history = PerfHistory::load(root().join(DEFAULT_HISTORY_PATH))?;
+                print!("{}", history.report(*day));
+                Ok(())
+            },
+            Command::Help(command) => {
+                println!("{}", help(command.as_deref())?);
+                Ok(())
+            },
+        }
+    }
+
+    fn execute_run(&self, days: Days) -> Result<(), CliError> {
+        let solved = days.solved()?;
+        self.build(None)?;
+
+        let instant = Instant::now();
+        let failed = solved
+            .iter()
+            .map(|&day| self.run_day(day, &self.day_args).map(|success| (day, success)))
+            .collect::<Result<Vec<(u8, bool)>, CliError>>()?
+            .into_iter()
+            .filter(|&(_, success)| !success)
+            .map(|(day, _)| day.to_string())
+            .collect::<Vec<String>>();
+
+        if days == Days::All {
+            let elapsed = instant.elapsed();
+            if self.options.format == OutputFormat::Text && self.options.verbosity > Verbosity::Quiet {
+                let text = format!(
+                    "[Total Execution time: {elapsed:?}]",
+                );
+                println!(
+                    "{text}\n{}",
+                    "=".repeat(text.chars().count())
+                );
+            }
+            if self.day_args.iter().any(|arg| arg == "--record") {
+                self.record_total(elapsed)?;
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(CliError::Failed(format!("Failed to run day {}", failed.join(", "))))
+        }
+    }
+
+    /// Records the total time across every day alongside the days' own timings,
+    /// flagging it if it regressed
+    fn record_total(&self, elapsed: Duration) -> Result<(), CliError> {
+        let flag = |name: &str| self.day_args
+            .iter()
+            .position(|arg| arg == name)
+            .and_then(|i| self.day_args.get(i + 1));
+        let path = flag("--history")
+            .map_or_else(|| root().join(DEFAULT_HISTORY_PATH), PathBuf::from);
+        let threshold = flag("--threshold")
+            .map_or(Ok(10.0), |threshold| threshold
+                .parse::<f64>()
+                .map_err(|_| CliError::Usage(format!("Invalid regression threshold `{threshold}`")))
+            )?;
+
+        let mut history = PerfHistory::load(path)?;
+        let record = PerfRecord::now(0, "total", elapsed);
+        if let Some(regression) = history.check(&record, threshold / 100.0, flag("--baseline").map(String::as_str)) {
+            println!("{regression}");
+        }
+        history.append(&[record])?;
+        Ok(())
+    }
+
+    fn execute_bench(&self, days: Days) -> Result<(), CliError> {
+        if self.options.runs.is_some() {
+            return self.execute_run(days);
+        }
+        let mut cli = self.clone();
+        cli.options.runs = Some(DEFAULT_BENCH_RUNS);
+        cli.day_args.extend(["--runs".to_string(), DEFAULT_BENCH_RUNS.to_string()]);
+        cli.execute_run(days)
+    }
+
+    fn execute_test(&self, days: Days) -> Result<(), CliError> {
+        let mut cargo = self.cargo();
+        cargo
+            .arg("test")
+            .args(self.profile_args())
+            .args(self.features_args());
+        for day in days.solved()? {
+            cargo.args(["--bin", &format!("day{day}")]);
+        }
+        self.log(&cargo);
+
+        if cargo.status()?.success() {
+            Ok(())
+        } else {
+            Err(CliError::Failed("Tests failed".to_string()))
+        }
+    }
+
+    fn execute_repl(&self, day: u8) -> Result<(), CliError> {
+        Days::One(day).solved()?;
+        let has_repl = fs::read_to_string(bin_path(day))?
+            .contains("repl_day(");
+        if !has_repl {
+            return Err(CliError::Failed(format!("Day {day} has no REPL yet")));
+        }
+        self.build(Some(day))?;
+
+        let mut args = vec!["--repl".to_string(), "--quiet".to_string()];
+        args.extend(self.day_args.iter().cloned());
+        if self.run_day(day, &args)? {
+            Ok(())
+        } else {
+            Err(CliError::Failed(format!("Day {day} failed")))
+        }
+    }
+
+    fn execute_dump(&self, day: u8, stage: Stage) -> Result<(), CliError> {
+        Days::One(day).solved()?;
+        self.build(Some(day))?;
+
+        let mut args = vec!["--dump".to_string(), stage.to_string()];
+        args.extend(self.day_args.iter().cloned());
+        if self.run_day(day, &args)? {
+            Ok(())
+        } else {
+            Err(CliError::Failed(format!("Day {day} failed")))
+        }
+    }
+
+    /// Seeds the day's corpus with its example from `fuzz/seeds` and its inputs, such as `inputs/day5.example.txt`,
+    /// then fuzzes it until interrupted or a crash is found
+    fn execute_fuzz(&self, day: u8) -> Result<(), CliError> {
+        Days::One(day).solved()?;
+        let corpus = root().join(format!("fuzz/corpus/day{day}"));
+        fs::create_dir_all(&corpus)?;
+
+        let mut n_seeds = 0;
+        let example = root().join(format!("fuzz/seeds/day{day}.txt"));
+        if example.is_file() {
+            fs::copy(&example, corpus.join("seed-example.txt"))?;
+            n_seeds += 1;
+        }
+        let input_prefix = format!("day{day}.");
+        for entry in fs::read_dir(self.config.inputs_dir())?.filter_map(Result::ok) {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with(&input_prefix) && name.ends_with(".txt") {
+                fs::copy(entry.path(), corpus.join(format!("seed-{name}")))?;
+                n_seeds += 1;
+            }
+        }
+        // the fuzz targets generate a well-formed input from bytes starting with 0xFF
+        fs::write(
+            corpus.join("seed-structured"),
+            [0xFF].into_iter().chain(0..=u8::MAX).collect::<Vec<u8>>(),
+        )?;
+        println!("Fuzzing day {day} from {} seeds, press Ctrl+C to stop", n_seeds + 1);
+
+        // not through `cargo()`, as `--quiet` would be taken by cargo rather than cargo-fuzz
+        let mut cargo = Process::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+        cargo
+            .current_dir(root())
+            .args(["fuzz", "run", &format!("day{day}")])
+            .arg(&corpus)
+            .args(["--", &format!("-timeout={FUZZ_TIMEOUT_SECS}")]);
+        self.log(&cargo);
+
+        if cargo.status()?.success() {
+            Ok(())
+        } else {
+            Err(CliError::Failed(format!("Fuzzing day {day} failed, any crash is saved in fuzz/artifacts/day{day}")))
+        }
+    }
+
+    /// Runs each day quietly, as its own `run` checks its answers
+    fn execute_check(&self, days: Days) -> Result<(), CliError> {
+        let solved = days.solved()?;
+        self.build(None)?;
+
+        let mut failed = Vec::new();
+        for &day in &solved {
+            let instant = Instant::now();
+            let output = self.output_day(day, &["--quiet".to_string()])?;
+            let elapsed = instant.elapsed();
+            let name = solution_name(day).unwrap_or_default();
+
+            if output.status.success() {
+                println!("Day {day:>2}  ok    {name:<32} {elapsed:>12.3?}");
+            } else {
+                // the failed assertion, on one line
+                let reason = panic_message(&String::from_utf8_lossy(&output.stderr))
+                    .lines()
+                    .map(str::trim)
+                    .collect::<Vec<&str>>()
+                    .join(" ");
+                println!("Day {day:>2}  FAIL  {name:<32} {reason}");
+                failed.push(day.to_string());
+            }
+        }
+        println!("{}/{} days passed", solved.len() - failed.len(), solved.len());
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(CliError::Failed(format!("Wrong answers for day {}", failed.join(", "))))
+        }
+    }
+
+    fn execute_list(&self) {
+        for day in solved_days() {
+            let name = solution_name(day).unwrap_or_default();
+            let has_input = self.config
+                .inputs_dir()
+                .join(format!("day{day}.txt"))
+                .is_file();
+
+            match self.options.format {
+                OutputFormat::Text if has_input => println!("Day {day:>2}  {name}"),
+                OutputFormat::Text => println!("Day {day:>2}  {name:<32} (no input)"),
+                OutputFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string(&ListedDay { day, name, input: has_input })
+                        .expect("Listed days always serialize"),
+                ),
+            }
+        }
+    }
+
+    /// Solves the part through its day's binary, for submitting
+    fn solve(&self, day: u8, part: Part) -> Result<String, CliError> {
+        self.build(Some(day))?;
+        let output = self.output_day(day, &[
+            "--part".to_string(), part.number().to_string(),
+            "--format".to_string(), "json".to_string(),
+        ])?;
+        if !output.status.success() {
+            return Err(CliError::Failed(format!(
+                "Failed to solve day {day} part {}:\n{}",
+                part.number(),
+                String::from_utf8_lossy(&output.stderr),
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        AnswersOutput::from_output(&stdout)
+            .and_then(|answers| answers.answer(part))
+            .map(|answer| answer.to_string())
+            .ok_or_else(|| CliError::Failed(format!("No answer found in the output of day {day}: {stdout}")))
+    }
+
+    fn execute_submit(&self, day: u8, part: Part, answer: Option<&str>) -> Result<(), CliError> {
+        let session = session()?;
+        let answer = match answer {
+            Some(answer) => answer.to_string(),
+            None => self.solve(day, part)?,
+        };
+        println!("Submitting {answer} for day {day} part {}", part.number());
+
+        let response = advent_request(
+            &session,
+            &format!("{day}/answer"),
+            &[
+                "--data", &format!("level={}", part.number()),
+                "--data-urlencode", &format!("answer={answer}"),
+            ],
+        )?;
+        let message = article_text(&response);
+        println!("{message}");
+
+        if message.contains("That's the right answer") {
+            Ok(())
+        } else {
+            Err(CliError::Failed("The answer was not accepted".to_string()))
+        }
+    }
+}
+
+/// The message a day's binary panicked with, or else everything it wrote to stderr
+fn panic_message(stderr: &str) -> String {
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !line.contains("panicked"));
+    lines.next();
+    let message = lines
+        .take_while(|line| !line.starts_with("note:") && !line.starts_with("stack backtrace:"))
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    if message.is_empty() {
+        stderr.trim().to_string()
+    } else {
+        message
+    }
+}
+
+/// Starts the day's solution in `src/days` from a template, with a binary to run it,
+/// adding the solution to the days' modules and registry and the binary to `Cargo.toml`
+fn execute_new(day: u8, name: Option<&str>) -> Result<(), CliError> {
+    for path in [source_path(day), bin_path(day)] {
+        if path.exists() {
+            return Err(CliError::Failed(format!("{} already exists", path.display())));
+        }
+    }
+    let days_path = root().join("src/days.rs");
+    let days = register_day(&fs::read_to_string(&days_path)?, day)
+        .ok_or_else(|| CliError::Failed(format!("Failed to find the days' modules and registry in {}", days_path.display())))?;
+
+    for (path, source) in [
+        (source_path(day), template(day, name.unwrap_or(""))),
+        (bin_path(day), bin_template(day)),
+    ] {
+        fs::write(&path, source)?;
+        println!("Created {}", path.display());
+    }
+    fs::write(&days_path, days)?;
+    println!("Added Day{day} to the registry in src/days.rs");
+
+    let manifest_path = root().join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let target = format!("name = \"day{day}\"");
+    if !manifest.contains(&target) {
+        fs::write(
+            &manifest_path,
+            format!("{}\n\n[[bin]]\n{target}\npath = \"src/bin/day{day}.rs\"\nrequired-features = [\"fs\"]\n", manifest.trim_end()),
+        )?;
+        println!("Added the day{day} binary to Cargo.toml");
+    }
+    Ok(())
+}
+
+/// Declares the day's module after the last one in `src/days.rs` and adds its solution to the end of the registry
+fn register_day(days: &str, day: u8) -> Option<String> {
+    let mut lines = days
+        .lines()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+    let last_module = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod day"))?;
+    lines.insert(last_module + 1, format!("pub mod day{day};"));
+
+    let registry_end = lines
+        .iter()
+        .rposition(|line| line == "}")?;
+    lines.insert(registry_end, format!("    {day} => day{day}::Day{day},"));
+    Some(lines.join("\n") + "\n")
+}
+
+fn template(day: u8, name: &str) -> String {
+    format!(r#"//! Day {day}: {name}
+//!
+//! <https://adventofcode.com/{YEAR}/day/{day}>
+use std::{{convert::Infallible, fmt::Display}};
+use crate::{{Solution, Parse, in_span}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const NAME: &'static str = "{name}";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {{
+        inp
+            .to_string()
+            .lines()
+            .count() as u64
+    }}
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {{
+        inp
+            .to_string()
+            .lines()
+            .count() as u64
+    }}
+
+    fn run(&self, inp: String) {{
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {{p1}}");
+        println!("Part 2: {{p2}}");
+    }}
+}}
+
+impl Parse for Day{day} {{
+    type Parsed<'a> = Vec<&'a str>;
+    type Error = Infallible;
+
+    fn parse_input<'a>(&self, inp: &'a str) -> Result<Vec<&'a str>, Infallible> {{
+        Ok(inp
+            .lines()
+            .collect()
+        )
+    }}
+}}
+"#)
+}
+
+fn bin_template(day: u8) -> String {
+    format!(r#"//! Runs Day {day}, see [`aoc_2023::days::day{day}`]
+use aoc_2023::days::day{day}::Day{day};
+
+fn main() {{
+    aoc_2023::run_day({day}, &Day{day});
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test() {{ main(); }}
+}}
+"#)
+}
+
+/// The adventofcode.com session cookie, from `AOC_SESSION`
+fn session() -> Result<String, CliError> {
+    env::var("AOC_SESSION")
+        .map(|session| session.trim().to_string())
+        .ok()
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| CliError::Failed("AOC_SESSION must be set to your adventofcode.com session cookie".to_string()))
+}
+
+/// Sends a request for the day's page on the Advent of Code site through `curl`
+///
+/// The session cookie is written to curl's stdin as a header,
+/// as its arguments are visible to every other process
+fn advent_request(session: &str, page: &str, curl_args: &[&str]) -> Result<String, CliError> {
+    let curl_failed = |err: io::Error| CliError::Failed(format!("Failed to run curl: {err}"));
+    let mut curl = Process::new("curl")
+        .args(["--silent", "--show-error", "--fail"])
+        .args(["--user-agent", "github.com/Tom-the-Bomb/aoc-2023"])
+        .args(["--header", "@-"])
+        .args(curl_args)
+        .arg(format!("https://adventofcode.com/{YEAR}/day/{page}"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(curl_failed)?;
+
+    // dropping stdin closes it, so curl stops reading headers
+    if let Some(mut stdin) = curl.stdin.take() {
+        writeln!(stdin, "Cookie: session={session}").map_err(curl_failed)?;
+    }
+    let output = curl
+        .wait_with_output()
+        .map_err(curl_failed)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(CliError::Failed(format!(
+            "Request for day {page} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim(),
+        )))
+    }
+}
+
+fn execute_fetch(config: &Config, day: u8, force: bool) -> Result<(), CliError> {
+    let path = config
+        .inputs_dir()
+        .join(format!("day{day}.txt"));
+    if path.exists() && !force {
+        return Err(CliError::Failed(format!("{} already exists, pass --force to overwrite it", path.display())));
+    }
+    let input = advent_request(&session()?, &format!("{day}/input"), &[])?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, input)?;
+    println!("Input written to {}", path.display());
+    Ok(())
+}
+
+/// The text of a page's `<article>`, where the site explains how an answer went
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+    text
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// The usage of a command, or of the whole CLI
+///
+/// # Errors
+///
+/// If the command does not exist
+pub fn help(command: Option<&str>) -> Result<String, CliError> {
+    let Some(command) = command else {
+        let commands = COMMANDS
+            .iter()
+            .map(|(_, usage, about)| format!("  {usage:<30} {about}\n"))
+            .collect::<String>();
+        return Ok(format!("Usage: aoc-2023 [command] [options]\n\nCommands:\n{commands}\n{OPTIONS}"));
+    };
+
+    COMMANDS
+        .iter()
+        .find(|(name, ..)| *name == command)
+        .map(|(_, usage, about)| format!("Usage: aoc-2023 {usage} [options]\n\n{about}\n\n{OPTIONS}"))
+        .ok_or_else(|| CliError::Usage(format!("Unknown command `{command}`")))
+}
@@ -0,0 +1,113 @@
+//! `aoc-2023 serve`, answering requests through the days' binaries
+
+use std::{
+    io::{Read, Write},
+    process::Stdio,
+    thread,
+    time::{Duration, Instant},
+};
+use super::{is_solved, panic_message, solved_days, Cli, CliError};
+use crate::{
+    serve::{Server, Solve, SolveError, Solved},
+    days, AnswersOutput, Part,
+};
+
+/// How long a day's binary gets to solve a request's input before it's killed
+const SOLVE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often to check whether the binary has finished
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Solves through the days' binaries, passing them the request's input on stdin
+struct BinarySolver {
+    cli: Cli,
+}
+
+impl Solve for BinarySolver {
+    fn days(&self) -> Vec<(u8, String)> {
+        days()
+            .into_iter()
+            .map(|(day, name)| (day, name.to_string()))
+            .collect()
+    }
+
+    fn solve(&self, day: u8, part: Part, input: &str) -> Result<Solved, SolveError> {
+        if !is_solved(day) {
+            return Err(SolveError::NotFound(day));
+        }
+        let (path, mut binary) = self.cli.day_binary(day, &[
+            "--part".to_string(), part.number().to_string(),
+            "--format".to_string(), "json".to_string(),
+            "--input".to_string(), "-".to_string(),
+        ]);
+        let mut child = binary
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| SolveError::Unavailable(format!("Failed to run {}: {err}", path.display())))?;
+
+        // the binary reads all of its input before writing anything
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(input.as_bytes())
+                .map_err(|err| SolveError::Unavailable(format!("Failed to pass the input to day {day}: {err}")))?;
+        }
+        // drained on their own threads so a chatty binary can't fill a pipe and stall while being waited on
+        let drain = |pipe: Option<Box<dyn Read + Send>>| thread::spawn(move || {
+            let mut output = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut output);
+            }
+            output
+        });
+        let stdout = drain(child.stdout.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+        let stderr = drain(child.stderr.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+
+        let deadline = Instant::now() + SOLVE_TIMEOUT;
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) if Instant::now() >= deadline => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(SolveError::TimedOut(SOLVE_TIMEOUT));
+                },
+                Ok(None) => thread::sleep(POLL_INTERVAL),
+                Err(err) => return Err(SolveError::Unavailable(format!("Failed to run {}: {err}", path.display()))),
+            }
+        };
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+        if !status.success() {
+            return Err(SolveError::Failed(panic_message(&String::from_utf8_lossy(&stderr))));
+        }
+
+        let stdout = String::from_utf8_lossy(&stdout);
+        AnswersOutput::from_output(&stdout)
+            .and_then(|output| Some(Solved {
+                answer: output.answer(part)?,
+                time: Duration::from_nanos(output.time_ns),
+            }))
+            .ok_or_else(|| SolveError::Failed(format!("No answer found in the output of day {day}: {stdout}")))
+    }
+}
+
+impl Cli {
+    /// Builds the days, then serves them on localhost until interrupted
+    pub(super) fn execute_serve(&self, port: u16) -> Result<(), CliError> {
+        self.build(None)?;
+
+        let server = Server::bind(("127.0.0.1", port), BinarySolver { cli: self.clone() })?;
+        println!(
+            "Serving {} days on http://{}, press Ctrl+C to stop",
+            solved_days().len(),
+            server.local_addr()?,
+        );
+        println!("  GET  /days");
+        println!("  POST /solve/{{day}}/{{part}} with the input as the body");
+
+        server.run()?;
+        Ok(())
+    }
+}
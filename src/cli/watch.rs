@@ -0,0 +1,182 @@
+//! `aoc-2023 watch <day>`, polling the day's files for changes
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+use super::{bin_path, root, source_path, Cli, CliError};
+use crate::{
+    config::{config_path, Config},
+    AnswersOutput, Part,
+};
+
+/// How often the files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// How long the files must stay unchanged before rebuilding,
+/// so that a save touching several files only triggers one run
+const SETTLE_TIME: Duration = Duration::from_millis(150);
+
+/// The answers and time of one of the day's runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WatchRun {
+    answers: Vec<(Part, u64)>,
+    time: Duration,
+}
+
+impl WatchRun {
+    fn from_output(stdout: &str) -> Option<Self> {
+        let output = AnswersOutput::from_output(stdout)?;
+        let answers = [Part::One, Part::Two]
+            .into_iter()
+            .filter_map(|part| output.answer(part).map(|answer| (part, answer)))
+            .collect();
+        let time = Duration::from_nanos(output.time_ns);
+
+        Some(Self { answers, time })
+    }
+
+    /// Prints the answers and time, marking what changed since the previous run
+    fn print_diff(&self, previous: Option<&Self>) {
+        for (part, answer) in &self.answers {
+            let before = previous.and_then(|previous| previous.answers
+                .iter()
+                .find(|(other, _)| other == part)
+                .map(|(_, answer)| answer)
+            );
+            let change = match before {
+                None => String::new(),
+                Some(before) if before == answer => " (unchanged)".to_string(),
+                Some(before) => format!(" (was {before})"),
+            };
+            println!("Part {}: {answer}{change}", part.number());
+        }
+
+        let change = previous.map_or_else(String::new, |previous| format!(
+            " ({:+.1}% from {:.3?})",
+            100.0 * (self.time.as_secs_f64() / previous.time.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0),
+            previous.time,
+        ));
+        println!("Execution time: {:.3?}{change}", self.time);
+    }
+}
+
+/// Adds the files under the directory to the map with their modification times,
+/// skipping the days' solutions and binaries, which are watched on their own
+fn add_library_files(dir: &Path, times: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+    {
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name != "bin" && name != "days") {
+                add_library_files(&path, times);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            if let Ok(modified) = fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+                times.insert(path, modified);
+            }
+        }
+    }
+}
+
+/// The modification times of the day's solution and binary, the library's sources, `aoc.toml` and the day's inputs,
+/// such as `inputs/day5.txt` and `inputs/day5.example.txt`
+fn modification_times(config: &Config, day: u8) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    add_library_files(&root().join("src"), &mut times);
+
+    let input_prefix = format!("day{day}.");
+    let inputs = fs::read_dir(config.inputs_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(&input_prefix) && name.ends_with(".txt"))
+        );
+
+    for path in inputs.chain([source_path(day), bin_path(day), config_path(root())]) {
+        if let Ok(modified) = fs::metadata(&path).and_then(|metadata| metadata.modified()) {
+            times.insert(path, modified);
+        }
+    }
+    times
+}
+
+/// The files added, removed or modified between two sets of modification times
+fn changed_files(before: &BTreeMap<PathBuf, SystemTime>, after: &BTreeMap<PathBuf, SystemTime>) -> Vec<String> {
+    let root = root();
+    before
+        .keys()
+        .chain(after.keys())
+        .filter(|path| before.get(*path) != after.get(*path))
+        .map(|path| path
+            .strip_prefix(&root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+        )
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect()
+}
+
+impl Cli {
+    /// Rebuilds and runs the day whenever one of its files changes, until interrupted
+    pub(super) fn execute_watch(&self, day: u8) -> Result<(), CliError> {
+        let mut times = BTreeMap::new();
+        let mut previous = None;
+
+        println!("Watching day {day}, press Ctrl+C to stop");
+        loop {
+            let mut current = modification_times(&self.config, day);
+            if current == times {
+                thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+            // wait for the editor to finish writing
+            loop {
+                thread::sleep(SETTLE_TIME);
+                let settled = modification_times(&self.config, day);
+                if settled == current {
+                    break;
+                }
+                current = settled;
+            }
+
+            if !times.is_empty() {
+                println!("\n[{}] changed", changed_files(&times, &current).join(", "));
+            }
+            times = current;
+
+            if let Err(err) = self.build(Some(day)) {
+                println!("{err}");
+                continue;
+            }
+            let mut args = self.day_args.clone();
+            args.extend(["--format".to_string(), "json".to_string()]);
+            let output = self.output_day(day, &args)?;
+            let stdout = String::from_utf8_lossy(&output.stdout);
+
+            match WatchRun::from_output(&stdout).filter(|_| output.status.success()) {
+                Some(run) => {
+                    run.print_diff(previous.as_ref());
+                    previous = Some(run);
+                },
+                None => println!(
+                    "Day {day} failed:\n{stdout}{}",
+                    String::from_utf8_lossy(&output.stderr),
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Project configuration from `aoc.toml`, falling back to the puzzle defaults
+//!
+//! ```toml
+//! [inputs]
+//! dir = "inputs"            # where day{day}.txt is read from, relative to aoc.toml
+//!
+//! [run]
+//! format = "text"           # or "json"
+//! profile = "release"       # or "dev", what the CLI builds and runs
+//! budget_ms = 1000          # warn when a day takes longer
+//!
+//! [day.21]
+//! part_two_steps = 26_501_365
+//! budget_ms = 500
+//! ```
+//!
+//! The solvers never read it: a day's puzzle parameters are passed to it explicitly,
+//! by [`run_day`](crate::run_day) through [`Solution::configure`](crate::Solution::configure)
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+#[cfg(feature = "fs")]
+use std::{fs, io};
+use serde::{de::DeserializeOwned, Deserialize};
+use crate::OutputFormat;
+
+/// Read from the working directory unless `AOC_CONFIG` holds another path
+pub const DEFAULT_CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    Io(PathBuf, String),
+    /// The config is not valid TOML or a setting has the wrong type, with where it is
+    Syntax(String),
+    /// A setting with a value that is out of range or unknown
+    Invalid { key: String, message: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "Failed to read {}: {err}", path.display()),
+            Self::Syntax(message) => write!(f, "{}", message.trim_end()),
+            Self::Invalid { key, message } => write!(f, "`{key}`: {message}"),
+        }
+    }
+}
+
+/// Which cargo profile the CLI builds and runs the days with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    #[default]
+    Release,
+    #[serde(alias = "debug")]
+    Dev,
+}
+
+impl Profile {
+    /// The directory under `target` its binaries are in
+    #[must_use]
+    pub const fn target_dir(self) -> &'static str {
+        match self {
+            Self::Release => "release",
+            Self::Dev => "debug",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct InputsTable {
+    dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RunTable {
+    format: Option<OutputFormat>,
+    profile: Option<Profile>,
+    budget_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+struct DayTable {
+    budget_ms: Option<u64>,
+    /// Every other key, which only the day itself knows
+    #[serde(flatten)]
+    params: toml::Table,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The directory of the config file, which relative paths are resolved against
+    #[serde(skip)]
+    base: PathBuf,
+    inputs: InputsTable,
+    run: RunTable,
+    /// Keyed by the day, checked to be from 1 to 25 when parsed
+    day: BTreeMap<String, DayTable>,
+}
+
+impl Config {
+    /// # Errors
+    ///
+    /// If the config is malformed
+    pub fn parse(raw: &str) -> Result<Self, ConfigError> {
+        let config = toml::from_str::<Self>(raw)
+            .map_err(|err| ConfigError::Syntax(err.to_string()))?;
+
+        if let Some(day) = config.day
+            .keys()
+            .find(|day| day
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .is_none()
+            )
+        {
+            return Err(ConfigError::Invalid {
+                key: format!("day.{day}"),
+                message: "Expected a day from 1 to 25".to_string(),
+            });
+        }
+        Ok(config)
+    }
+
+    /// Reads the config, resolving relative paths against its directory,
+    /// or the defaults if it does not exist
+    ///
+    /// # Errors
+    ///
+    /// If the config exists but could not be read or is malformed
+    #[cfg(feature = "fs")]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let mut config = match fs::read_to_string(path) {
+            Ok(raw) => Self::parse(&raw)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(ConfigError::Io(path.to_path_buf(), err.to_string())),
+        };
+        config.base = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        Ok(config)
+    }
+
+    fn day_table(&self, day: u8) -> Option<&DayTable> {
+        self.day.get(&day.to_string())
+    }
+
+    /// The directory the inputs are in, `inputs` by default
+    #[must_use]
+    pub fn inputs_dir(&self) -> PathBuf {
+        self.base.join(self.inputs.dir
+            .as_deref()
+            .unwrap_or_else(|| Path::new("inputs"))
+        )
+    }
+
+    #[must_use]
+    pub fn format(&self) -> OutputFormat {
+        self.run.format.unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn profile(&self) -> Profile {
+        self.run.profile.unwrap_or_default()
+    }
+
+    /// How long the day should take at most, from its own `budget_ms` or else the `run` one's
+    #[must_use]
+    pub fn budget(&self, day: u8) -> Option<Duration> {
+        self.day_table(day)
+            .and_then(|table| table.budget_ms)
+            .or(self.run.budget_ms)
+            .map(Duration::from_millis)
+    }
+
+    /// The day's puzzle parameters, every key of its table but `budget_ms`
+    #[must_use]
+    pub fn params(&self, day: u8) -> Params {
+        Params {
+            day,
+            values: self
+                .day_table(day)
+                .map(|table| table.params.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// A day's puzzle parameters from its `[day.{day}]` table, such as Day 21's steps,
+/// which the day reads in [`Solution::configure`](crate::Solution::configure)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    day: u8,
+    values: toml::Table,
+}
+
+impl Params {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The parameter, or `default` if not configured
+    ///
+    /// # Errors
+    ///
+    /// If the configured value has the wrong type
+    pub fn get<T: DeserializeOwned>(&self, key: &str, default: T) -> Result<T, ConfigError> {
+        self.values
+            .get(key)
+            .map_or(Ok(default), |value| value
+                .clone()
+                .try_into()
+                .map_err(|err| ConfigError::Invalid {
+                    key: format!("day.{}.{key}", self.day),
+                    message: err.to_string().trim_end().to_string(),
+                })
+            )
+    }
+
+    /// Checks that only the day's own parameters are set, catching typos in their names
+    ///
+    /// # Errors
+    ///
+    /// If another parameter is set
+    pub fn expect_only(&self, keys: &[&str]) -> Result<(), ConfigError> {
+        self.values
+            .keys()
+            .find(|key| !keys.contains(&key.as_str()))
+            .map_or(Ok(()), |key| Err(ConfigError::Invalid {
+                key: format!("day.{}.{key}", self.day),
+                message: if keys.is_empty() {
+                    format!("Day {} has no puzzle parameters", self.day)
+                } else {
+                    format!("Unknown puzzle parameter, expected one of `{}`", keys.join("`, `"))
+                },
+            }))
+    }
+}
+
+/// Where the config is read from, `AOC_CONFIG` or else `aoc.toml` in `dir`
+#[must_use]
+pub fn config_path<P: AsRef<Path>>(dir: P) -> PathBuf {
+    env::var_os("AOC_CONFIG")
+        .map_or_else(|| dir.as_ref().join(DEFAULT_CONFIG_PATH), PathBuf::from)
+}
+
+/// The config of the current process, loaded once from `AOC_CONFIG` or `aoc.toml`,
+/// or only the defaults without the `fs` feature
+///
+/// # Errors
+///
+/// If the config is malformed, with a message naming it
+pub fn try_config() -> Result<&'static Config, &'static str> {
+    static CONFIG: OnceLock<Result<Config, String>> = OnceLock::new();
+
+    CONFIG
+        .get_or_init(|| {
+            #[cfg(feature = "fs")]
+            {
+                let path = config_path("");
+                Config::load(&path)
+                    .map_err(|err| format!("Invalid config {}: {err}", path.display()))
+            }
+            #[cfg(not(feature = "fs"))]
+            Ok(Config::default())
+        })
+        .as_ref()
+        .map_err(String::as_str)
+}
+
+/// See [`try_config`]
+///
+/// # Panics
+///
+/// If the config is malformed
+pub fn config() -> &'static Config {
+    try_config().unwrap_or_else(|err| panic!("{err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(r#"
+            [inputs]
+            dir = "puzzles" # a comment
+
+            [run]
+            format = "json"
+            profile = "debug"
+            budget_ms = 1000
+
+            [day.21]
+            part_two_steps = 26_501_365
+            budget_ms = 500
+
+            [day.24]
+            test_area = [7, 27.5]
+        "#).unwrap();
+
+        assert_eq!(config.inputs_dir(), Path::new("puzzles"));
+        assert_eq!(config.format(), OutputFormat::Json);
+        assert_eq!(config.profile(), Profile::Dev);
+        assert_eq!(config.budget(21), Some(Duration::from_millis(500)));
+        assert_eq!(config.budget(1), Some(Duration::from_secs(1)));
+
+        let params = config.params(21);
+        assert_eq!(params.get("part_two_steps", 0_usize), Ok(26_501_365));
+        assert_eq!(params.get("part_one_steps", 64_usize), Ok(64));
+        assert!(params.get("part_two_steps", 0_u8).is_err());
+        assert!(params.expect_only(&["part_one_steps", "part_two_steps"]).is_ok());
+        assert!(params.expect_only(&["part_one_steps"]).is_err());
+        assert_eq!(config.params(24).get("test_area", (0.0, 0.0)), Ok((7.0, 27.5)));
+        assert!(config.params(1).is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+        assert!(Config::parse("[run]\nformat = \"xml\"").is_err());
+        assert!(Config::parse("[run]\nformt = \"json\"").is_err());
+        assert!(Config::parse("[run]\nbudget_ms = -1").is_err());
+        assert!(Config::parse("[day.26]\nbudget_ms = 1").is_err());
+        assert!(Config::parse("[inputs\ndir = 1").is_err());
+    }
+}
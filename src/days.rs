@@ -0,0 +1,87 @@
+//! Every day's solution, with a registry of them by day
+//!
+//! The binaries in `src/bin`, the benchmarks, the fuzz targets and the wasm module
+//! all run the days from here, which needs no filesystem
+
+use crate::{Part, Solution};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Defines [`days`] and [`solve_part`] over each day's solution, with its puzzle's parameters
+macro_rules! registry {
+    ($($day:literal => $solution:expr),* $(,)?) => {
+        /// The solved days with their puzzle names, in order
+        #[must_use]
+        pub fn days() -> Vec<(u8, &'static str)> {
+            vec![$(($day, Solution::name(&$solution))),*]
+        }
+
+        /// The day's answer to the part, or `None` if the day has no solution
+        ///
+        /// # Panics
+        ///
+        /// If the input is malformed, as the day's parts do
+        #[must_use]
+        pub fn solve_part(day: u8, part: Part, input: &str) -> Option<u64> {
+            match (day, part) {
+                $(
+                    ($day, Part::One) => Some(Solution::part_one(&$solution, input)),
+                    ($day, Part::Two) => Some(Solution::part_two(&$solution, input)),
+                )*
+                _ => None,
+            }
+        }
+    };
+}
+
+registry! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11::default(),
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14::default(),
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21::default(),
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24::default(),
+    25 => day25::Day25,
+}
@@ -0,0 +1,81 @@
+//! Day 1: Trebuchet!?
+//!
+//! <https://adventofcode.com/2023/day/1>
+use std::fmt::Display;
+use crate::{Solution, Parse, ParseError, Span, in_span};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const NAME: &'static str = "Trebuchet!?";
+
+    /// # Panics
+    ///
+    /// If no digits exist on a line
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        inp.to_string()
+            .lines()
+            .map(|line| {
+                let mut digits = line.chars()
+                    .filter_map(|c| c
+                        .to_digit(10)
+                        .map(u64::from)
+                    );
+                let first = digits
+                    .next()
+                    .unwrap();
+                first * 10 + digits
+                    .last()
+                    .unwrap_or(first)
+            })
+            .sum()
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let mut inp = inp.to_string();
+        let map = [
+            ("one", "1"),
+            ("two", "2"),
+            ("three", "3"),
+            ("four", "4"),
+            ("five", "5"),
+            ("six", "6"),
+            ("seven", "7"),
+            ("eight", "8"),
+            ("nine", "9"),
+        ];
+        in_span("spell_out", || for (key, val) in map {
+            inp = inp.replace(key, format!("{key}{val}{key}").as_str());
+        });
+        self.part_one(inp)
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 53651);
+        assert_eq!(p2, 53894);
+    }
+}
+
+impl Parse for Day1 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Error = ParseError;
+
+    /// The lines, which part one reads the digits of and part two spells them out in first,
+    /// so each needs a digit already
+    fn parse_input<'a>(&self, inp: &'a str) -> Result<Vec<&'a str>, ParseError> {
+        Span::new(inp)
+            .lines()
+            .map(|line| if line.as_str().contains(|c: char| c.is_ascii_digit()) {
+                Ok(line.as_str())
+            } else {
+                Err(line.error(format!("no digits in `{line}`")))
+            })
+            .collect()
+    }
+}
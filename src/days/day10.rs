@@ -0,0 +1,229 @@
+//! Day 10: Pipe Maze
+//!
+//! <https://adventofcode.com/2023/day/10>
+use std::{
+    collections::{
+        HashSet,
+        HashMap,
+    },
+    fmt::Display,
+};
+use crate::{Solution, Parse, ParseError, Span, span, in_span, Visualize, Canvas, Color, Polygon, PointLocation};
+
+static GO_LEFT_PIPES: [u8; 3] =
+    [b'-', b'J', b'7'];
+static GO_RIGHT_PIPES: [u8; 3] =
+    [b'-', b'L', b'F'];
+static GO_UP_PIPES: [u8; 3] =
+    [b'|', b'J', b'L'];
+static GO_DOWN_PIPES: [u8; 3] =
+    [b'|', b'7', b'F'];
+
+pub struct Day10;
+
+impl Day10 {
+    /// Checks that 'S' is part of a loop
+    fn parse(inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        let _span = span("parse");
+        let grid = inp
+            .lines()
+            .map(|row| row
+                .as_bytes()
+                .to_vec()
+            )
+            .collect::<Vec<Vec<u8>>>();
+
+        let (row, col) = Self::get_starting_pos(&grid)
+            .ok_or_else(|| Span::new(inp).error("no 'S' character found in grid"))?;
+        match Self::find_loop(&grid) {
+            Some(_) => Ok(grid),
+            None => Err(Span::new(inp)
+                .lines()
+                .nth(row)
+                .map_or(Span::new(inp), |line| line.slice(col..))
+                .error("'S' is not part of a loop")
+            ),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    #[inline]
+    fn get_grid<T: Display>(inp: T) -> Vec<Vec<u8>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn get_starting_pos(grid: &[Vec<u8>]) -> Option<(usize, usize)> {
+        grid
+            .iter()
+            .enumerate()
+            .find_map(|(i, row)| row
+                .iter()
+                .position(|c| *c == b'S')
+                .map(|j| (i, j))
+            )
+    }
+
+    /// Finds the tiles connected to the current tile by pipes that fit together
+    fn get_connections(grid: &[Vec<u8>], (curr_row, curr_col): (usize, usize)) -> Vec<(usize, usize)> {
+        let curr_tile = grid[curr_row][curr_col];
+
+        let neighbors = [
+            (curr_row.wrapping_sub(1), curr_col,
+                GO_UP_PIPES,
+                GO_DOWN_PIPES,
+            ),
+            (curr_row + 1, curr_col,
+                GO_DOWN_PIPES,
+                GO_UP_PIPES,
+            ),
+            (curr_row, curr_col.wrapping_sub(1),
+                GO_LEFT_PIPES,
+                GO_RIGHT_PIPES,
+            ),
+            (curr_row, curr_col + 1,
+                GO_RIGHT_PIPES,
+                GO_LEFT_PIPES,
+            ),
+        ];
+        neighbors
+            .into_iter()
+            .filter_map(|(next_row, next_col, pipes, co_pipes)|
+                grid
+                    .get(next_row)
+                    .and_then(|row| row.get(next_col))
+                    .filter(|&next_tile|
+                        (pipes.contains(&curr_tile) || curr_tile == b'S')
+                        && (co_pipes.contains(next_tile) || *next_tile == b'S')
+                    )
+                    .map(|_| (next_row, next_col))
+            )
+            .collect()
+    }
+
+    /// Walks along the pipes from 'S' until it is reached again,
+    /// returning the tiles of the loop in order, or `None` if 'S' is not part of one
+    fn find_loop(grid: &[Vec<u8>]) -> Option<Vec<(usize, usize)>> {
+        let start = Self::get_starting_pos(grid)?;
+
+        // 'S' may have pipes leading into it which are not part of the loop
+        Self::get_connections(grid, start)
+            .into_iter()
+            .find_map(|first| {
+                let mut path = vec![start, first];
+                loop {
+                    let &[.., prev, curr] = &path[..] else {
+                        unreachable!()
+                    };
+                    let next = Self::get_connections(grid, curr)
+                        .into_iter()
+                        .find(|&next| next != prev)?;
+                    if next == start {
+                        return Some(path);
+                    }
+                    path.push(next);
+                }
+            })
+    }
+
+    /// # Panics
+    ///
+    /// If 'S' is not part of a loop
+    fn get_loop(grid: &[Vec<u8>]) -> Vec<(usize, usize)> {
+        Self::find_loop(grid).expect("'S' is not part of a loop")
+    }
+}
+
+impl Solution for Day10 {
+    const NAME: &'static str = "Pipe Maze";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::get_loop(
+            &Self::get_grid(inp)
+        )
+        .len() as u64 / 2
+    }
+
+    /// # Panics
+    ///
+    /// If the loop does not form a simple polygon
+    #[allow(clippy::cast_possible_wrap)]
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let grid = Self::get_grid(inp);
+
+        Polygon::new(
+            Self::get_loop(&grid)
+                .into_iter()
+                .map(|(row, col)| (row as i64, col as i64))
+        )
+        .expect("Loop is not a simple polygon")
+        .interior_points()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 7063);
+        assert_eq!(p2, 589);
+    }
+}
+
+impl Visualize for Day10 {
+    /// Redraws the loop with box-drawing characters and marks the tiles it encloses
+    ///
+    /// All pipes that are not part of the loop are replaced with a "."
+    #[allow(clippy::cast_possible_wrap)]
+    fn visualize<T: Display>(&self, inp: T) -> Canvas {
+        let mapping = HashMap::from([
+            ('|', '│'),
+            ('-', '─'),
+            ('J', '┘'),
+            ('7', '┐'),
+            ('L', '└'),
+            ('F', '┌'),
+        ]);
+        let grid = Self::get_grid(inp);
+        let path = Self::get_loop(&grid);
+        let nodes = path
+            .iter()
+            .copied()
+            .collect::<HashSet<(usize, usize)>>();
+        let polygon = Polygon::new(
+            path
+                .iter()
+                .map(|&(row, col)| (row as i64, col as i64))
+        )
+        .expect("Loop is not a simple polygon");
+
+        let mut canvas = Canvas::from_grid(&grid);
+        let mut enclosed = Vec::new();
+        canvas.map_glyphs(|(i, j), tile|
+            if nodes.contains(&(i, j)) {
+                *mapping.get(&tile)
+                    .unwrap_or(&tile)
+            } else if polygon.locate((i as i64, j as i64)) == PointLocation::Inside {
+                enclosed.push((i, j));
+                '•'
+            }
+            else { '.' }
+        );
+        canvas.paint(nodes, Color::Cyan);
+        canvas.highlight(enclosed, Color::Green);
+        canvas.highlight([path[0]], Color::Red);
+        canvas
+    }
+}
+
+impl Parse for Day10 {
+    type Parsed<'a> = Vec<Vec<u8>>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        Self::parse(inp)
+    }
+}
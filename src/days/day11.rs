@@ -0,0 +1,194 @@
+//! Day 11: Cosmic Expansion
+//!
+//! <https://adventofcode.com/2023/day/11>
+use std::fmt::Display;
+use crate::{Solution, Parse, ParseError, Span, Params, ConfigError, span, in_span};
+
+/// Expanding each empty row and column into 2 in part one and 1,000,000 in part two by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day11 {
+    part_one_expansion: u64,
+    part_two_expansion: u64,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self::with_params(2, 1_000_000)
+    }
+}
+
+impl Day11 {
+    /// Expands each empty row and column into that many in each part
+    #[must_use]
+    pub const fn with_params(part_one_expansion: u64, part_two_expansion: u64) -> Self {
+        Self { part_one_expansion, part_two_expansion }
+    }
+
+    /// Brute force method
+    /// that expands the universe
+    /// by `+1` row for each empty row and +1 column for each empty colun
+    fn expand_one(mut universe: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+        for _ in 0..2 {
+            let mut new_vec = Vec::with_capacity(universe.len());
+            for row in universe {
+                if !row.contains(&b'#') {
+                    new_vec.push(row.clone());
+                }
+                new_vec.push(row);
+            }
+            // matrix transposal
+            universe = (0..new_vec[0].len())
+                .map(|i| (0..new_vec.len())
+                    .map(|j| new_vec[j][i])
+                    .collect::<Vec<u8>>()
+                )
+                .collect::<Vec<Vec<u8>>>();
+        }
+        universe
+    }
+
+    fn get_galaxies(universe: &[Vec<u8>]) -> Vec<(usize, usize)> {
+        universe
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row
+                .iter()
+                .enumerate()
+                .filter_map(move |(j, galaxy)|
+                    (*galaxy == b'#')
+                        .then_some((i, j))
+                )
+            )
+            .collect::<Vec<(usize, usize)>>()
+    }
+
+    fn parse(inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp).grid(".#")
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_universe<T: Display>(inp: T) -> Vec<Vec<u8>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn get_total_distances<T: Display>(inp: T, expansion_amount: u64) -> u64 {
+        let universe = Self::get_universe(inp);
+        let empty_rows = &universe
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row)|
+                (!row.contains(&b'#'))
+                    .then_some(i)
+            )
+            .collect::<Vec<usize>>();
+
+        let n_rows = universe.len();
+        let n_cols = universe
+            .first()
+            .unwrap()
+            .len();
+
+        let empty_cols = &(0..n_cols)
+            .filter(|j| (0..n_rows)
+                .all(|i| universe[i][*j] != b'#')
+            )
+            .collect::<Vec<usize>>();
+
+        let galaxies = Self::get_galaxies(&universe);
+
+        galaxies
+            .iter()
+            .enumerate()
+            // galaxy 1
+            .flat_map(|(idx, &(i1, j1))|
+                galaxies
+                    .iter()
+                    // effectively equivalent to getting combinations where k=2
+                    .take(idx)
+                    // galaxy 2
+                    .map(move |&(i2, j2)|
+                        (i1.min(i2)..i1.max(i2))
+                            .map(|row|
+                                if empty_rows.contains(&row) { expansion_amount }
+                                else { 1 }
+                            )
+                            .sum::<u64>()
+                        + (j1.min(j2)..j1.max(j2))
+                            .map(|col|
+                                if empty_cols.contains(&col) { expansion_amount }
+                                else { 1 }
+                            )
+                            .sum::<u64>()
+                    )
+            )
+            .sum()
+    }
+
+    /// Brute force solution for Part 1
+    /// Uses the strategy of actually expanding the universe's matrix
+    pub fn part_one_bf<T: Display>(&self, inp: T) -> u64 {
+        let universe = Self::expand_one(
+            Self::get_universe(inp)
+        );
+        let galaxies = Self::get_galaxies(&universe);
+
+        galaxies
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, &(i1, j1))|
+                galaxies
+                    .iter()
+                    .take(idx)
+                    .map(move |&(i2, j2)|
+                        (i2.abs_diff(i1) + j2.abs_diff(j1)) as u64
+                    )
+            )
+            .sum()
+    }
+}
+
+impl Solution for Day11 {
+    const NAME: &'static str = "Cosmic Expansion";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::get_total_distances(inp, self.part_one_expansion)
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::get_total_distances(inp, self.part_two_expansion)
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        assert_eq!(p1, self.part_one_bf(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 9_543_156);
+        assert_eq!(p2, 625_243_292_686);
+    }
+
+    fn configure(&self, params: &Params) -> Result<Option<Self>, ConfigError> {
+        params.expect_only(&["part_one_expansion", "part_two_expansion"])?;
+        let configured = Self::with_params(
+            params.get("part_one_expansion", self.part_one_expansion)?,
+            params.get("part_two_expansion", self.part_two_expansion)?,
+        );
+        Ok((configured != *self).then_some(configured))
+    }
+}
+
+impl Parse for Day11 {
+    type Parsed<'a> = Vec<Vec<u8>>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        Self::parse(inp)
+    }
+}
@@ -0,0 +1,153 @@
+//! Day 12: Hot Springs
+//!
+//! <https://adventofcode.com/2023/day/12>
+use std::fmt::Display;
+use crate::{Solution, Parse, ParseError, Span, span, in_span, trace, Memo};
+
+pub struct Day12;
+
+/// Each row's records and the sizes of its groups of damaged springs
+type Rows = Vec<(Vec<u8>, Vec<usize>)>;
+type Arrangements<'a> = Memo<(&'a [u8], &'a [usize]), u64>;
+
+impl Day12 {
+    fn get_arrangements<'a, T, C>(
+        records: &'a T,
+        criteria: &'a C,
+        memo: &mut Arrangements<'a>,
+    ) -> u64
+    where
+        T: AsRef<[u8]> + ?Sized,
+        C: AsRef<[usize]> + ?Sized,
+    {
+        let records = records
+            .as_ref();
+        let criteria = criteria
+            .as_ref();
+
+        let criteria_empty = criteria
+            .is_empty();
+        if records.is_empty() {
+            return u64::from(criteria_empty)
+        } else if criteria_empty {
+            return u64::from(!records.contains(&b'#'))
+        }
+
+        memo.get_or_compute((records, criteria), |memo| {
+            let mut count = 0;
+            if let Some(&first_record) = records.first() {
+                if let Some(&first_criteria) = criteria.first() {
+                    if first_record == b'.'
+                        || first_record == b'?'
+                    {
+                        count += Self::get_arrangements(
+                            records.get(1..)
+                                .unwrap_or_default(),
+                            criteria,
+                            memo,
+                        );
+                    }
+                    if (first_record == b'#'
+                        || first_record == b'?')
+                        && records.len() >= first_criteria
+                        && !records[..first_criteria].contains(&b'.')
+                        && (first_criteria == records.len()
+                        || records[first_criteria] != b'#')
+                    {
+                        count += Self::get_arrangements(
+                            records.get(first_criteria + 1..)
+                                .unwrap_or(&[]),
+                            criteria.get(1..)
+                                .unwrap_or_default(),
+                            memo,
+                        );
+                    }
+                }
+            }
+            count
+        })
+    }
+
+    /// Reads each row's records and criteria, unfolded into `n_copies` of them
+    fn parse(inp: &str, n_copies: usize) -> Result<Rows, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .lines()
+            .map(|line| {
+                let (records, criteria) = line.split_once(" ")?;
+                if let Some(i) = records.as_str().find(|c| !matches!(c, '.' | '#' | '?')) {
+                    return Err(records
+                        .slice(i..)
+                        .error(format!("invalid records `{records}`, expected only `.`, `#` or `?`"))
+                    );
+                }
+                Ok((
+                    [records.as_str().as_bytes()]
+                        .repeat(n_copies)
+                        .join([b'?'].as_slice()),
+                    criteria
+                        .fields::<usize>(",")?
+                        .repeat(n_copies),
+                ))
+            })
+            .collect()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_rows<T: Display>(inp: T, n_copies: usize) -> Rows {
+        Self::parse(&inp.to_string(), n_copies).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn count_arrangements(rows: &[(Vec<u8>, Vec<usize>)]) -> u64 {
+        let mut memo = Memo::new();
+
+        let total = rows
+            .iter()
+            .map(|(records, criteria)| {
+                // rows are unrelated to one another
+                memo.clear();
+                Self::get_arrangements(records, criteria, &mut memo)
+            })
+            .sum();
+        // alongside the trace's timings, as with `--verbose`
+        if trace::is_tracing() {
+            eprintln!("Arrangements memo: {}", memo.stats());
+        }
+        total
+    }
+}
+
+impl Solution for Day12 {
+    const NAME: &'static str = "Hot Springs";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::count_arrangements(&Self::get_rows(inp, 1))
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::count_arrangements(&Self::get_rows(inp, 5))
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 7007);
+        assert_eq!(p2, 3_476_169_006_222);
+    }
+}
+
+impl Parse for Day12 {
+    type Parsed<'a> = Rows;
+    type Error = ParseError;
+
+    /// The rows as part one reads them, which part two unfolds 5 times over
+    fn parse_input(&self, inp: &str) -> Result<Rows, ParseError> {
+        Self::parse(inp, 1)
+    }
+}
@@ -0,0 +1,125 @@
+//! Day 13: Point of Incidence
+//!
+//! <https://adventofcode.com/2023/day/13>
+use std::fmt::Display;
+use crate::{Solution, Parse, ParseError, Span, span, in_span};
+
+pub struct Day13;
+
+impl Day13 {
+    fn find_mirror<T>(grid: T, smudge: usize) -> usize
+    where
+        T: AsRef<[Vec<u8>]>,
+    {
+        let grid = grid.as_ref();
+
+        for line in 1..grid.len() {
+            let mut left = grid
+                .iter()
+                .take(line)
+                .rev()
+                .collect::<Vec<&Vec<u8>>>();
+            let mut right = grid
+                .iter()
+                .skip(line)
+                .collect::<Vec<&Vec<u8>>>();
+            let left_size = left.len();
+            let right_size = right.len();
+
+            if left_size > right_size {
+                left = left[..right_size]
+                    .to_vec();
+            } else {
+                right = right[..left_size]
+                    .to_vec();
+            }
+
+            if smudge == 0
+                && left == right
+                || left
+                    .into_iter()
+                    .flatten()
+                    .zip(right
+                        .into_iter()
+                        .flatten()
+                    )
+                    .map(|(t1, t2)| usize::from(t1 != t2))
+                    .sum::<usize>()
+                    == smudge
+            {
+                return line;
+            }
+        }
+        0
+    }
+
+    fn parse(inp: &str) -> Result<Vec<Vec<Vec<u8>>>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .sections()
+            .map(|pattern| pattern.grid(".#"))
+            .collect()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_patterns<T: Display>(inp: T) -> Vec<Vec<Vec<u8>>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn get_summary<T: Display>(inp: T, smudge: usize) -> u64 {
+        Self::get_patterns(inp)
+            .into_iter()
+            .map(|grid| {
+                let n_rows = grid.len();
+                let n_cols = grid
+                    .first()
+                    .unwrap()
+                    .len();
+                100 * Self::find_mirror(&grid, smudge) as u64
+                    + Self::find_mirror(
+                        (0..n_cols)
+                            .map(|i| (0..n_rows)
+                                .map(|j| grid[j][i])
+                                .collect::<Vec<u8>>()
+                            )
+                        .collect::<Vec<Vec<u8>>>(),
+                        smudge
+                    ) as u64
+            })
+            .sum()
+    }
+}
+
+impl Solution for Day13 {
+    const NAME: &'static str = "Point of Incidence";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::get_summary(inp, 0)
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::get_summary(inp, 1)
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 35210);
+        assert_eq!(p2, 31974);
+    }
+}
+
+impl Parse for Day13 {
+    type Parsed<'a> = Vec<Vec<Vec<u8>>>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<Vec<u8>>>, ParseError> {
+        Self::parse(inp)
+    }
+}
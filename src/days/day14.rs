@@ -0,0 +1,279 @@
+//! Day 14: Parabolic Reflector Dish
+//!
+//! <https://adventofcode.com/2023/day/14>
+use std::{collections::VecDeque, fmt::Display};
+use crate::{
+    Solution, Parse, ParseError, Span, Params, ConfigError, span, in_span, Visualize, Animate, Animation, Canvas, Color,
+    Explore, ReplCommand, GRID_COMMANDS, grid_command, parse_arg,
+};
+
+/// Spinning the platform 1,000,000,000 cycles in part two by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day14 {
+    cycles: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self::with_params(1_000_000_000)
+    }
+}
+
+type Grid = Vec<Vec<u8>>;
+
+impl Day14 {
+    /// Spins the platform that many cycles in part two
+    #[must_use]
+    pub const fn with_params(cycles: usize) -> Self {
+        Self { cycles }
+    }
+
+    #[inline]
+    fn transpose(grid: &Grid) -> Grid {
+        (0..grid[0].len())
+            .map(|i| (0..grid.len())
+                .map(|j| grid[j][i])
+                .collect::<Vec<u8>>()
+            )
+            .collect::<Grid>()
+    }
+
+    #[inline]
+    fn reverse_rows(grid: &mut Grid) {
+        for row in grid {
+            row.reverse();
+        }
+    }
+
+    fn tilt_lever(grid: &mut Grid) {
+        for row in grid {
+            for i in 0..row.len() {
+                if row[i] != b'O' {
+                    continue;
+                }
+                for t in (1..=i).rev() {
+                    if row[t - 1] != b'.' {
+                        break;
+                    }
+                    row.swap(t, t - 1);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn tilt_north(grid: &Grid) -> Grid {
+        let mut grid = Self::transpose(grid);
+        Self::tilt_lever(&mut grid);
+        Self::transpose(&grid)
+    }
+
+    #[inline]
+    fn tilt_south(grid: &Grid) -> Grid {
+        let mut grid = Self::transpose(grid);
+        Self::reverse_rows(&mut grid);
+        Self::tilt_lever(&mut grid);
+        Self::reverse_rows(&mut grid);
+        Self::transpose(&grid)
+    }
+
+    #[inline]
+    fn tilt_east(grid: &mut Grid) {
+        Self::reverse_rows(grid);
+        Self::tilt_lever(grid);
+        Self::reverse_rows(grid);
+    }
+
+    #[inline]
+    fn cycle(grid: &Grid) -> Grid {
+        let mut grid = Self::tilt_north(grid);
+        Self::tilt_lever(&mut grid);
+        grid = Self::tilt_south(&grid);
+        Self::tilt_east(&mut grid);
+        grid
+    }
+
+    #[inline]
+    fn get_load(grid: &Grid) -> u64 {
+        let n_rows = grid.len();
+
+        #[allow(clippy::naive_bytecount)]
+        grid
+            .iter()
+            .enumerate()
+            .map(|(i, row)|
+                row
+                    .iter()
+                    .filter(|&&tile| tile == b'O')
+                    .count() as u64
+                * (n_rows - i) as u64
+            )
+            .sum()
+    }
+
+    /// Spins the platform until it repeats a previous state,
+    /// calling `on_cycle` with the state after every spin cycle
+    ///
+    /// Returns every state seen and the index where the repetition starts
+    ///
+    /// # Panics
+    ///
+    /// If the cycles vec is empty
+    fn spin<F>(grid: Grid, mut on_cycle: F) -> (VecDeque<Grid>, usize)
+    where
+        F: FnMut(&Grid),
+    {
+        let _span = span("spin");
+        let mut cycles = VecDeque::from([grid]);
+        let start = loop {
+            let next_term = Self::cycle(cycles
+                .back()
+                .unwrap()
+            );
+            on_cycle(&next_term);
+
+            if let Some(index) = cycles
+                .iter()
+                .position(|term| term == &next_term)
+            {
+                break index;
+            }
+            cycles.push_back(next_term);
+        };
+        (cycles, start)
+    }
+
+    /// The platform after `n_cycles` spin cycles,
+    /// skipping ahead through the states once they start repeating
+    fn spin_for(grid: Grid, n_cycles: usize) -> Grid {
+        let (mut cycles, start) = Self::spin(grid, |_| ());
+        let index = if n_cycles < start {
+            n_cycles
+        } else {
+            (n_cycles - start)
+            % (cycles.len() - start)
+            + start
+        };
+        cycles
+            .swap_remove_back(index)
+            .expect("The index is within the states seen")
+    }
+
+    fn draw(grid: &Grid) -> Canvas {
+        let mut canvas = Canvas::from_grid(grid);
+        canvas.paint_glyphs(&['#'], Color::Gray);
+        canvas.paint_glyphs(&['O'], Color::Yellow);
+        canvas
+    }
+
+    fn parse(inp: &str) -> Result<Grid, ParseError> {
+        let _span = span("parse");
+        Span::new(inp).grid(".#O")
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    #[inline]
+    fn get_grid<T: Display>(inp: T) -> Grid {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Solution for Day14 {
+    const NAME: &'static str = "Parabolic Reflector Dish";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::get_load(
+            &Self::tilt_north(
+                &Self::get_grid(inp)
+            )
+        )
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::get_load(
+            &Self::spin_for(Self::get_grid(inp), self.cycles)
+        )
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 111_339);
+        assert_eq!(p2, 93736);
+    }
+
+    fn configure(&self, params: &Params) -> Result<Option<Self>, ConfigError> {
+        params.expect_only(&["cycles"])?;
+        let configured = Self::with_params(
+            params.get("cycles", self.cycles)?,
+        );
+        Ok((configured != *self).then_some(configured))
+    }
+}
+
+impl Visualize for Day14 {
+    /// Draws the platform after tilting it north once
+    fn visualize<T: Display>(&self, inp: T) -> Canvas {
+        Self::draw(
+            &Self::tilt_north(&Self::get_grid(inp))
+        )
+    }
+}
+
+impl Animate for Day14 {
+    /// Records the platform after every spin cycle until its states start repeating
+    fn animate<T: Display>(&self, inp: T, animation: &mut Animation) {
+        let grid = Self::get_grid(inp);
+        animation.push(Self::draw(&grid));
+
+        Self::spin(grid, |grid| animation.record(|| Self::draw(grid)));
+    }
+}
+
+impl Explore for Day14 {
+    fn commands(&self) -> Vec<ReplCommand> {
+        [
+            GRID_COMMANDS.as_slice(),
+            &[
+                ReplCommand::new("tilt", "", "Tilt the platform north"),
+                ReplCommand::new("cycle", "[n]", "Run n spin cycles (one by default)"),
+                ReplCommand::new("load", "", "Print the load on the north support beams"),
+            ],
+        ].concat()
+    }
+
+    fn execute(&self, grid: &mut Grid, command: &str, args: &[&str]) -> Result<String, String> {
+        if let Some(output) = grid_command(grid, command, args) {
+            return output;
+        }
+        match command {
+            "tilt" => {
+                *grid = Self::tilt_north(grid);
+                Ok(format!("Load: {}", Self::get_load(grid)))
+            },
+            "cycle" => {
+                let times = if args.is_empty() { 1 } else { parse_arg::<usize>(args, 0, "number of cycles")? };
+                // however many cycles are asked for, only those until the states repeat are run
+                *grid = Self::spin_for(grid.clone(), times);
+                Ok(format!("Load: {}", Self::get_load(grid)))
+            },
+            "load" => Ok(Self::get_load(grid).to_string()),
+            _ => Err(format!("Unknown command `{command}`")),
+        }
+    }
+}
+
+impl Parse for Day14 {
+    type Parsed<'a> = Grid;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Grid, ParseError> {
+        Self::parse(inp)
+    }
+}
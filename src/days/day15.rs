@@ -0,0 +1,140 @@
+//! Day 15: Lens Library
+//!
+//! <https://adventofcode.com/2023/day/15>
+use std::fmt::Display;
+use crate::{Solution, Parse, ParseError, Span, span, in_span};
+
+pub struct Day15;
+
+impl Day15 {
+    /// Hashing algorithm
+    /// turns any string into an 8-bit integer
+    ///
+    /// `((current_value + ascii_value(character)) * 17) % 256`
+    fn hash<T>(string: T) -> u8
+    where
+        T: AsRef<str>
+    {
+        string
+            .as_ref()
+            .bytes()
+            .fold(0u8, |acc, next| acc
+                .wrapping_add(next)
+                .wrapping_mul(17)
+            )
+    }
+
+    /// Splits the initialization sequence into its steps,
+    /// each either `label=focal_length` or `label-`
+    fn parse(inp: &str) -> Result<Vec<&str>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .split(",")
+            .map(|step| {
+                if let Ok((_, focus)) = step.split_once("=") {
+                    focus
+                        .as_str()
+                        .parse::<u8>()
+                        .map_err(|err| focus.error(format!("invalid focal length `{focus}`: {err}")))?;
+                } else if !step.as_str().ends_with('-') {
+                    return Err(step.error(format!("expected `=` or a trailing `-` in `{step}`")));
+                }
+                Ok(step.as_str())
+            })
+            .collect()
+    }
+}
+
+impl Solution for Day15 {
+    const NAME: &'static str = "Lens Library";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        inp
+            .to_string()
+            .split(',')
+            .map(|s| u64::from(Self::hash(s)))
+            .sum()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let inp = inp.to_string();
+        let mut boxes: [Vec<(&str, u64)>; 256] =
+            std::array::from_fn(|_| Vec::new());
+
+        for string in Self::parse(&inp).unwrap_or_else(|err| panic!("{err}")) {
+            let (label, focus) = string
+                .split_once('=')
+                .unwrap_or_else(||
+                    string
+                        .split_once('-')
+                        .unwrap()
+                );
+            if let Some(map) =
+                boxes.get_mut(Self::hash(label) as usize)
+            {
+                match (
+                    // index of the entry (label, _) if exists
+                    map
+                        .iter()
+                        .position(|(l, _)| *l == label),
+                    focus.parse::<u64>()
+                ) {
+                    // label already exists, focus is a number
+                    // update entry
+                    (Some(i), Ok(focus)) => {
+                        map[i] = (label, focus);
+                    },
+                    // label already exists, focus is none (needs to be removed; ending with '-')
+                    // remove the entry
+                    (Some(i), Err(_)) => {
+                        map.remove(i);
+                    },
+                    // label does not exist, focus is a number
+                    // create new entry
+                    (None, Ok(focus)) => {
+                        map.push((label, focus));
+                    }
+                    // last condition (label doesnt exist, and focus is none (needs to be removed; ending with '-')
+                    // We cant remove a non existing entry anyways
+                    // therefore this will never happen, so we do nothing
+                    _ => (),
+                }
+            }
+        }
+
+        boxes
+            .into_iter()
+            .zip(1..)
+            .flat_map(|(map, i)|
+                map
+                    .into_iter()
+                    .zip(1..)
+                    .map(move |((_, focus), j)| i * j * focus)
+            )
+            .sum()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 508_498);
+        assert_eq!(p2, 279_116);
+    }
+}
+
+impl Parse for Day15 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Error = ParseError;
+
+    /// The steps of the initialization sequence, which part one hashes and part two follows
+    fn parse_input<'a>(&self, inp: &'a str) -> Result<Vec<&'a str>, ParseError> {
+        Self::parse(inp)
+    }
+}
@@ -0,0 +1,242 @@
+//! Day 16: The Floor Will Be Lava
+//!
+//! <https://adventofcode.com/2023/day/16>
+use std::{
+    collections::VecDeque,
+    fmt::Display
+};
+use crate::{Solution, Parse, ParseError, Span, span, in_span, Visualize, Animate, Animation, Canvas, Color, GridBitSet};
+
+pub struct Day16;
+
+impl Day16 {
+    fn parse(inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp).grid(".|-/\\")
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_grid<T: Display>(inp: T) -> Vec<Vec<u8>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    #[inline]
+    const fn direction_index(direction: (i8, i8)) -> usize {
+        match direction {
+            (-1, 0) => 0,
+            (1, 0) => 1,
+            (0, -1) => 2,
+            _ => 3,
+        }
+    }
+
+    /// Follows the beam through the grid one tile at a time,
+    /// calling `on_step` with the tiles energized so far after each step
+    ///
+    /// Returns every tile the beam passes through along with its direction
+    fn energize<F>(
+        grid: &[Vec<u8>],
+        (starting_row, starting_col): (usize, usize),
+        (starting_row_incr, starting_col_incr): (i8, i8),
+        mut on_step: F,
+    ) -> GridBitSet
+    where
+        F: FnMut(&GridBitSet),
+    {
+        let mut energized = GridBitSet::new(
+            grid.len(),
+            grid.first().map_or(0, Vec::len),
+            4,
+        )
+        .expect("Grid is too large to track");
+        let mut to_check = VecDeque::from([(
+            (starting_row, starting_col),
+            (starting_row_incr, starting_col_incr),
+            0,
+        )]);
+        let mut curr_step = 0;
+
+        #[allow(clippy::cast_sign_loss)]
+        while let Some((
+            (mut row, mut col),
+            (mut row_incr, mut col_incr),
+            step,
+        )) = to_check.pop_front()
+        {
+            // every beam front has moved once the first of the next step is reached
+            if step > curr_step {
+                on_step(&energized);
+                curr_step = step;
+            }
+
+            row = row.wrapping_add(row_incr as usize);
+            col = col.wrapping_add(col_incr as usize);
+
+            if let Some(&tile) = grid
+                .get(row)
+                .and_then(|row| row.get(col))
+            {
+                let directions =
+                    if tile == b'-'
+                        && row_incr != 0
+                    {
+                        vec![(0, -1), (0, 1)]
+                    } else if tile == b'|'
+                        && col_incr != 0
+                    {
+                        vec![(-1, 0), (1, 0)]
+                    } else {
+                        (row_incr, col_incr) = match tile {
+                            b'/' => (-col_incr, -row_incr),
+                            b'\\' => (col_incr, row_incr),
+                            _ => (row_incr, col_incr),
+                        };
+                        vec![(row_incr, col_incr)]
+                    };
+
+                for direction in directions {
+                    if energized.insert((row, col), Self::direction_index(direction)) {
+                        to_check.push_back(((row, col), direction, step + 1));
+                    }
+                }
+            }
+        }
+        on_step(&energized);
+
+        energized
+    }
+
+    #[inline]
+    fn get_energized_amount(
+        grid: &[Vec<u8>],
+        starting_row: usize,
+        starting_col: usize,
+        starting_row_incr: i8,
+        starting_col_incr: i8,
+    ) -> u64
+    {
+        Self::energize(
+            grid,
+            (starting_row, starting_col),
+            (starting_row_incr, starting_col_incr),
+            |_| (),
+        )
+        .count_cells() as u64
+    }
+}
+
+impl Solution for Day16 {
+    const NAME: &'static str = "The Floor Will Be Lava";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::get_energized_amount(
+            &Self::get_grid(inp),
+            0, usize::MAX, 0, 1,
+        )
+    }
+
+    /// # Panics
+    ///
+    /// If the grid is empty
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let grid = Self::get_grid(inp);
+
+        let n_rows = grid.len();
+        let n_cols = grid
+            .first()
+            .unwrap()
+            .len();
+
+        (0..n_rows)
+            .map(|row|
+                Self::get_energized_amount(
+                    &grid,
+                    row, usize::MAX, 0, 1
+                )
+                .max(
+                    Self::get_energized_amount(
+                        &grid,
+                        row, n_cols, 0, -1
+                    )
+                )
+            )
+            .max()
+            .and_then(|max_row|
+                (0..n_cols)
+                    .map(|col|
+                        Self::get_energized_amount(
+                            &grid,
+                            usize::MAX, col, 1, 0
+                        )
+                        .max(
+                            Self::get_energized_amount(
+                                &grid,
+                                n_rows, col, -1, 0
+                            )
+                        )
+                    )
+                    .max()
+                    .map(|max_col| max_col.max(max_row))
+            )
+            .unwrap()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 7798);
+        assert_eq!(p2, 8026);
+    }
+}
+
+impl Day16 {
+    fn draw(grid: &[Vec<u8>], energized: &GridBitSet) -> Canvas {
+        let mut canvas = Canvas::from_grid(grid);
+
+        canvas.paint_glyphs(&['/', '\\', '|', '-'], Color::Gray);
+        canvas.highlight(energized.cells(), Color::Yellow);
+        canvas
+    }
+}
+
+impl Visualize for Day16 {
+    /// Draws the tiles energized by the beam entering from the top left
+    fn visualize<T: Display>(&self, inp: T) -> Canvas {
+        let grid = Self::get_grid(inp);
+
+        Self::draw(
+            &grid,
+            &Self::energize(&grid, (0, usize::MAX), (0, 1), |_| ()),
+        )
+    }
+}
+
+impl Animate for Day16 {
+    /// Records the beam entering from the top left spreading out one tile at a time
+    fn animate<T: Display>(&self, inp: T, animation: &mut Animation) {
+        let grid = Self::get_grid(inp);
+
+        let energized = Self::energize(
+            &grid,
+            (0, usize::MAX),
+            (0, 1),
+            |energized| animation.record(|| Self::draw(&grid, energized)),
+        );
+        animation.push(Self::draw(&grid, &energized));
+    }
+}
+
+impl Parse for Day16 {
+    type Parsed<'a> = Vec<Vec<u8>>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        Self::parse(inp)
+    }
+}
@@ -0,0 +1,207 @@
+//! Day 17: Clumsy Crucible
+//!
+//! <https://adventofcode.com/2023/day/17>
+use std::{
+    collections::{BinaryHeap, HashMap, hash_map::Entry},
+    cmp::Reverse,
+    fmt::Display,
+};
+use crate::{Solution, Parse, ParseError, Span, span, in_span, Visualize, Canvas, Color};
+
+pub struct Day17;
+
+/// Steps taken in the current direction, position and direction
+type State = (u8, (usize, usize), (i8, i8));
+
+static ALL_DIRECTIONS: [(i8, i8); 4] =
+    [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+impl Day17 {
+    fn parse(inp: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .grid("0123456789")
+            .map(|grid| grid
+                .into_iter()
+                .map(|row| row
+                    .into_iter()
+                    .map(|tile| usize::from(tile - b'0'))
+                    .collect()
+                )
+                .collect()
+            )
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_grid<T: Display>(inp: T) -> Vec<Vec<usize>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Finds the least heat lost on the way to the bottom right,
+    /// along with the tiles of the path taken
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty for some reason
+    fn find_path<T: Display>(inp: T, is_part_two: bool) -> Option<(usize, Vec<(usize, usize)>)> {
+        let grid = Self::get_grid(inp);
+        let _span = span("search");
+
+        let n_rows = grid.len();
+        let n_cols = grid
+            .first()
+            .unwrap()
+            .len();
+        // each state traversed points back to the one it was reached from
+        let mut traversed: HashMap<State, Option<State>> = HashMap::new();
+        let mut to_check = BinaryHeap::from([
+            Reverse((0usize, 0u8, (0, 0), (0, 0), None))
+        ]);
+        let max_dir_traversed = if is_part_two { 10 } else { 3 };
+
+        while let Some(Reverse((
+            heat, dir_traversed, (row, col), (row_incr, col_incr), prev_entry
+        ))) =
+            to_check.pop()
+        {
+            let set_entry = (dir_traversed, (row, col), (row_incr, col_incr));
+
+            if row == n_rows - 1
+                && col == n_cols - 1
+                && if is_part_two { dir_traversed >= 4 } else { true }
+            {
+                let mut path = vec![(row, col)];
+                let mut prev_entry = prev_entry;
+                while let Some((_, tile, _)) = prev_entry {
+                    path.push(tile);
+                    prev_entry = prev_entry.and_then(|entry| traversed[&entry]);
+                }
+                path.reverse();
+                return Some((heat, path));
+            }
+
+            if let Entry::Vacant(entry) = traversed.entry(set_entry) {
+                entry.insert(prev_entry);
+                let mut directions = Vec::with_capacity(3);
+
+                if if is_part_two {
+                    dir_traversed >= 4
+                    || row_incr == 0
+                    && col_incr == 0
+                } else { true }
+                {
+                    directions.extend(ALL_DIRECTIONS
+                        .iter()
+                        .filter_map(|&(new_row_incr, new_col_incr)|
+                            (
+                                (new_row_incr != row_incr
+                                    || new_col_incr != col_incr)
+                                && (new_row_incr != -row_incr
+                                    || new_col_incr != -col_incr)
+                            )
+                            .then_some(((new_row_incr, new_col_incr), true))
+                        )
+                    );
+                }
+
+                if dir_traversed < max_dir_traversed
+                    && (row_incr != 0 || col_incr != 0)
+                {
+                    directions.push(((row_incr, col_incr), false));
+                }
+
+                #[allow(clippy::cast_sign_loss)]
+                for ((row_incr, col_incr), changed_directions) in directions {
+                    let new_row = row.wrapping_add(row_incr as usize);
+                    let new_col = col.wrapping_add(col_incr as usize);
+
+                    if let Some(&new_heat) = grid
+                        .get(new_row)
+                        .and_then(|row| row.get(new_col))
+                    {
+                        to_check.push(Reverse((
+                            heat + new_heat,
+                            if changed_directions { 1 } else { dir_traversed + 1 },
+                            (new_row, new_col),
+                            (row_incr, col_incr),
+                            Some(set_entry),
+                        )));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Solution for Day17 {
+    const NAME: &'static str = "Clumsy Crucible";
+
+    /// # Panics
+    ///
+    /// If no paths to the end are found (not possible)
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::find_path(inp, false)
+            .expect("No paths found")
+            .0 as u64
+    }
+
+    /// # Panics
+    ///
+    /// If no paths to the end are found (not possible)
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::find_path(inp, true)
+            .expect("No paths found")
+            .0 as u64
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 724);
+        assert_eq!(p2, 877);
+    }
+}
+
+impl Visualize for Day17 {
+    /// Draws the path taken by the crucible in part one
+    ///
+    /// # Panics
+    ///
+    /// If no paths to the end are found (not possible)
+    fn visualize<T: Display>(&self, inp: T) -> Canvas {
+        let inp = inp.to_string();
+        let (_, path) = Self::find_path(&inp, false)
+            .expect("No paths found");
+
+        let mut canvas = Canvas::from_grid(
+            &inp
+                .lines()
+                .collect::<Vec<&str>>()
+        );
+        canvas.paint_glyphs(&['1', '2', '3', '4', '5', '6', '7', '8', '9'], Color::Gray);
+        canvas.highlight(path, Color::Red);
+        canvas
+    }
+}
+
+impl Parse for Day17 {
+    type Parsed<'a> = Vec<Vec<usize>>;
+    type Error = ParseError;
+
+    /// The grid of a full puzzle input, which needs to be at least 5 blocks on a side
+    /// for either crucible to have room to turn its way to the end
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+        let grid = Self::parse(inp)?;
+        if grid.len() < 5 || grid[0].len() < 5 {
+            return Err(Span::new(inp).error("expected a grid of at least 5 by 5 blocks"));
+        }
+        Ok(grid)
+    }
+}
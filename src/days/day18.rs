@@ -0,0 +1,271 @@
+//! Day 18: Lavaduct Lagoon
+//!
+//! <https://adventofcode.com/2023/day/18>
+use std::{
+    iter::once,
+    fmt::Display,
+};
+use crate::{
+    Solution, Parse, ParseError, Span, Visualize, Canvas, Color, Image, Palette, Polygon, PolygonError, span, in_span,
+};
+
+pub struct Day18;
+
+/// Each step's distance and `(x, y)` direction
+type Plan = Vec<(usize, (i64, i64))>;
+
+impl Day18 {
+    /// Walks the dig plan, returning the `(x, y)` corners of the trench
+    fn try_polygon<T>(data: T) -> Result<Polygon, PolygonError>
+    where
+        T: IntoIterator<Item = (usize, (i64, i64))>
+    {
+        let _span = span("polygon");
+        let mut last = (0, 0);
+
+        #[allow(clippy::cast_possible_wrap)]
+        let points = once(last)
+            .chain(data.into_iter().map(|(dist, (dir_x, dir_y))| {
+                last = (
+                    last.0 + dir_x * dist as i64,
+                    last.1 + dir_y * dist as i64,
+                );
+                last
+            }));
+
+        Polygon::new(points)
+    }
+
+    /// # Panics
+    ///
+    /// If the dig plan does not form a simple polygon
+    fn get_polygon<T>(data: T) -> Polygon
+    where
+        T: IntoIterator<Item = (usize, (i64, i64))>
+    {
+        Self::try_polygon(data)
+            .expect("Dig plan is not a simple polygon")
+    }
+
+    /// Checks that the plan digs out a lagoon,
+    /// with a trench short enough that its shoelace sums fit in an `i64`
+    fn check_plan(inp: &str, plan: Plan) -> Result<Plan, ParseError> {
+        let length = plan
+            .iter()
+            .try_fold(0_u32, |length, &(dist, _)| {
+                u32::try_from(dist).ok().and_then(|dist| length.checked_add(dist))
+            });
+        if length.is_none() {
+            return Err(Span::new(inp).error("the trench is too long"));
+        }
+        Self::try_polygon(plan.iter().copied())
+            .map_err(|err| Span::new(inp).error(format!("the dig plan is not a simple polygon: {err:?}")))?;
+        Ok(plan)
+    }
+
+    /// Uses shoelace formula + Pick's theorem to find the total area
+    ///
+    /// `A + b/2 + 1 = i + b`
+    /// where A = shoelace result
+    ///       b = perimeter
+    ///       i + b = desired result
+    fn get_area<T>(data: T) -> u64
+    where
+        T: IntoIterator<Item = (usize, (i64, i64))>
+    {
+        Self::get_polygon(data)
+            .enclosed_points()
+    }
+
+    /// Turns the polygon's `(x, y)` corners into `(row, col)` points with north facing up
+    fn to_rows_cols(polygon: &Polygon) -> Polygon {
+        Polygon::new(
+            polygon
+                .vertices()
+                .iter()
+                .map(|&(x, y)| (-y, x))
+        )
+        .expect("Dig plan is not a simple polygon")
+    }
+
+    /// Reads each line's distance and direction, as `R 6`
+    fn parse_plan(inp: &str) -> Result<Plan, ParseError> {
+        Span::new(inp)
+            .lines()
+            .map(|line| {
+                let mut words = line.words();
+                let (Some(direction), Some(dist)) = (words.next(), words.next()) else {
+                    return Err(line.error(format!("expected a direction and distance in `{line}`")));
+                };
+                let direction = match direction.as_str() {
+                    "U" => (0, 1),
+                    "D" => (0, -1),
+                    "L" => (-1, 0),
+                    "R" => (1, 0),
+                    _ => return Err(direction.error(format!("invalid direction `{direction}`, expected `U`, `D`, `L` or `R`"))),
+                };
+                Ok((dist.parse::<usize>()?, direction))
+            })
+            .collect::<Result<_, _>>()
+            .and_then(|plan| Self::check_plan(inp, plan))
+    }
+
+    /// Reads each line's distance and direction from its hex code, as `(#70c710)`
+    fn parse_hex_plan(inp: &str) -> Result<Plan, ParseError> {
+        Span::new(inp)
+            .lines()
+            .map(|line| {
+                let hexcode = line
+                    .words()
+                    .last()
+                    .ok_or_else(|| line.error("expected a hex code"))?
+                    .strip_prefix("(#")?
+                    .strip_suffix(")")?;
+                if hexcode.as_str().len() != 6 || !hexcode.as_str().is_ascii() {
+                    return Err(hexcode.error(format!("invalid hex code `{hexcode}`, expected 6 hex digits")));
+                }
+                let (dist, direction) = (hexcode.slice(..5), hexcode.slice(5..));
+                let dist = usize::from_str_radix(dist.as_str(), 16)
+                    .map_err(|err| dist.error(format!("invalid distance `{dist}`: {err}")))?;
+                let direction = match direction.as_str() {
+                    "0" => (1, 0),
+                    "1" => (0, -1),
+                    "2" => (-1, 0),
+                    "3" => (0, 1),
+                    _ => return Err(direction.error(format!("invalid direction `{direction}`, expected 0 to 3"))),
+                };
+                Ok((dist, direction))
+            })
+            .collect::<Result<_, _>>()
+            .and_then(|plan| Self::check_plan(inp, plan))
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_plan(inp: &str) -> Plan {
+        Self::parse_plan(inp).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_hex_plan(inp: &str) -> Plan {
+        Self::parse_hex_plan(inp).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Solution for Day18 {
+    const NAME: &'static str = "Lavaduct Lagoon";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::get_area(Self::get_plan(&inp.to_string()))
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::get_area(Self::get_hex_plan(&inp.to_string()))
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 61_865);
+        assert_eq!(p2, 40_343_619_199_142);
+    }
+}
+
+impl Visualize for Day18 {
+    /// Draws the lagoon dug out by following the plan of part one
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    fn visualize<T: Display>(&self, inp: T) -> Canvas {
+        let polygon = Self::to_rows_cols(
+            &Self::get_polygon(Self::get_plan(&inp.to_string()))
+        );
+        let (rows, cols): (Vec<i64>, Vec<i64>) = polygon
+            .vertices()
+            .iter()
+            .copied()
+            .unzip();
+        let (min_row, min_col) = (
+            rows.iter().min().copied().unwrap_or_default(),
+            cols.iter().min().copied().unwrap_or_default(),
+        );
+        let (n_rows, n_cols) = (
+            (rows.iter().max().copied().unwrap_or_default() - min_row + 1) as usize,
+            (cols.iter().max().copied().unwrap_or_default() - min_col + 1) as usize,
+        );
+
+        let mut canvas = Canvas::new(n_rows, n_cols, '.');
+        let to_cell = |(row, col): (i64, i64)| (
+            (row - min_row) as usize,
+            (col - min_col) as usize,
+        );
+
+        let mut trench = Vec::new();
+        for (a, b) in polygon.edges() {
+            let step = ((b.0 - a.0).signum(), (b.1 - a.1).signum());
+            let mut point = a;
+            while point != b {
+                trench.push(to_cell(point));
+                point = (point.0 + step.0, point.1 + step.1);
+            }
+        }
+
+        // the trench only runs along rows and columns,
+        // so each row is inside between every other vertical edge it crosses
+        let mut interior = Vec::new();
+        #[allow(clippy::cast_possible_wrap)]
+        for i in 0..n_rows {
+            let row = i as i64 + min_row;
+            let mut crossings = polygon
+                .edges()
+                .filter(|&((a_row, _), (b_row, _))| a_row.min(b_row) <= row && row < a_row.max(b_row))
+                .map(|((_, col), _)| col)
+                .collect::<Vec<i64>>();
+            crossings.sort_unstable();
+
+            interior.extend((0..n_cols)
+                .filter(|&j| crossings.partition_point(|&col| col < j as i64 + min_col) % 2 == 1)
+                .map(|j| (i, j))
+            );
+        }
+        for &cell in trench.iter().chain(&interior) {
+            if let Some(cell) = canvas.get_mut(cell) {
+                cell.glyph = '#';
+            }
+        }
+        canvas.paint(interior, Color::Blue);
+        canvas.highlight(trench, Color::Yellow);
+        canvas
+    }
+
+    /// Draws the much larger lagoon from part two,
+    /// scaled down so that its longest side is `scale * 250` pixels
+    fn rasterize<T: Display>(&self, inp: T, palette: &Palette, scale: usize) -> Image {
+        Image::from_polygon(
+            &Self::to_rows_cols(
+                &Self::get_polygon(Self::get_hex_plan(&inp.to_string()))
+            ),
+            palette,
+            (Color::Blue, Color::Yellow),
+            scale * 250,
+        )
+    }
+}
+
+impl Parse for Day18 {
+    type Parsed<'a> = (Plan, Plan);
+    type Error = ParseError;
+
+    /// The plans of part one and part two, which the parts read as they dig
+    fn parse_input(&self, inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((
+            Self::parse_plan(inp)?,
+            Self::parse_hex_plan(inp)?,
+        ))
+    }
+}
@@ -0,0 +1,488 @@
+//! Day 19: Aplenty
+//!
+//! <https://adventofcode.com/2023/day/19>
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::Range,
+};
+use serde::Serialize;
+use crate::{Solution, Parse, span, in_span, ParseError, Span, Explore, ReplCommand, parse_arg, Intermediate, Graph};
+
+#[derive(Debug, Clone, Serialize)]
+struct Rule<'a> {
+    key: &'a str,
+    target: &'a str,
+    rhs: u64,
+    is_gt: bool,
+    /// Follows from `is_gt`
+    #[serde(skip)]
+    condition: fn(&u64, &u64) -> bool,
+}
+
+impl<'a> Rule<'a> {
+    fn eval(&self, data: &HashMap<String, u64>) -> bool {
+        data.get(self.key)
+            .map(|key| (self.condition)(key, &self.rhs))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Workflow<'a> {
+    default: &'a str,
+    rules: Vec<Rule<'a>>,
+}
+
+impl<'a> Workflow<'a> {
+    const fn from_default(default: &'a str) -> Self {
+        Self {
+            default,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Reads each rule, as `a<2006:qkq`, sending parts to one of `names`
+    fn with_rules<T>(mut self, rules: T, names: &HashSet<&str>) -> Result<Self, ParseError>
+    where
+        T: Iterator<Item = Span<'a>>
+    {
+        for rule in rules {
+            let (condition, target) = rule
+                .split_once(":")?;
+            let is_gt = condition
+                .as_str()
+                .contains('>');
+            let (key, rhs) = condition.split_once(
+                if is_gt { ">" } else { "<" }
+            )?;
+            if !RATINGS.contains(&key.as_str()) {
+                return Err(key.error(format!("invalid rating `{key}`, expected `x`, `m`, `a` or `s`")));
+            }
+
+            self.rules.push(Rule {
+                key: key.as_str(),
+                target: Self::check_target(target, names)?,
+                is_gt,
+                rhs: u64::from(rhs.parse::<u16>()?),
+                condition: if is_gt { u64::gt } else { u64::lt },
+            });
+        }
+        Ok(self)
+    }
+
+    /// # Errors
+    ///
+    /// If `target` is neither `A`, `R` nor one of `names`
+    fn check_target(target: Span<'a>, names: &HashSet<&str>) -> Result<&'a str, ParseError> {
+        match target.as_str() {
+            "A" | "R" => Ok(target.as_str()),
+            name if names.contains(name) => Ok(name),
+            name => Err(target.error(format!("no workflow named `{name}`"))),
+        }
+    }
+}
+
+type Workflows<'a> = HashMap<String, Workflow<'a>>;
+type Part = HashMap<String, u64>;
+
+/// The categories each part is rated in
+const RATINGS: [&str; 4] = ["x", "m", "a", "s"];
+
+/// The parsed input, as explored in the REPL and dumped
+#[derive(Serialize)]
+pub struct Model<'a> {
+    workflows: Workflows<'a>,
+    parts: Vec<Part>,
+}
+
+/// A part along with the workflows it is sent through
+#[derive(Serialize)]
+pub struct Routed<'a> {
+    part: Part,
+    route: Vec<&'a str>,
+    accepted: bool,
+}
+
+pub struct Day19;
+
+impl Day19 {
+    /// Reads the workflows, which must start from `in`
+    /// and only send parts on to later workflows, so that every part ends up at `A` or `R`
+    fn parse_workflows(raw: Span<'_>) -> Result<Workflows<'_>, ParseError> {
+        let _span = span("parse_workflows");
+        let lines = raw
+            .lines()
+            .map(|line| line.split_once("{"))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        let names = lines
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<HashSet<&str>>();
+        if !names.contains("in") {
+            return Err(raw.error("no workflow named `in` to start from"));
+        }
+
+        let workflows = lines
+            .into_iter()
+            .map(|(name, data)| {
+                let mut data = data
+                    .strip_suffix("}")?
+                    .split(",")
+                    .collect::<Vec<Span>>();
+                let default = data
+                    .pop()
+                    .unwrap();
+                Ok((
+                    name.to_string(),
+                    Workflow::from_default(Workflow::check_target(default, &names)?)
+                        .with_rules(data.into_iter(), &names)?
+                ))
+            })
+            .collect::<Result<Workflows, ParseError>>()?;
+
+        let index = workflows
+            .keys()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect::<HashMap<&str, usize>>();
+        let mut graph = Graph::new(index.len());
+        for (name, workflow) in &workflows {
+            for target in workflow.rules
+                .iter()
+                .map(|rule| rule.target)
+                .chain([workflow.default])
+                .filter_map(|target| index.get(target))
+            {
+                graph.add_edge(index[name.as_str()], *target);
+            }
+        }
+        if graph.topological_order().is_none() {
+            return Err(raw.error("the workflows send parts round in a loop"));
+        }
+        Ok(workflows)
+    }
+
+    fn parse_parts(raw: Span<'_>) -> Result<Vec<Part>, ParseError> {
+        let _span = span("parse_parts");
+        raw
+            .lines()
+            .map(|line|
+                line
+                    .strip_prefix("{")?
+                    .strip_suffix("}")?
+                    .split(",")
+                    .map(|entry| {
+                        let (part, rating) = entry
+                            .key_value("=")?;
+                        Ok((
+                            part.to_string(),
+                            u64::from(rating.parse::<u32>()?),
+                        ))
+                    })
+                    .collect::<Result<Part, ParseError>>()
+            )
+            .collect()
+    }
+
+    fn parse(inp: &str) -> Result<(Workflows<'_>, Vec<Part>), ParseError> {
+        let _span = span("parse");
+        let inp = Span::new(inp);
+        let mut sections = inp.sections();
+        let (workflows, parts) = sections
+            .next()
+            .zip(sections.next())
+            .ok_or_else(|| inp.error("expected workflows and parts separated by a blank line"))?;
+
+        Ok((
+            Self::parse_workflows(workflows)?,
+            Self::parse_parts(parts)?,
+        ))
+    }
+
+    #[must_use]
+    fn is_accepted(
+        workflows: &HashMap<String, Workflow>,
+        group: &HashMap<String, u64>,
+        target: &str,
+    ) -> bool {
+        match target {
+            "A" => true,
+            "R" => false,
+            target => workflows
+                .get(target)
+                .map(|workflow| {
+                    workflow.rules
+                        .iter()
+                        .find_map(|rule|
+                            rule.eval(group)
+                                .then(|| Self::is_accepted(workflows, group, rule.target))
+                        )
+                        .unwrap_or_else(|| Self::is_accepted(workflows, group, workflow.default))
+                })
+                .unwrap_or_default()
+        }
+    }
+
+    /// The workflows a part is sent through from `target`, ending with `A` or `R`
+    /// (or an unknown workflow)
+    fn route<'a>(workflows: &Workflows<'a>, group: &Part, mut target: &'a str) -> Vec<&'a str> {
+        let mut route = vec![target];
+        while let Some(workflow) = workflows
+            .get(target)
+            .filter(|_| route.len() <= workflows.len())
+        {
+            target = workflow.rules
+                .iter()
+                .find(|rule| rule.eval(group))
+                .map_or(workflow.default, |rule| rule.target);
+            route.push(target);
+        }
+        route
+    }
+
+    #[must_use]
+    fn count_range(
+        workflows: &HashMap<String, Workflow>,
+        ranges: &mut HashMap<String, Range<u64>>,
+        target: &str,
+    ) -> u64 {
+        match target {
+            "A" => ranges
+                .values()
+                .map(|range| range.end - range.start)
+                .product(),
+            "R" => 0,
+            target => {
+                let mut total = 0;
+                if let Some(workflow) = workflows
+                    .get(target)
+                {
+                    for rule in &workflow.rules {
+                        let range = ranges
+                            .get(rule.key)
+                            .unwrap();
+                        let true_range =
+                            if rule.is_gt {
+                                range.start.max(rule.rhs + 1)..range.end
+                            } else {
+                                range.start..range.end.min(rule.rhs)
+                            };
+                        let false_range =
+                            if rule.is_gt {
+                                range.start..range.end.min(rule.rhs + 1)
+                            } else {
+                                range.start.max(rule.rhs)..range.end
+                            };
+
+                        if !true_range.is_empty() {
+                            let mut clone = ranges.clone();
+                            clone.insert(
+                                rule.key.to_string(),
+                                true_range,
+                            );
+                            total += Self::count_range(workflows, &mut clone, rule.target);
+                        }
+                        if false_range.is_empty() {
+                            return total;
+                        }
+                        ranges.insert(
+                            rule.key.to_string(),
+                            false_range,
+                        );
+                    }
+                    total += Self::count_range(workflows, ranges, workflow.default);
+                }
+                total
+            }
+        }
+    }
+}
+
+impl Solution for Day19 {
+    const NAME: &'static str = "Aplenty";
+
+    /// # Panics
+    ///
+    /// If failed to parse input (malformed)
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let inp = inp.to_string();
+        let (workflows, parts) = Self::parse(&inp)
+            .unwrap_or_else(|err| panic!("{err}"));
+
+        parts
+            .into_iter()
+            .filter_map(|group|
+                Self::is_accepted(&workflows, &group, "in")
+                    .then(|| group.values().sum::<u64>())
+            )
+            .sum()
+    }
+
+    /// # Panics
+    ///
+    /// If failed to parse input (malformed)
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let inp = inp.to_string();
+        let (workflows, _) = Self::parse(&inp)
+            .unwrap_or_else(|err| panic!("{err}"));
+
+        Self::count_range(
+            &workflows,
+            &mut HashMap::from([
+                ("x".to_string(), 1..4001),
+                ("m".to_string(), 1..4001),
+                ("a".to_string(), 1..4001),
+                ("s".to_string(), 1..4001),
+            ]),
+            "in",
+        )
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 362_930);
+        assert_eq!(p2, 116_365_820_987_729);
+    }
+}
+
+impl Day19 {
+    fn format_part(group: &Part) -> String {
+        let ratings = RATINGS
+            .into_iter()
+            .filter_map(|key| group
+                .get(key)
+                .map(|rating| format!("{key}={rating}"))
+            )
+            .collect::<Vec<String>>();
+        format!("{{{}}}", ratings.join(","))
+    }
+
+    /// A part given either as its index in the input or as its ratings, e.g. `x=1,m=2,a=3,s=4`
+    fn get_part(parts: &[Part], arg: &str) -> Result<Part, String> {
+        if let Ok(index) = arg.parse::<usize>() {
+            return parts
+                .get(index)
+                .cloned()
+                .ok_or_else(|| format!("There are only {} parts", parts.len()));
+        }
+        let line = format!("{{{}}}", arg.trim_start_matches('{').trim_end_matches('}'));
+        Self::parse_parts(Span::new(&line))
+            .map_err(|err| err.to_string())?
+            .pop()
+            .ok_or_else(|| "Missing part".to_string())
+    }
+}
+
+impl Explore for Day19 {
+    fn commands(&self) -> Vec<ReplCommand> {
+        vec![
+            ReplCommand::new("workflows", "", "List the workflows"),
+            ReplCommand::new("show", "<workflow>", "Print a workflow's rules"),
+            ReplCommand::new("parts", "", "List the parts and whether they are accepted"),
+            ReplCommand::new("eval", "<index|x=..,m=..,a=..,s=..>", "Trace a part through the workflows"),
+            ReplCommand::new("count", "[workflow]", "Count the ratings accepted from a workflow (`in` by default)"),
+        ]
+    }
+
+    fn execute(&self, model: &mut Model<'_>, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "workflows" => {
+                let mut names = model.workflows
+                    .keys()
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>();
+                names.sort_unstable();
+                Ok(format!("{} workflows: {}", names.len(), names.join(" ")))
+            },
+            "show" => {
+                let name = parse_arg::<String>(args, 0, "workflow")?;
+                let workflow = model.workflows
+                    .get(&name)
+                    .ok_or_else(|| format!("No workflow named `{name}`"))?;
+                let rules = workflow.rules
+                    .iter()
+                    .map(|rule| format!(
+                        "{}{}{}:{}",
+                        rule.key,
+                        if rule.is_gt { '>' } else { '<' },
+                        rule.rhs,
+                        rule.target,
+                    ))
+                    .chain([workflow.default.to_string()])
+                    .collect::<Vec<String>>();
+                Ok(format!("{name}{{{}}}", rules.join(",")))
+            },
+            "parts" => Ok(model.parts
+                .iter()
+                .enumerate()
+                .map(|(i, group)| format!(
+                    "{i:>4} {} {}",
+                    Self::format_part(group),
+                    if Self::is_accepted(&model.workflows, group, "in") { "accepted" } else { "rejected" },
+                ))
+                .collect::<Vec<String>>()
+                .join("\n")
+            ),
+            "eval" => {
+                let group = Self::get_part(&model.parts, &parse_arg::<String>(args, 0, "part")?)?;
+                let route = Self::route(&model.workflows, &group, "in");
+                let verdict = match route.last() {
+                    Some(&"A") => format!("accepted, rating {}", group.values().sum::<u64>()),
+                    Some(&"R") => "rejected".to_string(),
+                    _ => "stuck".to_string(),
+                };
+                Ok(format!("{} {}: {verdict}", Self::format_part(&group), route.join(" -> ")))
+            },
+            "count" => {
+                let start = args
+                    .first()
+                    .copied()
+                    .unwrap_or("in");
+                if !model.workflows.contains_key(start) {
+                    return Err(format!("No workflow named `{start}`"));
+                }
+                Ok(Self::count_range(
+                    &model.workflows,
+                    &mut RATINGS
+                        .into_iter()
+                        .map(|key| (key.to_string(), 1..4001))
+                        .collect(),
+                    start,
+                ).to_string())
+            },
+            _ => Err(format!("Unknown command `{command}`")),
+        }
+    }
+}
+
+impl Parse for Day19 {
+    type Parsed<'a> = Model<'a>;
+    type Error = ParseError;
+
+    fn parse_input<'a>(&self, inp: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        Self::parse(inp)
+            .map(|(workflows, parts)| Model { workflows, parts })
+    }
+
+    /// Part one's routes
+    fn intermediate(&self, model: Model<'_>) -> Option<Intermediate> {
+        let routed = model.parts
+            .into_iter()
+            .map(|part| {
+                let route = Self::route(&model.workflows, &part, "in");
+                Routed {
+                    accepted: route.last() == Some(&"A"),
+                    part,
+                    route,
+                }
+            })
+            .collect::<Vec<Routed>>();
+        Some(serde_json::to_value(routed))
+    }
+}
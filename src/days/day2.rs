@@ -0,0 +1,110 @@
+//! Day 2: Cube Conundrum
+//!
+//! <https://adventofcode.com/2023/day/2>
+use std::fmt::Display;
+use crate::{Solution, Parse, span, in_span, ParseError, Span};
+
+/// The amount of `(red, green, blue)` cubes revealed in a single play
+type Play = (u64, u64, u64);
+
+pub struct Day2;
+
+impl Day2 {
+    fn parse_games(inp: &str) -> Result<Vec<(u64, Vec<Play>)>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .lines()
+            .map(|line| {
+                let (game_id, plays) = line
+                    .split_once(":")?;
+                let game_id = game_id
+                    .strip_prefix("Game ")?
+                    .parse::<u64>()?;
+
+                let plays = plays
+                    .split(";")
+                    .map(|play| {
+                        let (mut red, mut green, mut blue) = (0, 0, 0);
+
+                        for color in play.split(",") {
+                            let (num, name) = color
+                                .trim()
+                                .split_once(" ")?;
+                            let num = num
+                                .parse::<u64>()?;
+                            match name.as_str() {
+                                "red" => red += num,
+                                "green" => green += num,
+                                "blue" => blue += num,
+                                _ => return Err(name.error(format!("unknown color `{name}`"))),
+                            }
+                        }
+                        Ok((red, green, blue))
+                    })
+                    .collect::<Result<Vec<Play>, ParseError>>()?;
+                Ok((game_id, plays))
+            })
+            .collect()
+    }
+}
+
+impl Solution for Day2 {
+    const NAME: &'static str = "Cube Conundrum";
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::parse_games(&inp.to_string())
+            .unwrap_or_else(|err| panic!("{err}"))
+            .into_iter()
+            .filter(|(_, plays)| plays
+                .iter()
+                .all(|&(red, green, blue)|
+                    red <= 12 && green <= 13 && blue <= 14
+                )
+            )
+            .map(|(game_id, _)| game_id)
+            .sum()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::parse_games(&inp.to_string())
+            .unwrap_or_else(|err| panic!("{err}"))
+            .into_iter()
+            .map(|(_, plays)| {
+                let (red, green, blue) = plays
+                    .into_iter()
+                    .fold((0, 0, 0), |(red, green, blue), play| (
+                        red.max(play.0),
+                        green.max(play.1),
+                        blue.max(play.2),
+                    ));
+                red * green * blue
+            })
+            .sum()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 2486);
+        assert_eq!(p2, 87984);
+    }
+}
+
+impl Parse for Day2 {
+    type Parsed<'a> = Vec<(u64, Vec<Play>)>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<(u64, Vec<Play>)>, ParseError> {
+        Self::parse_games(inp)
+    }
+}
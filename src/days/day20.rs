@@ -0,0 +1,464 @@
+//! Day 20: Pulse Propagation
+//!
+//! <https://adventofcode.com/2023/day/20>
+use std::{
+    collections::{VecDeque, HashMap},
+    fmt::Display,
+};
+use serde::Serialize;
+use crate::{Solution, Parse, ParseError, Span, span, in_span, Cycle, Explore, ReplCommand, parse_arg};
+
+#[derive(Debug, Clone)]
+struct Destination<'a> {
+    name: &'a str,
+    target: String,
+    pulse: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+enum Module<'a> {
+    Flipper {
+        name: &'a str,
+        outputs: Vec<String>,
+        status: bool,
+    },
+    Conjunction {
+        name: &'a str,
+        outputs: Vec<String>,
+        memory: HashMap<String, bool>,
+    }
+}
+
+impl<'a> Module<'a> {
+    fn new(raw: Span<'a>, outputs: Vec<String>) -> Result<Self, ParseError> {
+        if let Some(name) = raw.as_str().strip_prefix('%') {
+            Ok(Self::Flipper {
+                name, outputs,
+                status: false,
+            })
+        } else if let Some(name) = raw.as_str().strip_prefix('&') {
+            Ok(Self::Conjunction {
+                name, outputs,
+                memory: HashMap::new(),
+            })
+        } else {
+            Err(raw.error(format!("invalid module `{raw}`, expected `%` or `&` before its name")))
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    const fn name(&self) -> &'a str {
+        match self {
+            Self::Flipper { name, .. }
+            | Self::Conjunction { name, .. } => name,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    const fn outputs(&self) -> &Vec<String> {
+        match self {
+            Self::Flipper { outputs, .. }
+            | Self::Conjunction { outputs, .. } => outputs,
+        }
+    }
+}
+
+/// The module network part way through some button presses, as explored in the REPL
+#[derive(Serialize)]
+pub struct Model<'a> {
+    modules: HashMap<String, Module<'a>>,
+    broadcast_targets: Vec<String>,
+    n_presses: usize,
+}
+
+pub struct Day20;
+
+/// The most presses part two waits for each feeder's states to repeat,
+/// well beyond the 4096 of the puzzle's 12-bit counters
+const MAX_PRESSES: u64 = 1 << 20;
+
+impl Day20 {
+    fn parse(inp: &str) -> Result<(HashMap<String, Module<'_>>, Vec<String>), ParseError> {
+        let _span = span("parse");
+        let mut modules = HashMap::new();
+        let mut broadcast_targets = Vec::new();
+
+        for line in Span::new(inp)
+            .lines()
+        {
+            let (name, targets) = line
+                .split_once("->")?;
+            let targets = targets
+                .as_str()
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .collect::<Vec<String>>();
+
+            match name.trim().as_str() {
+                "broadcaster" => broadcast_targets = targets,
+                _ => {
+                    let module = Module::new(name.trim(), targets)?;
+                    modules.insert(
+                        module.name().to_string(),
+                        module,
+                    );
+                }
+            }
+        }
+
+        for (name, module) in modules
+            .clone()
+        {
+            for output in module
+                .outputs()
+            {
+                if let Some(Module::Conjunction {
+                    memory, ..
+                }) = modules.get_mut(output) {
+                    memory.insert(
+                        name.to_string(),
+                        false
+                    );
+                }
+            }
+        }
+        Ok((modules, broadcast_targets))
+    }
+
+    /// The conjunction which sends pulses to `rx`, along with the modules feeding into it,
+    /// whose high pulses part two waits to line up
+    fn find_feeders(modules: &HashMap<String, Module>) -> Result<(String, Vec<String>), String> {
+        let mut rx_feeders = modules
+            .values()
+            .filter(|module| module.outputs().iter().any(|output| output == "rx"));
+        match (rx_feeders.next(), rx_feeders.next()) {
+            (Some(Module::Conjunction { name, memory, .. }), None) if !memory.is_empty() => Ok((
+                name.to_string(),
+                memory.keys().cloned().collect(),
+            )),
+            (Some(Module::Conjunction { name, .. }), None) => Err(format!("nothing feeds into `{name}`, which feeds into `rx`")),
+            (Some(Module::Flipper { name, .. }), None) => Err(format!("expected a conjunction to feed into `rx`, found flip-flop `{name}`")),
+            (None, _) => Err("no module feeds into `rx`".to_string()),
+            (Some(_), Some(_)) => Err("expected a single module to feed into `rx`".to_string()),
+        }
+    }
+
+    /// The part of the network that can send pulses on to `name`, including itself,
+    /// which only ever receives pulses from within and from the broadcaster
+    fn upstream<'a>(modules: &HashMap<String, Module<'a>>, name: &str) -> HashMap<String, Module<'a>> {
+        let mut network = HashMap::new();
+        let mut to_visit = vec![name];
+        while let Some(name) = to_visit.pop() {
+            if let Some(module) = modules
+                .get(name)
+                .filter(|_| !network.contains_key(name))
+            {
+                network.insert(name.to_string(), module.clone());
+                to_visit.extend(modules
+                    .iter()
+                    .filter(|(_, module)| module.outputs().iter().any(|output| output == name))
+                    .map(|(input, _)| input.as_str())
+                );
+            }
+        }
+        network
+    }
+
+    /// The flip-flops' statuses and the conjunctions' memories, in the order of `names`
+    fn state(modules: &HashMap<String, Module>, names: &[String]) -> Vec<bool> {
+        names
+            .iter()
+            .flat_map(|name| match &modules[name] {
+                Module::Flipper { status, .. } => vec![*status],
+                Module::Conjunction { memory, .. } => {
+                    let mut inputs = memory
+                        .iter()
+                        .collect::<Vec<(&String, &bool)>>();
+                    inputs.sort_unstable();
+                    inputs
+                        .into_iter()
+                        .map(|(_, &pulse)| pulse)
+                        .collect()
+                },
+            })
+            .collect()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_modules(inp: &str) -> (HashMap<String, Module<'_>>, Vec<String>) {
+        Self::parse(inp).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn run_modules<'a>(
+        destinations: &mut VecDeque<Destination<'a>>,
+        mut module: &mut Module<'a>,
+        source: &'a str,
+        pulse: bool,
+    ) {
+        let pulse_to_send = match (&mut module, pulse) {
+            (Module::Flipper { status, .. }, false) => {
+                *status = !*status;
+                *status
+            },
+            (Module::Conjunction { memory, .. }, pulse) => {
+                memory.insert(source.to_string(), pulse);
+                !memory
+                    .values()
+                    .all(|x| *x)
+            },
+            _ => return,
+        };
+
+        for output in module.outputs() {
+            destinations.push_back(
+                Destination {
+                    target: output.to_string(),
+                    pulse: pulse_to_send,
+                    name: module.name(),
+                }
+            );
+        }
+    }
+
+    /// Presses the button once, sending pulses through the modules until they settle,
+    /// with `on_pulse` seeing each pulse before it is delivered
+    fn press<'a, F>(
+        modules: &mut HashMap<String, Module<'a>>,
+        broadcast_targets: &[String],
+        mut on_pulse: F,
+    )
+    where
+        F: FnMut(&Destination<'a>),
+    {
+        let mut destinations =
+            Self::get_destinations(broadcast_targets);
+
+        while let Some(source) = destinations.pop_front() {
+            on_pulse(&source);
+
+            if let Some(module) = modules.get_mut(&source.target) {
+                Self::run_modules(
+                    &mut destinations,
+                    module,
+                    source.name,
+                    source.pulse,
+                );
+            }
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    fn get_destinations<'a, T>(broadcast_targets: T) -> VecDeque<Destination<'a>>
+    where
+        T: AsRef<[String]>,
+    {
+        broadcast_targets
+            .as_ref()
+            .iter()
+            .map(|target| Destination {
+                target: target.to_string(),
+                pulse: false,
+                name: "broadcaster",
+            })
+            .collect::<VecDeque<Destination>>()
+    }
+}
+
+impl Solution for Day20 {
+    const NAME: &'static str = "Pulse Propagation";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let inp = inp.to_string();
+        let (mut modules, broadcast_targets) =
+            Self::get_modules(&inp);
+
+        let mut n_low = 0;
+        let mut n_high = 0;
+
+        for _ in 0..1000 {
+            n_low += 1;
+            Self::press(&mut modules, &broadcast_targets, |source| {
+                if source.pulse {
+                    n_high += 1;
+                } else {
+                    n_low += 1;
+                }
+            });
+        }
+        n_low * n_high
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed, or has no single conjunction feeding into 'rx',
+    /// if the modules feeding it do not repeat their states within [`MAX_PRESSES`],
+    /// or if they never send high pulses on the same press
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let inp = inp.to_string();
+        let (modules, broadcast_targets) =
+            Self::get_modules(&inp);
+
+        let (rx_feeder, feeders) = Self::find_feeders(&modules)
+            .unwrap_or_else(|err| panic!("{err}"));
+
+        // each feeder is driven by its own part of the network,
+        // which goes round in a cycle of states, so its high pulses do too
+        let cycles = feeders
+            .iter()
+            .map(|feeder| {
+                let mut network = Self::upstream(&modules, feeder);
+                let mut names = network
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<String>>();
+                names.sort_unstable();
+
+                Cycle::find(Self::state(&network, &names), MAX_PRESSES, |_| {
+                    let mut fired = false;
+                    Self::press(&mut network, &broadcast_targets, |source| {
+                        fired |= source.pulse && source.name == feeder && source.target == rx_feeder;
+                    });
+                    (Self::state(&network, &names), fired)
+                })
+                .unwrap_or_else(|| panic!("`{feeder}` does not repeat within {MAX_PRESSES} presses"))
+            })
+            .collect::<Vec<Cycle>>();
+
+        // the cycles count the presses before each one from 0
+        Cycle::first_common(&cycles)
+            .unwrap_or_else(|err| panic!("Failed to line up the feeders: {err}"))
+            .expect("feeder cycles never line up")
+            + 1
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 680_278_040);
+        assert_eq!(p2, 243_548_140_870_057);
+    }
+}
+
+impl Day20 {
+    fn describe(name: &str, module: &Module) -> String {
+        match module {
+            Module::Flipper { outputs, status, .. } => format!(
+                "%{name} ({}) -> {}",
+                if *status { "on" } else { "off" },
+                outputs.join(", "),
+            ),
+            Module::Conjunction { outputs, memory, .. } => {
+                let mut inputs = memory
+                    .iter()
+                    .map(|(input, pulse)| format!("{input}={}", if *pulse { "high" } else { "low" }))
+                    .collect::<Vec<String>>();
+                inputs.sort_unstable();
+                format!("&{name} [{}] -> {}", inputs.join(" "), outputs.join(", "))
+            },
+        }
+    }
+
+    fn sorted_names<'m>(modules: &'m HashMap<String, Module>) -> Vec<&'m String> {
+        let mut names = modules
+            .keys()
+            .collect::<Vec<&String>>();
+        names.sort_unstable();
+        names
+    }
+}
+
+impl Explore for Day20 {
+    fn commands(&self) -> Vec<ReplCommand> {
+        vec![
+            ReplCommand::new("modules", "", "List the modules with their states and outputs"),
+            ReplCommand::new("show", "<module>", "Print a module's state and outputs"),
+            ReplCommand::new("press", "[n]", "Press the button n times (once by default), counting the pulses sent"),
+            ReplCommand::new("until", "<module>", "Press the button until the module sends a high pulse"),
+            ReplCommand::new("state", "", "List the flip-flops that are on"),
+            ReplCommand::new("presses", "", "Print how many times the button was pressed"),
+        ]
+    }
+
+    fn execute(&self, model: &mut Model<'_>, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "modules" => Ok(Self::sorted_names(&model.modules)
+                .into_iter()
+                .map(|name| Self::describe(name, &model.modules[name]))
+                .collect::<Vec<String>>()
+                .join("\n")
+            ),
+            "show" => {
+                let name = parse_arg::<String>(args, 0, "module")?;
+                model.modules
+                    .get(&name)
+                    .map(|module| Self::describe(&name, module))
+                    .ok_or_else(|| format!("No module named `{name}`"))
+            },
+            "press" => {
+                let times = if args.is_empty() { 1 } else { parse_arg::<usize>(args, 0, "number of presses")? };
+                let (mut n_low, mut n_high) = (times, 0);
+                for _ in 0..times {
+                    Self::press(&mut model.modules, &model.broadcast_targets, |source| {
+                        if source.pulse {
+                            n_high += 1;
+                        } else {
+                            n_low += 1;
+                        }
+                    });
+                }
+                model.n_presses += times;
+                Ok(format!("{n_low} low and {n_high} high pulses sent, {} presses in total", model.n_presses))
+            },
+            "until" => {
+                let name = parse_arg::<String>(args, 0, "module")?;
+                if !model.modules.contains_key(&name) {
+                    return Err(format!("No module named `{name}`"));
+                }
+                // a module that never fires would keep the REPL pressing forever
+                for _ in 0..1_000_000 {
+                    let mut fired = false;
+                    Self::press(&mut model.modules, &model.broadcast_targets, |source| {
+                        fired |= source.pulse && source.name == name;
+                    });
+                    model.n_presses += 1;
+                    if fired {
+                        return Ok(format!("{name} sent a high pulse on press {}", model.n_presses));
+                    }
+                }
+                Err(format!("{name} sent no high pulse within a million presses"))
+            },
+            "state" => {
+                let on = Self::sorted_names(&model.modules)
+                    .into_iter()
+                    .filter(|name| matches!(model.modules[*name], Module::Flipper { status: true, .. }))
+                    .map(String::as_str)
+                    .collect::<Vec<&str>>();
+                Ok(format!("{} flip-flops on: {}", on.len(), on.join(" ")))
+            },
+            "presses" => Ok(model.n_presses.to_string()),
+            _ => Err(format!("Unknown command `{command}`")),
+        }
+    }
+}
+
+impl Parse for Day20 {
+    type Parsed<'a> = Model<'a>;
+    type Error = ParseError;
+
+    /// The modules of a full puzzle input, with a single conjunction feeding into `rx` for part two
+    fn parse_input<'a>(&self, inp: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        let (modules, broadcast_targets) = Self::parse(inp)?;
+        Self::find_feeders(&modules)
+            .map_err(|err| Span::new(inp).error(err))?;
+        Ok(Model { modules, broadcast_targets, n_presses: 0 })
+    }
+}
@@ -0,0 +1,365 @@
+//! Day 21: Step Counter
+//!
+//! <https://adventofcode.com/2023/day/21>
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+use crate::{
+    Solution, Parse, ParseError, Span, Params, ConfigError, span, in_span, Visualize, Animate, Animation, Canvas, Color, GridBitSet, Polynomial,
+    Explore, ReplCommand, GRID_COMMANDS, grid_command, parse_arg,
+};
+
+/// The longest side of the square around the start that is tracked with a bitset,
+/// about 32 MiB of it, past which the plots visited are hashed instead
+const MAX_DENSE_SIDE: usize = 1 << 14;
+
+/// The most steps the REPL's `reach` traverses, which takes a few seconds
+const MAX_REACH_STEPS: usize = 5_000;
+
+/// The plots visited so far, by their position within the square around the start
+enum Traversed {
+    Dense(GridBitSet),
+    Sparse(HashSet<(usize, usize)>),
+}
+
+impl Traversed {
+    fn new(side: usize) -> Self {
+        match (side <= MAX_DENSE_SIDE)
+            .then(|| GridBitSet::new(side, side, 1))
+            .flatten()
+        {
+            Some(bits) => Self::Dense(bits),
+            None => Self::Sparse(HashSet::new()),
+        }
+    }
+
+    /// Returns whether the plot was not already visited
+    fn insert(&mut self, plot: (usize, usize)) -> bool {
+        match self {
+            Self::Dense(bits) => bits.insert(plot, 0),
+            Self::Sparse(plots) => plots.insert(plot),
+        }
+    }
+}
+
+/// Taking 64 steps in part one and 26,501,365 in part two by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day21 {
+    part_one_steps: usize,
+    part_two_steps: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self::with_params(64, 26_501_365)
+    }
+}
+
+impl Day21 {
+    /// Takes that many steps in each part
+    #[must_use]
+    pub const fn with_params(part_one_steps: usize, part_two_steps: usize) -> Self {
+        Self { part_one_steps, part_two_steps }
+    }
+
+    fn get_starting_pos(grid: &[Vec<u8>]) -> (usize, usize) {
+        for (i, row) in grid
+            .iter()
+            .enumerate()
+        {
+            if let Some(j) = row
+                .iter()
+                .position(|c| *c == b'S')
+            {
+                return (i, j)
+            }
+        }
+        panic!("No 'S' character found in grid")
+    }
+
+    fn parse(inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        let _span = span("parse");
+        let grid = Span::new(inp).grid(".#S")?;
+        if grid.iter().flatten().any(|&tile| tile == b'S') {
+            Ok(grid)
+        } else {
+            Err(Span::new(inp).error("no 'S' character found in grid"))
+        }
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    #[inline]
+    fn get_grid<T: Display>(inp: T) -> Vec<Vec<u8>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Counts the plots reachable in exactly `steps` steps,
+    /// passing each plot visited along the way to `on_visit`
+    /// as its position on the original grid and the fewest steps needed to get there
+    ///
+    /// # Panics
+    ///
+    /// If the square of plots within `steps` of the start has more than [`usize::MAX`] on a side
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    fn traverse<F>(
+        grid: &[Vec<u8>],
+        (start_row, start_col): (usize, usize),
+        steps: usize,
+        mut on_visit: F,
+    ) -> u64
+    where
+        F: FnMut((usize, usize), usize),
+    {
+        let n_rows = grid.len() as isize;
+        let n_cols = grid
+            .first()
+            .unwrap()
+            .len() as isize;
+
+        // no plot further than `steps` away from the start can be reached,
+        // so positions are tracked within a square of that radius around it
+        let side = steps
+            .checked_mul(2)
+            .and_then(|diameter| diameter.checked_add(1))
+            .expect("Too many steps to track");
+        let center = (steps, steps);
+
+        let mut to_check = VecDeque::from([
+            (center, steps),
+        ]);
+        let mut traversed = Traversed::new(side);
+        traversed.insert(center);
+        let mut n_reached = 0;
+
+        // wrap back around onto the repeating grid
+        let wrap = |(row, col): (usize, usize)| (
+            (start_row as isize + row as isize - steps as isize).rem_euclid(n_rows) as usize,
+            (start_col as isize + col as isize - steps as isize).rem_euclid(n_cols) as usize,
+        );
+
+        while let Some(((row, col), steps_left)) = to_check.pop_front() {
+            on_visit(wrap((row, col)), steps - steps_left);
+            if steps_left % 2 == 0 {
+                n_reached += 1;
+            }
+
+            if steps_left > 0 {
+                for (new_row, new_col) in [
+                    (row, col + 1),
+                    (row, col - 1),
+                    (row + 1, col),
+                    (row - 1, col),
+                ] {
+                    let (tile_row, tile_col) = wrap((new_row, new_col));
+                    let tile = grid[tile_row][tile_col];
+
+                    if tile != b'#' && traversed.insert((new_row, new_col)) {
+                        to_check.push_back((
+                            (new_row, new_col),
+                            steps_left - 1,
+                        ));
+                    }
+                }
+            }
+        }
+        n_reached
+    }
+}
+
+impl Solution for Day21 {
+    const NAME: &'static str = "Step Counter";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let grid = Self::get_grid(inp);
+        Self::traverse(
+            &grid,
+            Self::get_starting_pos(&grid),
+            self.part_one_steps,
+            |_, _| (),
+        )
+    }
+
+    /// # Panics
+    ///
+    /// If the reachable plot counts do not grow quadratically
+    /// with each repetition of the grid
+    #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let grid = Self::get_grid(inp);
+        let start = Self::get_starting_pos(&grid);
+
+        let steps = self.part_two_steps;
+        let n_rows = grid.len();
+        let (n, remainder) = (steps / n_rows, steps % n_rows);
+
+        // sample a few full grid repetitions in and extrapolate from there
+        let samples = 1..=4;
+        if n <= *samples.end() {
+            return Self::traverse(&grid, start, steps, |_, _| ());
+        }
+        let polynomial = Polynomial::interpolate(
+            samples
+                .map(|k| (
+                    k as i128,
+                    in_span("sample", ||
+                        Self::traverse(&grid, start, remainder + k * n_rows, |_, _| ()) as i128
+                    ),
+                ))
+        )
+        .ok()
+        .filter(|polynomial| polynomial
+            .degree()
+            .is_some_and(|degree| degree <= 2)
+        )
+        .expect("Reachable plots do not grow quadratically");
+
+        polynomial
+            .evaluate(n as i128)
+            .ok()
+            .and_then(|plots| plots.to_integer())
+            .expect("Reachable plots overflow")
+            as u64
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 3743);
+        assert_eq!(p2, 618_261_433_219_147);
+    }
+
+    fn configure(&self, params: &Params) -> Result<Option<Self>, ConfigError> {
+        params.expect_only(&["part_one_steps", "part_two_steps"])?;
+        let configured = Self::with_params(
+            params.get("part_one_steps", self.part_one_steps)?,
+            params.get("part_two_steps", self.part_two_steps)?,
+        );
+        Ok((configured != *self).then_some(configured))
+    }
+}
+
+impl Day21 {
+    /// Draws the plots reachable in exactly `steps` steps, given when each plot is first visited
+    fn draw(
+        grid: &[Vec<u8>],
+        start: (usize, usize),
+        visited: &[((usize, usize), usize)],
+        steps: usize,
+    ) -> Canvas {
+        let reached = visited
+            .iter()
+            .filter(|&&(_, taken)| taken <= steps && taken % 2 == steps % 2)
+            .map(|&(plot, _)| plot)
+            .collect::<Vec<(usize, usize)>>();
+
+        let mut canvas = Canvas::from_grid(grid);
+        canvas.paint_glyphs(&['#'], Color::Gray);
+        for &plot in &reached {
+            if let Some(cell) = canvas.get_mut(plot) {
+                cell.glyph = 'O';
+            }
+        }
+        canvas.highlight(reached, Color::Green);
+        canvas.highlight([start], Color::Red);
+        canvas
+    }
+}
+
+impl Visualize for Day21 {
+    /// Draws the garden plots reachable in exactly part one's 64 steps
+    fn visualize<T: Display>(&self, inp: T) -> Canvas {
+        let grid = Self::get_grid(inp);
+        let start = Self::get_starting_pos(&grid);
+        let steps = self.part_one_steps;
+        let mut visited = Vec::new();
+        Self::traverse(&grid, start, steps, |plot, taken| visited.push((plot, taken)));
+
+        Self::draw(&grid, start, &visited, steps)
+    }
+}
+
+impl Animate for Day21 {
+    /// Records the plots reachable after each of part one's first 64 steps
+    fn animate<T: Display>(&self, inp: T, animation: &mut Animation) {
+        let grid = Self::get_grid(inp);
+        let start = Self::get_starting_pos(&grid);
+        let n_steps = self.part_one_steps;
+        let mut visited = Vec::new();
+        Self::traverse(&grid, start, n_steps, |plot, taken| visited.push((plot, taken)));
+
+        for steps in 0..n_steps {
+            animation.record(|| Self::draw(&grid, start, &visited, steps));
+        }
+        animation.push(Self::draw(&grid, start, &visited, n_steps));
+    }
+}
+
+impl Explore for Day21 {
+    fn commands(&self) -> Vec<ReplCommand> {
+        [
+            GRID_COMMANDS.as_slice(),
+            &[
+                ReplCommand::new("start", "", "Print where the elf starts"),
+                ReplCommand::new("reach", "<steps> [row col]", "Count the plots reachable in exactly that many steps (at most 5000), from the start by default"),
+            ],
+        ].concat()
+    }
+
+    fn execute(&self, grid: &mut Vec<Vec<u8>>, command: &str, args: &[&str]) -> Result<String, String> {
+        if let Some(output) = grid_command(grid, command, args) {
+            return output;
+        }
+        match command {
+            "start" => {
+                let (row, col) = Self::get_starting_pos(grid);
+                Ok(format!("({row}, {col})"))
+            },
+            "reach" => {
+                let steps = parse_arg::<usize>(args, 0, "number of steps")?;
+                if steps > MAX_REACH_STEPS {
+                    return Err(format!("At most {MAX_REACH_STEPS} steps can be traversed"));
+                }
+                let start = if args.len() > 1 {
+                    (parse_arg::<usize>(args, 1, "row")?, parse_arg::<usize>(args, 2, "column")?)
+                } else {
+                    Self::get_starting_pos(grid)
+                };
+                if grid.get(start.0).and_then(|row| row.get(start.1)).is_none_or(|&tile| tile == b'#') {
+                    return Err(format!("({}, {}) is not a garden plot", start.0, start.1));
+                }
+                Ok(Self::traverse(grid, start, steps, |_, _| ()).to_string())
+            },
+            _ => Err(format!("Unknown command `{command}`")),
+        }
+    }
+}
+
+impl Parse for Day21 {
+    type Parsed<'a> = Vec<Vec<u8>>;
+    type Error = ParseError;
+
+    /// The grid of a full puzzle input, which part two needs to be square with the start in the middle,
+    /// and its edges and the start's row and column clear of rocks, for the plots to grow quadratically
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        let grid = Self::parse(inp)?;
+        let size = grid.len();
+        let middle = size / 2;
+        if grid[0].len() != size || size % 2 == 0 || grid[middle][middle] != b'S' {
+            return Err(Span::new(inp).error("expected a square grid of odd size with 'S' in the middle"));
+        }
+        let is_clear = (0..size)
+            .flat_map(|i| [(0, i), (size - 1, i), (i, 0), (i, size - 1), (middle, i), (i, middle)])
+            .all(|(row, col)| grid[row][col] != b'#');
+        if !is_clear {
+            return Err(Span::new(inp).error("expected the edges and the row and column of 'S' to be clear of rocks"));
+        }
+        Ok(grid)
+    }
+}
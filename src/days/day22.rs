@@ -0,0 +1,319 @@
+//! Day 22: Sand Slabs
+//!
+//! <https://adventofcode.com/2023/day/22>
+use std::{
+    collections::{HashMap, HashSet},
+    cmp::Ordering,
+    fmt::Display,
+};
+use serde::Serialize;
+use crate::{Solution, Parse, span, in_span, Animate, Animation, Canvas, Color, Intermediate, Graph, ParseError, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Point {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+impl Point {
+    fn parse(raw: Span<'_>) -> Result<Self, ParseError> {
+        match raw.fields::<usize>(",")?[..] {
+            [x, y, z] => Ok(Self { x, y, z }),
+            _ => Err(raw.error(format!("expected 3 coordinates in `{raw}`"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Brick {
+    bottom: Point,
+    top: Point,
+}
+
+impl Brick {
+    fn parse(raw: Span<'_>) -> Result<Self, ParseError> {
+        let (bottom, top) = raw
+            .split_once("~")?;
+        let (bottom, top) = (
+            Point::parse(bottom)?,
+            Point::parse(top)?,
+        );
+
+        if bottom.x > top.x || bottom.y > top.y || bottom.z > top.z {
+            return Err(raw.error(format!("expected the ends of `{raw}` to be in ascending order")));
+        }
+        Ok(Self { bottom, top })
+    }
+}
+
+impl Ord for Brick {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bottom.z.cmp(&other.bottom.z)
+    }
+}
+
+impl PartialOrd for Brick {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Brick {
+    #[inline]
+    const fn height(&self) -> usize {
+        self.top.z - self.bottom.z
+    }
+
+    #[inline]
+    fn overlaps_xy(&self, other: &Self) -> bool {
+        self.bottom.x.max(other.bottom.x) <= self.top.x.min(other.top.x)
+        && self.bottom.y.max(other.bottom.y) <= self.top.y.min(other.top.y)
+    }
+}
+
+/// The settled bricks, along with which of them rest on which, by index
+#[derive(Serialize)]
+pub struct Supports {
+    bricks: Vec<Brick>,
+    supports: HashMap<usize, HashSet<usize>>,
+    supported_by: HashMap<usize, HashSet<usize>>,
+}
+
+pub struct Day22;
+
+impl Day22 {
+    /// Parses the bricks, sorted from lowest to highest
+    fn parse(inp: &str) -> Result<Vec<Brick>, ParseError> {
+        let _span = span("parse");
+        let mut bricks = Span::new(inp)
+            .lines()
+            .map(Brick::parse)
+            .collect::<Result<Vec<Brick>, ParseError>>()?;
+
+        bricks.sort();
+        Ok(bricks)
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_bricks<T: Display>(inp: T) -> Vec<Brick> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Lets each brick fall from lowest to highest,
+    /// calling `on_settle` with the bricks and the index of the one that just came to rest
+    fn settle<F>(bricks: &mut [Brick], mut on_settle: F)
+    where
+        F: FnMut(&[Brick], usize),
+    {
+        let _span = span("settle");
+        for i in 0..bricks.len() {
+            let brick = &bricks[i];
+            let mut z = 1;
+
+            for lower_brick in bricks
+                .iter()
+                .take(i)
+            {
+                if brick.overlaps_xy(lower_brick) {
+                    z = z.max(lower_brick.top.z + 1);
+                }
+            }
+            bricks[i].top.z = brick.height() + z;
+            bricks[i].bottom.z = z;
+            on_settle(bricks, i);
+        }
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    #[allow(clippy::type_complexity)]
+    fn get_support_mappings<T: Display>(inp: T) -> (
+        Vec<Brick>,
+        HashMap<usize, HashSet<usize>>,
+        HashMap<usize, HashSet<usize>>,
+    ) {
+        let Supports { bricks, supports, supported_by } = Self::supports(Self::get_bricks(inp));
+        (bricks, supports, supported_by)
+    }
+
+    fn supports(mut bricks: Vec<Brick>) -> Supports {
+        let n_bricks = bricks.len();
+
+        Self::settle(&mut bricks, |_, _| ());
+        bricks.sort();
+        let _span = span("supports");
+
+        let mut supports = HashMap::with_capacity(n_bricks);
+        let mut supported_by = HashMap::with_capacity(n_bricks);
+
+        for (a, brick) in bricks
+            .iter()
+            .enumerate()
+        {
+            for (b, lower_brick) in bricks
+                .iter()
+                .take(a)
+                .enumerate()
+            {
+                if lower_brick.overlaps_xy(brick)
+                    && brick.bottom.z == lower_brick.top.z + 1
+                {
+                    supports
+                        .entry(b)
+                        .or_insert_with(HashSet::new)
+                        .insert(a);
+                    supported_by
+                        .entry(a)
+                        .or_insert_with(HashSet::new)
+                        .insert(b);
+                }
+            }
+        }
+        Supports { bricks, supports, supported_by }
+    }
+}
+
+impl Solution for Day22 {
+    const NAME: &'static str = "Sand Slabs";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let (bricks, supports, supported_by) = Self::get_support_mappings(inp);
+
+        (0..bricks.len())
+            .filter(|b| supports
+                .get(b)
+                .map_or(true, |b_supports|
+                    b_supports
+                        .iter()
+                        .all(|a| supported_by
+                            .get(a)
+                            .map(|supports_a| supports_a.len() > 1)
+                            .unwrap_or_default()
+                        )
+                )
+            )
+            .count() as u64
+    }
+
+    /// A brick makes another fall when every chain of support
+    /// from the ground up to the other brick passes through it,
+    /// i.e. when it dominates the other brick
+    ///
+    /// # Panics
+    ///
+    /// If bricks somehow support each other in a cycle
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let (bricks, supports, supported_by) = Self::get_support_mappings(inp);
+        let ground = bricks.len();
+
+        let mut graph = Graph::new(ground + 1);
+        for (&b, b_supports) in &supports {
+            for &a in b_supports {
+                graph.add_edge(b, a);
+            }
+        }
+        for a in (0..ground)
+            .filter(|a| !supported_by.contains_key(a))
+        {
+            graph.add_edge(ground, a);
+        }
+
+        in_span("dominators", || graph.dominator_tree(ground))
+            .expect("Bricks support each other in a cycle")
+            .dominated_counts()[..ground]
+            .iter()
+            .map(|&count| count as u64)
+            .sum()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 459);
+        assert_eq!(p2, 75784);
+    }
+}
+
+impl Day22 {
+    /// Draws the bricks from the front (x against z) and the side (y against z),
+    /// with the bricks up to `settled` at rest and the one at `settled` highlighted
+    fn draw(bricks: &[Brick], settled: usize, (max_x, max_y, max_z): (usize, usize, usize)) -> Canvas {
+        let gap = 3;
+        let mut canvas = Canvas::new(max_z + 1, max_x + gap + max_y + 2, ' ');
+        let ground = max_z;
+        canvas.map_glyphs(|(row, col), tile|
+            if row == ground && (col <= max_x || col > max_x + gap) { '-' }
+            else { tile }
+        );
+
+        // falling bricks are drawn first, so that the settled ones cover them
+        for order in [Ordering::Greater, Ordering::Less, Ordering::Equal] {
+            let color = match order {
+                Ordering::Greater => Color::Gray,
+                Ordering::Less => Color::Yellow,
+                Ordering::Equal => Color::Red,
+            };
+            for brick in bricks
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i.cmp(&settled) == order)
+                .map(|(_, brick)| brick)
+            {
+                for z in brick.bottom.z..=brick.top.z {
+                    let front = (brick.bottom.x..=brick.top.x)
+                        .map(|x| (ground - z, x));
+                    let side = (brick.bottom.y..=brick.top.y)
+                        .map(|y| (ground - z, max_x + gap + 1 + y));
+
+                    for cell in front.chain(side) {
+                        if let Some(cell) = canvas.get_mut(cell) {
+                            cell.glyph = '#';
+                            cell.color = Some(color);
+                        }
+                    }
+                }
+            }
+        }
+        canvas
+    }
+}
+
+impl Animate for Day22 {
+    /// Records the bricks coming to rest one at a time, from lowest to highest
+    fn animate<T: Display>(&self, inp: T, animation: &mut Animation) {
+        let mut bricks = Self::get_bricks(inp);
+        let bounds = bricks
+            .iter()
+            .fold((0, 0, 0), |(max_x, max_y, max_z), brick| (
+                max_x.max(brick.top.x),
+                max_y.max(brick.top.y),
+                max_z.max(brick.top.z),
+            ));
+
+        Self::settle(&mut bricks, |bricks, settled| animation.record(||
+            Self::draw(bricks, settled, bounds)
+        ));
+        animation.push(Self::draw(&bricks, bricks.len(), bounds));
+    }
+}
+
+impl Parse for Day22 {
+    type Parsed<'a> = Vec<Brick>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Brick>, ParseError> {
+        Self::parse(inp)
+    }
+
+    fn intermediate(&self, bricks: Vec<Brick>) -> Option<Intermediate> {
+        Some(serde_json::to_value(Self::supports(bricks)))
+    }
+}
@@ -0,0 +1,247 @@
+//! Day 23: A Long Walk
+//!
+//! <https://adventofcode.com/2023/day/23>
+use std::fmt::Display;
+use serde::Serialize;
+use crate::{
+    Solution, Parse, Visualize, Canvas, Color, Intermediate, span, in_span, ParseError, Span,
+    BitSet, CompressedGraph, Coord, compress_grid, get_passable_neighbors,
+};
+
+/// The trails compressed into graphs, with and without the slopes being slippery
+#[derive(Serialize)]
+pub struct Graphs {
+    slopes: CompressedGraph,
+    no_slopes: CompressedGraph,
+}
+
+pub struct Day23;
+
+impl Day23 {
+    fn dfs(
+        node: usize,
+        end: usize,
+        graph: &CompressedGraph,
+        seen: &mut BitSet,
+    ) -> usize {
+        if node == end { 0 }
+        else {
+            seen.insert(node);
+
+            #[allow(clippy::filter_map_bool_then)]
+            let max_length = graph
+                .neighbors(node)
+                .iter()
+                .filter_map(|&(next_node, distance)|
+                    (!seen.contains(next_node))
+                        .then(|| Self::dfs(next_node, end, graph, seen) + distance)
+                )
+                .max()
+                .unwrap_or(0);
+
+            seen.remove(node);
+            max_length
+        }
+    }
+
+    fn parse(inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        let _span = span("parse");
+        let grid = Span::new(inp).grid("#.<>^v")?;
+        match (grid.first(), grid.last()) {
+            (Some(first), Some(last)) if first.contains(&b'.') && last.contains(&b'.') => Ok(grid),
+            _ => Err(Span::new(inp).error("expected a trail's start in the first row and its end in the last")),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_grid<T: Display>(inp: T) -> Vec<Vec<u8>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Compresses the trails into a graph with the start and end as nodes 0 and 1
+    fn get_graph(grid: &[Vec<u8>], slopes: bool) -> CompressedGraph {
+        let _span = span("compress_grid");
+        let start = (0, grid[0]
+            .iter()
+            .position(|&c| c == b'.')
+            .unwrap()
+        );
+        let end = {
+            let last_row = grid.len() - 1;
+            (last_row, grid[last_row]
+                .iter()
+                .position(|&c| c == b'.')
+                .unwrap()
+            )
+        };
+
+        // slopes may only be walked down in the direction they point in
+        let follow_slope = |(row, col): Coord, next: Coord| match grid[row][col] {
+            b'>' => next == (row, col + 1),
+            b'<' => next == (row, col.wrapping_sub(1)),
+            b'v' => next == (row + 1, col),
+            b'^' => next == (row.wrapping_sub(1), col),
+            _ => true,
+        };
+        compress_grid(
+            grid,
+            &[start, end],
+            |tile| tile != b'#',
+            slopes.then_some(&follow_slope as &dyn Fn(Coord, Coord) -> bool),
+        )
+    }
+
+    fn hike<T: Display>(inp: T, slopes: bool) -> u64 {
+        let graph = Self::get_graph(&Self::get_grid(inp), slopes);
+
+        in_span("search", || Self::dfs(0, 1, &graph, &mut BitSet::new(graph.len()))) as u64
+    }
+
+    /// Same as [`Self::dfs`], but also returns the nodes along the longest path,
+    /// kept separate so that solving does not need to build paths
+    fn longest_path(
+        node: usize,
+        end: usize,
+        graph: &CompressedGraph,
+        seen: &mut BitSet,
+    ) -> Option<(usize, Vec<usize>)> {
+        if node == end {
+            return Some((0, vec![end]));
+        }
+        seen.insert(node);
+
+        let longest = graph
+            .neighbors(node)
+            .iter()
+            .filter_map(|&(next_node, distance)|
+                if seen.contains(next_node) { None }
+                else {
+                    Self::longest_path(next_node, end, graph, seen)
+                        .map(|(length, path)| (length + distance, path))
+                }
+            )
+            .max_by_key(|&(length, _)| length)
+            .map(|(length, mut path)| {
+                path.push(node);
+                (length, path)
+            });
+
+        seen.remove(node);
+        longest
+    }
+
+    /// Walks the corridor leading from one node to the next
+    /// that is `distance` steps long, returning every tile along it
+    fn get_corridor(
+        grid: &[Vec<u8>],
+        graph: &CompressedGraph,
+        from: Coord,
+        to: Coord,
+        distance: usize,
+    ) -> Vec<Coord> {
+        let is_passable = |tile| tile != b'#';
+
+        get_passable_neighbors(grid, from, &is_passable)
+            .into_iter()
+            .find_map(|first| {
+                let mut path = vec![from, first];
+                loop {
+                    let &[.., prev, curr] = &path[..] else {
+                        unreachable!()
+                    };
+                    if curr == to {
+                        return (path.len() - 1 == distance).then_some(path);
+                    }
+                    if graph.index_of(curr).is_some() {
+                        return None;
+                    }
+                    let next = get_passable_neighbors(grid, curr, &is_passable)
+                        .into_iter()
+                        .find(|&next| next != prev)?;
+                    path.push(next);
+                }
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Solution for Day23 {
+    const NAME: &'static str = "A Long Walk";
+
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::hike(inp, true)
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::hike(inp, false)
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 2182);
+        assert_eq!(p2, 6670);
+    }
+}
+
+impl Visualize for Day23 {
+    /// Draws the longest hike when slopes can be climbed
+    ///
+    /// # Panics
+    ///
+    /// If the end cannot be reached from the start
+    fn visualize<T: Display>(&self, inp: T) -> Canvas {
+        let grid = Self::get_grid(inp);
+        let graph = Self::get_graph(&grid, false);
+        let (_, mut path) = Self::longest_path(0, 1, &graph, &mut BitSet::new(graph.len()))
+            .expect("No hikes reach the end");
+        path.reverse();
+
+        let mut canvas = Canvas::from_grid(&grid);
+        canvas.paint_glyphs(&['#'], Color::Gray);
+        for pair in path.windows(2) {
+            let distance = graph
+                .neighbors(pair[0])
+                .iter()
+                .find(|&&(next_node, _)| next_node == pair[1])
+                .map_or(0, |&(_, distance)| distance);
+            canvas.highlight(
+                Self::get_corridor(
+                    &grid, &graph,
+                    graph.coord(pair[0]), graph.coord(pair[1]),
+                    distance,
+                ),
+                Color::Green,
+            );
+        }
+        canvas.highlight(
+            path
+                .iter()
+                .map(|&node| graph.coord(node)),
+            Color::Red,
+        );
+        canvas
+    }
+}
+
+impl Parse for Day23 {
+    type Parsed<'a> = Vec<Vec<u8>>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        Self::parse(inp)
+    }
+
+    fn intermediate(&self, grid: Vec<Vec<u8>>) -> Option<Intermediate> {
+        Some(serde_json::to_value(Graphs {
+            slopes: Self::get_graph(&grid, true),
+            no_slopes: Self::get_graph(&grid, false),
+        }))
+    }
+}
@@ -0,0 +1,321 @@
+//! Day 24: Never Tell Me The Odds
+//!
+//! <https://adventofcode.com/2023/day/24>
+use std::{
+    str::FromStr,
+    fmt::Display,
+};
+use serde::Serialize;
+use crate::{Solution, Parse, Params, ConfigError, span, in_span, LinearSystemError, ParseError, Span, solve_linear_system};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseHailstoneError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Hailstone {
+    x_pos: f64,
+    y_pos: f64,
+    z_pos: f64,
+    x_vel: f64,
+    y_vel: f64,
+    z_vel: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ExactHailstone {
+    x_pos: i128,
+    y_pos: i128,
+    z_pos: i128,
+    x_vel: i128,
+    y_vel: i128,
+    z_vel: i128,
+}
+
+macro_rules! parse_part {
+    ($parts:expr, $target_ty:ty) => {
+        $parts
+            .next()
+            .and_then(|raw| raw
+                .trim()
+                .parse::<$target_ty>()
+                .ok()
+            )
+            .ok_or(ParseHailstoneError)?
+    }
+}
+
+macro_rules! impl_from_str {
+    ($struct_ty:ty, $field_ty:ty) => {
+        impl FromStr for $struct_ty {
+            type Err = ParseHailstoneError;
+
+            fn from_str(raw: &str) -> Result<Self, Self::Err> {
+                let (pos, vel) = raw
+                    .split_once('@')
+                    .ok_or(ParseHailstoneError)?;
+                let mut pos = pos.split(',');
+                let mut vel = vel.split(',');
+
+                Ok(Self {
+                    x_pos: parse_part!(pos, $field_ty),
+                    y_pos: parse_part!(pos, $field_ty),
+                    z_pos: parse_part!(pos, $field_ty),
+
+                    x_vel: parse_part!(vel, $field_ty),
+                    y_vel: parse_part!(vel, $field_ty),
+                    z_vel: parse_part!(vel, $field_ty),
+                })
+            }
+        }
+    }
+}
+
+impl_from_str!(Hailstone, f64);
+impl_from_str!(ExactHailstone, i128);
+
+#[inline]
+fn float_cmp(a: f64, b: f64) -> bool {
+    (a - b).abs() < f64::EPSILON
+}
+
+impl Hailstone {
+    #[inline]
+    #[must_use]
+    fn m(&self) -> f64 {
+        self.y_vel / self.x_vel
+    }
+
+    #[inline]
+    #[must_use]
+    fn b(&self) -> f64 {
+        self.m()
+            .mul_add(-self.x_pos, self.y_pos)
+    }
+
+    #[inline]
+    #[must_use]
+    fn evaluate(&self, x: f64) -> f64 {
+        self.m()
+            .mul_add(x, self.b())
+    }
+
+    #[inline]
+    #[must_use]
+    fn in_domain(&self, x: f64, y: f64) -> bool {
+        (if self.x_vel > 0.0 {
+            x >= self.x_pos
+        } else if float_cmp(self.x_vel, 0.0) {
+            float_cmp(x, self.x_pos)
+        } else {
+            x <= self.x_pos
+        })
+        && (if self.y_vel > 0.0 {
+            y >= self.y_pos
+        } else if float_cmp(self.x_vel, 0.0) {
+            float_cmp(y, self.y_pos)
+        } else {
+            y <= self.y_pos
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    fn intersection(&self, other: &Self) -> Option<(f64, f64)> {
+        let m_diff = self.m() - other.m();
+        (m_diff != 0.0)
+            .then(|| {
+                let x = (other.b() - self.b()) / m_diff;
+                (x, self.evaluate(x))
+            })
+    }
+}
+
+impl ExactHailstone {
+    #[inline]
+    #[must_use]
+    const fn pos(&self) -> [i128; 3] {
+        [self.x_pos, self.y_pos, self.z_pos]
+    }
+
+    #[inline]
+    #[must_use]
+    const fn vel(&self) -> [i128; 3] {
+        [self.x_vel, self.y_vel, self.z_vel]
+    }
+}
+
+/// Testing for intersections within 200,000,000,000,000 to 400,000,000,000,000 on both axes in part one by default
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Day24 {
+    test_area: (f64, f64),
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Self::with_params((200_000_000_000_000.0, 400_000_000_000_000.0))
+    }
+}
+
+impl Day24 {
+    /// Tests for intersections within the bounds on both axes in part one
+    #[must_use]
+    pub const fn with_params(test_area: (f64, f64)) -> Self {
+        Self { test_area }
+    }
+
+    /// Parses every line as a hailstone, as `H`
+    fn get_hailstones<H: FromStr>(inp: &str) -> Result<Vec<H>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .lines()
+            .map(|line| line
+                .as_str()
+                .parse::<H>()
+                .map_err(|_| line.error(format!("invalid hailstone `{line}`")))
+            )
+            .collect()
+    }
+
+    /// Finds the rock's `[x, y, z, x_vel, y_vel, z_vel]` that hits all 3 hailstones
+    ///
+    /// For a rock at `p` with velocity `v`, hitting a hailstone at `p1` with velocity `v1`
+    /// means `(p - p1) x (v - v1) = 0`.
+    /// The quadratic `p x v` term is shared by every hailstone,
+    /// so equating it across a pair of hailstones leaves linear equations,
+    /// 2 per pair of axes when comparing `hs1` against each of `hs2` and `hs3`
+    ///
+    /// Returns [`None`] if the only rock to hit all 3 isn't at integer coordinates
+    fn find_rock(
+        hs1: &ExactHailstone,
+        hs2: &ExactHailstone,
+        hs3: &ExactHailstone,
+    ) -> Result<Option<Vec<i128>>, LinearSystemError> {
+        let mut matrix = Vec::with_capacity(6);
+        let mut rhs = Vec::with_capacity(6);
+
+        for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+            for other in [hs2, hs3] {
+                let (p1, v1) = (hs1.pos(), hs1.vel());
+                let (p2, v2) = (other.pos(), other.vel());
+
+                let mut row = [0; 6];
+                row[i] = v2[j] - v1[j];
+                row[j] = v1[i] - v2[i];
+                row[3 + i] = p1[j] - p2[j];
+                row[3 + j] = p2[i] - p1[i];
+
+                matrix.push(row);
+                rhs.push(
+                    p1[j] * v1[i] - p2[j] * v2[i]
+                    - (p1[i] * v1[j] - p2[i] * v2[j])
+                );
+            }
+        }
+
+        solve_linear_system(&matrix, &rhs)
+            .map(|solution| solution.to_integers())
+    }
+}
+
+impl Solution for Day24 {
+    const NAME: &'static str = "Never Tell Me The Odds";
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let hailstones = Self::get_hailstones::<Hailstone>(&inp.to_string())
+            .unwrap_or_else(|err| panic!("{err}"));
+        let (min, max) = self.test_area;
+        let test_area = min..=max;
+        let _span = span("intersect");
+
+        hailstones
+            .iter()
+            .enumerate()
+            .flat_map(|(i, hs1)| hailstones
+                .iter()
+                .take(i)
+                .filter(|hs2| hs1
+                    .intersection(hs2)
+                    .map(|(x, y)|
+                        hs1.in_domain(x, y)
+                        && hs2.in_domain(x, y)
+                        && test_area.contains(&x)
+                        && test_area.contains(&y)
+                    )
+                    .unwrap_or_default()
+                )
+            )
+            .count() as u64
+    }
+
+    /// # Panics
+    ///
+    /// If no rock can hit every hailstone at integer coordinates,
+    /// or the input is malformed
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let hailstones = Self::get_hailstones::<ExactHailstone>(&inp.to_string())
+            .unwrap_or_else(|err| panic!("{err}"));
+        let _span = span("solve");
+        let n = hailstones.len();
+
+        // some triples of hailstones may not pin down a single rock,
+        // such as when 2 of them travel in parallel,
+        // or only a rock between integer coordinates would hit them
+        let rock = (0..n)
+            .flat_map(|i| (i + 1..n)
+                .flat_map(move |j| (j + 1..n)
+                    .map(move |k| (i, j, k))
+                )
+            )
+            .find_map(|(i, j, k)| match Self::find_rock(
+                &hailstones[i], &hailstones[j], &hailstones[k]
+            ) {
+                Ok(rock) => rock,
+                Err(LinearSystemError::Degenerate) => None,
+                Err(err) => panic!("Failed to find the rock: {err}"),
+            })
+            .expect("No 3 hailstones pin down the rock");
+
+        u64::try_from(rock[0] + rock[1] + rock[2])
+            .unwrap()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 14672);
+        assert_eq!(p2, 646_810_057_104_753);
+    }
+
+    fn configure(&self, params: &Params) -> Result<Option<Self>, ConfigError> {
+        params.expect_only(&["test_area"])?;
+        let configured = Self::with_params(
+            params.get("test_area", self.test_area)?,
+        );
+        Ok((configured != *self).then_some(configured))
+    }
+}
+
+impl Parse for Day24 {
+    type Parsed<'a> = (Vec<Hailstone>, Vec<ExactHailstone>);
+    type Error = ParseError;
+
+    /// The hailstones as part one and part two read them,
+    /// at least 3 of them for part two to pin down the rock with
+    fn parse_input(&self, inp: &str) -> Result<(Vec<Hailstone>, Vec<ExactHailstone>), ParseError> {
+        let exact = Self::get_hailstones::<ExactHailstone>(inp)?;
+        if exact.len() < 3 {
+            return Err(Span::new(inp).error(format!("expected at least 3 hailstones, found {}", exact.len())));
+        }
+        Ok((
+            Self::get_hailstones(inp)?,
+            exact,
+        ))
+    }
+}
@@ -0,0 +1,134 @@
+//! Day 25: Snowverload
+//!
+//! <https://adventofcode.com/2023/day/25>
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+use rustworkx_core::{
+    petgraph::graphmap::UnGraphMap,
+    connectivity::stoer_wagner_min_cut as min_cut,
+};
+use crate::{Solution, Parse, ParseError, Span, in_span, UnionFind};
+
+pub struct Day25;
+
+impl Day25 {
+    /// Reads every wire, from the component before each line's `:` to every one after it,
+    /// which must all join up into one machine
+    fn parse(inp: &str) -> Result<Vec<(&str, &str)>, ParseError> {
+        in_span("parse", || {
+            let mut wires = Vec::new();
+            for line in Span::new(inp).lines() {
+                let (left, right) = line.key_value(":")?;
+                for node in right.words() {
+                    if node.as_str() == left.as_str() {
+                        return Err(node.error(format!("`{left}` is wired to itself")));
+                    }
+                    wires.push((left.as_str(), node.as_str()));
+                }
+            }
+            if Self::component_sizes(&wires, &[]).len() != 1 {
+                return Err(Span::new(inp).error("expected the components to be wired into one machine"));
+            }
+            Ok(wires)
+        })
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_wires(inp: &str) -> Vec<(&str, &str)> {
+        Self::parse(inp).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The sizes of the connected components, smallest first,
+    /// after reconnecting every wire that doesn't cross the partition
+    fn component_sizes(wires: &[(&str, &str)], partition: &[&str]) -> Vec<usize> {
+        let partition = partition
+            .iter()
+            .copied()
+            .collect::<HashSet<&str>>();
+        let mut indices = HashMap::new();
+        for &(a, b) in wires {
+            for node in [a, b] {
+                let n_nodes = indices.len();
+                indices.entry(node).or_insert(n_nodes);
+            }
+        }
+
+        let mut components = UnionFind::new(indices.len());
+        for &(a, b) in wires {
+            if partition.contains(a) == partition.contains(b) {
+                components.union(indices[a], indices[b]);
+            }
+        }
+        let mut sizes = components
+            .sets()
+            .iter()
+            .map(Vec::len)
+            .collect::<Vec<usize>>();
+        sizes.sort_unstable();
+        sizes
+    }
+}
+
+impl Solution for Day25 {
+    const NAME: &'static str = "Snowverload";
+
+    /// # Panics
+    ///
+    /// If the input is malformed,
+    /// or if the cut does not leave two groups of components connected among themselves
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let inp = inp
+            .to_string();
+        let wires = Self::get_wires(&inp);
+
+        let mut graph: UnGraphMap<&str, i32> = UnGraphMap::new();
+        for &(left, node) in &wires {
+            graph.add_edge(left, node, 1);
+            graph.add_edge(node, left, 1);
+        }
+
+        if let Ok(Some((_, partition_1))) = in_span("min_cut", || min_cut(
+            &graph,
+            |_| Ok::<usize, usize>(1)
+        )) {
+            let size_1 = partition_1.len();
+            let size_2 = graph.node_count() - size_1;
+
+            // independently check the partition sizes, which takes a fraction of the cut's time
+            assert_eq!(
+                Self::component_sizes(&wires, &partition_1),
+                [size_1.min(size_2), size_1.max(size_2)],
+                "Partition sizes do not match the connected components",
+            );
+
+            size_1 as u64 * size_2 as u64
+        } else {
+            panic!("Could not perform a minimum cut on the graph")
+        }
+    }
+
+    fn part_two<T: Display>(&self, _inp: T) -> u64 {
+        unimplemented!()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(inp));
+
+        println!("Part 1: {p1}");
+
+        assert_eq!(p1, 554_064);
+    }
+}
+
+impl Parse for Day25 {
+    type Parsed<'a> = Vec<(&'a str, &'a str)>;
+    type Error = ParseError;
+
+    fn parse_input<'a>(&self, inp: &'a str) -> Result<Self::Parsed<'a>, ParseError> {
+        Self::parse(inp)
+    }
+}
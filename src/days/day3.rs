@@ -0,0 +1,231 @@
+//! Day 3: Gear Ratios
+//!
+//! <https://adventofcode.com/2023/day/3>
+use std::{
+    collections::HashMap,
+    fmt::Display,
+};
+use crate::{Solution, Parse, ParseError, Span, span, in_span};
+
+pub struct Day3;
+
+impl Day3 {
+    fn symbol_adjacent<A, C, F>(
+        arr: A,
+        coordinates: C,
+        condition: F,
+    ) -> Vec<(usize, usize)>
+    where
+        F: Fn(char) -> bool,
+        A: AsRef<[Vec<char>]>,
+        C: AsRef<[(usize, usize)]>,
+    {
+        let arr = arr.as_ref();
+        let coordinates = coordinates.as_ref();
+        let n_coords = coordinates.len() - 1;
+
+        coordinates
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &(row, col))| {
+                let mut indices = vec![
+                    (row.wrapping_sub(1), col),
+                    (row + 1, col),
+                ];
+                if i == 0 {
+                    indices.extend([
+                        (row, col.wrapping_sub(1)),
+                        (row.wrapping_sub(1), col.wrapping_sub(1)),
+                        (row + 1, col.wrapping_sub(1)),
+                    ]);
+                }
+                if i == n_coords {
+                    indices.extend([
+                        (row, col + 1),
+                        (row.wrapping_sub(1), col + 1),
+                        (row + 1, col + 1),
+                    ]);
+                }
+                indices
+                    .into_iter()
+                    .filter_map(|coord @ (row, col)| arr
+                        .get(row)
+                        .and_then(|row| row
+                            .get(col)
+                            .and_then(|&c| condition(c)
+                                .then_some(coord)
+                            )
+                        )
+                    )
+            })
+            .collect::<Vec<(usize, usize)>>()
+    }
+
+    /// Checks that every number fits into a [`u16`], so that the gear ratios and their sum cannot overflow,
+    /// which also rules out numeric characters other than ASCII digits
+    fn parse(inp: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .lines()
+            .map(|line| {
+                let mut start = None;
+                for (i, chr) in line
+                    .as_str()
+                    .char_indices()
+                    .chain([(line.as_str().len(), '.')])
+                {
+                    match (chr.is_numeric(), start) {
+                        (true, None) => start = Some(i),
+                        (false, Some(from)) => {
+                            line.slice(from..i).parse::<u16>()?;
+                            start = None;
+                        },
+                        _ => {},
+                    }
+                }
+                Ok(line
+                    .as_str()
+                    .chars()
+                    .collect::<Vec<char>>()
+                )
+            })
+            .collect()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_grid<T: Display>(inp: T) -> Vec<Vec<char>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Solution for Day3 {
+    const NAME: &'static str = "Gear Ratios";
+
+    /// # Panics
+    ///
+    /// If a number string in the input somehow is unable to be parsed into [`u64`]
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let arr = Self::get_grid(inp);
+        let mut total = 0;
+
+        let mut curr_indices = Vec::new();
+        let mut curr_num = String::new();
+        for (y, row) in arr
+            .iter()
+            .enumerate()
+        {
+            // a number ending the row is followed by a '.' rather than carried on into the next row
+            for (x, chr) in row
+                .iter()
+                .chain(&['.'])
+                .enumerate()
+            {
+                if chr.is_numeric() {
+                    curr_indices.push((y, x));
+                    curr_num.push(*chr);
+                } else {
+                    if !curr_indices.is_empty()
+                        && !Self::symbol_adjacent(
+                            &arr, &curr_indices,
+                            |c| !c.is_numeric() && c != '.',
+                        ).is_empty()
+                    {
+                        total += curr_num
+                            .parse::<u64>()
+                            .unwrap();
+                    }
+                    curr_indices.clear();
+                    curr_num.clear();
+                }
+            }
+        }
+        total
+    }
+
+    /// # Panics
+    ///
+    /// if the numbers failed to be parsed into [`u64`]
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let arr = Self::get_grid(inp);
+        let mut total = 0;
+
+        for (y, row) in arr
+            .iter()
+            .enumerate()
+        {
+            for (x, chr) in row
+                .iter()
+                .enumerate()
+            {
+                if *chr == '*' {
+                    let nums = Self::symbol_adjacent(
+                        &arr, [(y, x)],
+                        char::is_numeric,
+                    );
+                    if !nums.is_empty() {
+                        let mut num_map = HashMap::new();
+                        for (y, mut x) in nums {
+                            let mut curr_num = String::new();
+
+                            while arr
+                                .get(y)
+                                .and_then(|row| row
+                                    .get(x.wrapping_sub(1))
+                                    .filter(|c| c.is_numeric())
+                                )
+                                .is_some()
+                            {
+                                x -= 1;
+                            }
+
+                            while let Some(chr) = arr
+                                .get(y)
+                                .and_then(|row| row.get(x)
+                                    .filter(|c| c.is_numeric())
+                                )
+                            {
+                                curr_num.push(*chr);
+                                x += 1;
+                            }
+                            num_map.insert((y, x), curr_num);
+                        }
+                        if num_map.len() == 2 {
+                            let mut values = num_map.values();
+                            total += values
+                                .next()
+                                .and_then(|a| a.parse::<u64>().ok())
+                                .unwrap()
+                                * values
+                                .next()
+                                .and_then(|b| b.parse::<u64>().ok())
+                                .unwrap();
+                        }
+                    }
+                }
+            }
+        }
+        total
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 532_428) ;
+        assert_eq!(p2, 84_051_670);
+    }
+}
+
+impl Parse for Day3 {
+    type Parsed<'a> = Vec<Vec<char>>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        Self::parse(inp)
+    }
+}
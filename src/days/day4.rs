@@ -0,0 +1,105 @@
+//! Day 4: Scratchcards
+//!
+//! <https://adventofcode.com/2023/day/4>
+use std::{
+    collections::HashSet,
+    fmt::Display,
+};
+use crate::{Solution, Parse, span, in_span, ParseError, Span};
+
+pub struct Day4;
+
+impl Day4 {
+    /// returns the amount of the numbers that are winning numbers on each card
+    fn get_winning_amts(inp: &str) -> Result<Vec<usize>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .lines()
+            .map(|card| {
+                let (_, nums) = card
+                    .split_once(":")?;
+                let (winning, mine) = nums
+                    .split_once("|")?;
+                let winning = winning
+                    .integers::<usize>()?
+                    .into_iter()
+                    .collect::<HashSet<usize>>();
+
+                Ok(mine
+                    .integers::<usize>()?
+                    .into_iter()
+                    .filter(|num| winning.contains(num))
+                    .count()
+                )
+            })
+            .collect()
+    }
+}
+
+impl Solution for Day4 {
+    const NAME: &'static str = "Scratchcards";
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    /// or if the amount of winning numbers exceeds [`usize::MAX`]
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::get_winning_amts(&inp.to_string())
+            .unwrap_or_else(|err| panic!("{err}"))
+            .into_iter()
+            .map(|amt_win| {
+                if amt_win > 0 {
+                    (2_u64).pow(
+                        u32::try_from(amt_win)
+                            .unwrap() - 1
+                    )
+                } else { 0 }
+            })
+            .sum()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let win_amts = Self::get_winning_amts(&inp.to_string())
+            .unwrap_or_else(|err| panic!("{err}"));
+        let mut copies = vec![1; win_amts.len()];
+
+        for (i, win_amt) in win_amts
+            .into_iter()
+            .enumerate()
+        {
+            for card in i + 1..=win_amt + i {
+                let n_copies = *copies.get(i)
+                    .unwrap_or(&0);
+                if let Some(copy) = copies.get_mut(card) {
+                    *copy += n_copies;
+                }
+            }
+        }
+        copies
+            .into_iter()
+            .sum()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 26914);
+        assert_eq!(p2, 13_080_971);
+    }
+}
+
+impl Parse for Day4 {
+    type Parsed<'a> = Vec<usize>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<usize>, ParseError> {
+        Self::get_winning_amts(inp)
+    }
+}
@@ -0,0 +1,202 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! <https://adventofcode.com/2023/day/5>
+use std::{ops::Range, fmt::Display};
+use serde::Serialize;
+use crate::{Solution, Parse, ParseError, Span, span, in_span, Intermediate};
+#[cfg(not(feature = "nightly"))]
+use crate::IteratorExt;
+
+/// Pairs of a source range and the offset between the source and destination values
+type LookupTable = Vec<(Range<i64>, i64)>;
+
+/// The parsed input
+#[derive(Debug, Clone, Serialize)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    /// One for each map, from seeds to locations
+    tables: Vec<LookupTable>,
+}
+
+/// The values after each map, from the seeds to the locations
+#[derive(Debug, Clone, Serialize)]
+pub struct Conversions {
+    /// Part one's seeds
+    seeds: Vec<Vec<i64>>,
+    /// Part two's seed ranges, as `[start, length]`
+    seed_ranges: Vec<Vec<[i64; 2]>>,
+}
+
+pub struct Day5;
+
+impl Day5 {
+    /// Reads the lines after the map's name, each as `dest_start src_start length`
+    ///
+    /// The numbers are [`u32`]s, so that every value stays non-negative and far from overflowing
+    /// however many maps it goes through, as a map sends `src_start..src_end` to `dest_start..dest_end`
+    fn get_lookup_table(map: Span<'_>) -> Result<LookupTable, ParseError> {
+        map
+            .lines()
+            .skip(1)
+            .map(|line| match line.fields::<u32>(" ")?[..] {
+                [dest_start, src_start, length] => {
+                    let (dest_start, src_start) = (i64::from(dest_start), i64::from(src_start));
+                    Ok((src_start..src_start + i64::from(length), dest_start - src_start))
+                },
+                _ => Err(line.error(format!("expected 3 numbers in `{line}`"))),
+            })
+            .collect()
+    }
+
+    fn parse(inp: &str) -> Result<Almanac, ParseError> {
+        let _span = span("parse");
+        let inp = Span::new(inp);
+        let mut sections = inp.sections();
+        let seeds = sections
+            .next()
+            .ok_or_else(|| inp.error("expected the seeds"))?
+            .strip_prefix("seeds:")?;
+        // part two reads them in pairs of a start and a length
+        let seeds = match seeds.fields::<u32>(" ")? {
+            seeds if !seeds.is_empty() && seeds.len() % 2 == 0 => seeds
+                .into_iter()
+                .map(i64::from)
+                .collect(),
+            _ => return Err(seeds.error("expected pairs of seeds")),
+        };
+
+        Ok(Almanac {
+            seeds,
+            tables: sections
+                .map(Self::get_lookup_table)
+                .collect::<Result<Vec<LookupTable>, ParseError>>()?,
+        })
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_almanac<T: Display>(inp: T) -> Almanac {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn convert_seeds(seeds: &mut [i64], table: &LookupTable) {
+        for item in seeds {
+            *item = table.iter()
+                .find_map(|(map_range, diff)| map_range
+                    .contains(item)
+                    .then_some(*item + *diff)
+                )
+                .unwrap_or(*item);
+        }
+    }
+
+    fn convert_seed_ranges(mut curr_data: Vec<[i64; 2]>, table: &LookupTable) -> Vec<[i64; 2]> {
+        let mut temp = Vec::new();
+
+        'a: while let Some([a, mut b]) = curr_data.pop() {
+            b += a;
+            for (map_range, diff) in table {
+                let isect_a = a.max(map_range.start);
+                let isect_b = b.min(map_range.end);
+
+                if isect_a < isect_b {
+                    temp.push([isect_a + diff, isect_b - isect_a]);
+                    if isect_a > a {
+                        curr_data.push([map_range.start, isect_a - map_range.start]);
+                    } else if b > isect_b {
+                        curr_data.push([isect_b, b - isect_b]);
+                    }
+                    continue 'a;
+                }
+            }
+            temp.push([a, b - a]);
+        }
+        temp
+    }
+
+    #[cfg(feature = "nightly")]
+    fn get_seed_ranges(seeds: &[i64]) -> Vec<[i64; 2]> {
+        seeds
+            .iter()
+            .copied()
+            .array_chunks::<2>()
+            .collect()
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    fn get_seed_ranges(seeds: &[i64]) -> Vec<[i64; 2]> {
+        seeds
+            .iter()
+            .copied()
+            .chunked::<2>()
+            .collect()
+    }
+}
+
+impl Solution for Day5 {
+    const NAME: &'static str = "If You Give A Seed A Fertilizer";
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let Almanac { mut seeds, tables } = Self::get_almanac(inp);
+
+        for table in &tables {
+            Self::convert_seeds(&mut seeds, table);
+        }
+        seeds
+            .into_iter()
+            .min()
+            .and_then(|n| u64::try_from(n).ok())
+            .unwrap()
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let Almanac { seeds, tables } = Self::get_almanac(inp);
+
+        tables
+            .iter()
+            .fold(Self::get_seed_ranges(&seeds), Self::convert_seed_ranges)
+            .into_iter()
+            .min()
+            .and_then(|[n, _]| u64::try_from(n).ok())
+            .unwrap()
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 993_500_720);
+        assert_eq!(p2, 4_917_124);
+    }
+}
+
+impl Parse for Day5 {
+    type Parsed<'a> = Almanac;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Almanac, ParseError> {
+        Self::parse(inp)
+    }
+
+    fn intermediate(&self, almanac: Almanac) -> Option<Intermediate> {
+        let mut seeds = vec![almanac.seeds.clone()];
+        let mut seed_ranges = vec![Self::get_seed_ranges(&almanac.seeds)];
+        for table in &almanac.tables {
+            let mut next = seeds[seeds.len() - 1].clone();
+            Self::convert_seeds(&mut next, table);
+            seeds.push(next);
+            seed_ranges.push(Self::convert_seed_ranges(seed_ranges[seed_ranges.len() - 1].clone(), table));
+        }
+        Some(serde_json::to_value(Conversions { seeds, seed_ranges }))
+    }
+}
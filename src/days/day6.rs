@@ -0,0 +1,188 @@
+//! Day 6: Wait For It
+//!
+//! <https://adventofcode.com/2023/day/6>
+use std::fmt::Display;
+use crate::{Solution, Parse, ParseError, Span, span, in_span};
+
+pub struct Day6;
+
+impl Day6 {
+    /// Brute forces getting the amount of cases where we beat the target distance
+    /// by looping through all the possible values for the time the button is held,
+    /// calculating the distance based on that and checking if it beats the target distance that we need to beat
+    #[inline]
+    fn get_num_beats_bf((time, to_beat): (u64, u64)) -> u64 {
+        (1..time)
+            .filter(|hold_time| hold_time * (time - hold_time) > to_beat)
+            .count() as u64
+    }
+
+    /// Mathematical method of getting the amount of cases where we beat the target distance
+    ///
+    /// The function d(t) which models the distance travelled based on the time the button is held is a quadratic
+    /// we simply need to solve the inequality were d(t) > [the time we need to beat]
+    /// then we simply need to calculate the amount of integers in between the range of the inequality
+    /// AKA the 2 roots of the function d(t) - [the time we need to beat]
+    /// that number would be ⌊greater root⌋ - ⌈smaller root⌉ + 1
+    #[allow(
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+    )]
+    fn get_num_beats((time, to_beat): (u64, u64)) -> u64 {
+        let time = time as f64;
+        let to_beat = to_beat as f64;
+        let discriminant = 4.0_f64
+            .mul_add(-to_beat, time.powi(2));
+
+        if discriminant < 0.0 { 0 }
+        else {
+            let root1 = (time - discriminant.sqrt()) / 2.0;
+            let root2 = (time + discriminant.sqrt()) / 2.0;
+            (root2.trunc() - root1.ceil() + 1.0) as u64
+        }
+    }
+
+    /// The races' times and record distances as part one reads them, which part two joins the digits of
+    ///
+    /// There needs to be a race, and the product of the times, which bounds part one's answer,
+    /// and the joined up time and distance need to fit into a [`u64`]
+    fn parse(inp: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+        let _span = span("parse");
+        let inp = Span::new(inp);
+        let mut lines = inp.lines();
+        let mut read_line = |name: &str| {
+            let line = lines
+                .next()
+                .ok_or_else(|| inp.error(format!("expected a `{name}:` line")))?
+                .strip_prefix(&format!("{name}:"))?;
+            line.as_str()
+                .replace(|c| c == '\r' || c == ' ', "")
+                .parse::<u64>()
+                .map_err(|err| line.error(format!("invalid joined up {name} `{line}`: {err}")))?;
+            line.fields::<u64>(" ")
+        };
+
+        let times = read_line("Time")?;
+        let distances = read_line("Distance")?;
+        if let Some(line) = lines.find(|line| !line.trim().is_empty()) {
+            return Err(line.error("expected only the times and distances"));
+        }
+        if times.len() != distances.len() {
+            return Err(inp.error(format!("expected as many distances as times, {} and {}", distances.len(), times.len())));
+        }
+        if times.iter().try_fold(1, |product: u64, &time| product.checked_mul(time)).is_none() {
+            return Err(inp.error("the product of the times overflows"));
+        }
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .collect()
+        )
+    }
+
+    /// Helper function for Part 1 that executes the brute force and non brute force methods
+    fn part_one_helper<T, F>(inp: T, map_func: F) -> u64
+    where
+        T: Display,
+        F: Fn((u64, u64)) -> u64,
+    {
+        inp
+            .to_string()
+            .replace('\r', "")
+            .split_once('\n')
+            .map(|(time, distance)| (
+                time.split_once(':')
+                    .unwrap()
+                    .1
+                    .split_whitespace()
+                    .filter_map(|n| n.parse::<u64>().ok())
+                    .zip(
+                        distance.split_once(':')
+                            .unwrap()
+                            .1
+                            .split_whitespace()
+                            .filter_map(|n| n.parse::<u64>().ok())
+                    )
+                    .map(map_func)
+                    .product()
+                )
+            )
+            .unwrap()
+    }
+
+    /// Helper function for Part 2 that executes the brute force and non brute force methods
+    fn part_two_helper<T, F>(inp: T, map_func: F) -> u64
+    where
+        T: Display,
+        F: Fn((u64, u64)) -> u64,
+    {
+        inp
+            .to_string()
+            .replace(|c| c == '\r' || c == ' ', "")
+            .split_once('\n')
+            .map(|(time, distance)|
+                map_func((
+                    time.split_once(':')
+                        .unwrap()
+                        .1
+                        .parse::<u64>()
+                        .unwrap(),
+                    distance.split_once(':')
+                        .unwrap()
+                        .1
+                        .trim_end()
+                        .parse::<u64>()
+                        .unwrap(),
+                ))
+            )
+            .unwrap()
+    }
+
+    /// Brute force part 1
+    pub fn part_one_bf<T: Display>(&self, inp: T) -> u64 {
+        Self::part_one_helper(inp, Self::get_num_beats_bf)
+    }
+
+    /// Brute force part 2
+    pub fn part_two_bf<T: Display>(&self, inp: T) -> u64 {
+        Self::part_two_helper(inp, Self::get_num_beats_bf)
+    }
+}
+
+impl Solution for Day6 {
+    const NAME: &'static str = "Wait For It";
+
+    /// Non brute force part 1
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::part_one_helper(inp, Self::get_num_beats)
+    }
+
+    /// Non brute force part 2
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::part_two_helper(inp, Self::get_num_beats)
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        assert_eq!(self.part_one_bf(&inp), p1);
+        assert_eq!(self.part_two_bf(&inp), p2);
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 1_731_600);
+        assert_eq!(p2, 40_087_680);
+    }
+}
+
+impl Parse for Day6 {
+    type Parsed<'a> = Vec<(u64, u64)>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+        Self::parse(inp)
+    }
+}
@@ -0,0 +1,196 @@
+//! Day 8: Haunted Wasteland
+//!
+//! <https://adventofcode.com/2023/day/8>
+use std::{
+    collections::HashMap,
+    fmt::Display,
+};
+use crate::{Solution, Parse, span, in_span, ParseError, Span, Cycle};
+
+pub struct Day8;
+
+type Map = HashMap<String, (String, String)>;
+
+impl Day8 {
+    fn parse<T: Display>(inp: T)
+        -> Result<(String, Map), ParseError>
+    {
+        let _span = span("parse");
+        let inp = inp.to_string();
+        let inp = Span::new(&inp);
+
+        let mut sections = inp.sections();
+        let (instructions, nodes) = sections
+            .next()
+            .zip(sections.next())
+            .ok_or_else(|| inp.error("expected instructions and nodes separated by a blank line"))?;
+
+        let instructions = instructions.trim();
+        if instructions.is_empty() {
+            return Err(instructions.error("expected instructions"));
+        }
+        if let Some(invalid) = instructions
+            .as_str()
+            .find(|c| c != 'L' && c != 'R')
+        {
+            return Err(
+                instructions
+                    .slice(invalid..)
+                    .error("expected instructions to only be `L` or `R`")
+            );
+        }
+        let nodes = nodes
+            .lines()
+            .map(|line| {
+                let (key, children) = line
+                    .key_value("=")?;
+                let (left, right) = children
+                    .strip_prefix("(")?
+                    .strip_suffix(")")?
+                    .key_value(",")?;
+                Ok((key, left, right))
+            })
+            .collect::<Result<Vec<(Span, Span, Span)>, ParseError>>()?;
+
+        let map = nodes
+            .iter()
+            .map(|(key, left, right)| (key.to_string(), (left.to_string(), right.to_string())))
+            .collect::<Map>();
+        if let Some(missing) = nodes
+            .iter()
+            .flat_map(|&(_, left, right)| [left, right])
+            .find(|child| !map.contains_key(child.as_str()))
+        {
+            return Err(missing.error(format!("no node `{missing}`")));
+        }
+        if !map.contains_key("AAA") {
+            return Err(inp.error("no node `AAA` to start from"));
+        }
+        Ok((instructions.to_string(), map))
+    }
+
+    fn count_instructions<S, F>(
+        instructions: S,
+        left: S,
+        right: S,
+        nodes: &Map,
+        end_condition: F
+    ) -> u64
+    where
+        S: Display,
+        F: Fn(&String) -> bool,
+    {
+        let mut left = &left
+            .to_string();
+        let mut right = &right
+            .to_string();
+        let instructions = instructions.to_string();
+        // past as many steps as there are `(node, instruction)` states, the walk is going round in circles
+        let max_count = (nodes.len() * instructions.len()) as u64;
+        let mut count = 0;
+        for instruction in instructions
+            .bytes()
+            .cycle()
+        {
+            count += 1;
+            assert!(count <= max_count, "the end is never reached");
+            let key = match instruction {
+                b'L' => left,
+                _ => right,
+            };
+            if end_condition(key) {
+                break;
+            }
+            let values = nodes.get(key)
+                .unwrap();
+            left = &values.0;
+            right = &values.1;
+        }
+        count
+    }
+
+    /// Walks from `start` until a `(node, instruction)` state repeats,
+    /// recording every step that lands on an end node
+    fn find_cycle<F>(
+        instructions: &[u8],
+        start: &str,
+        nodes: &Map,
+        end_condition: F,
+    ) -> Cycle
+    where
+        F: Fn(&str) -> bool,
+    {
+        let max_steps = (nodes.len() * instructions.len()) as u64;
+        Cycle::find((start, 0), max_steps, |&(node, index)| {
+            let (left, right) = nodes.get(node)
+                .unwrap();
+            let next = match instructions[index] {
+                b'L' => left,
+                _ => right,
+            };
+            ((next.as_str(), (index + 1) % instructions.len()), end_condition(node))
+        })
+        .expect("A state repeats within as many steps as there are states")
+    }
+}
+
+impl Solution for Day8 {
+    const NAME: &'static str = "Haunted Wasteland";
+
+    /// # Panics
+    ///
+    /// If the input is malformed,
+    /// or if the ZZZ node is never reached
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        let (instructions, nodes) =
+            Self::parse(inp)
+                .unwrap_or_else(|err| panic!("{err}"));
+        let (left, right) = nodes.get("AAA")
+            .unwrap();
+        Self::count_instructions(
+            &instructions, left, right, &nodes, |s| s == "ZZZ"
+        )
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed,
+    /// or if the ghosts never stand on end nodes at the same time
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        let (instructions, nodes) =
+            Self::parse(inp)
+                .unwrap_or_else(|err| panic!("{err}"));
+
+        let cycles = nodes
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .map(|start| Self::find_cycle(
+                instructions.as_bytes(), start, &nodes, |s| s.ends_with('Z')
+            ))
+            .collect::<Vec<Cycle>>();
+
+        Cycle::first_common(&cycles)
+            .unwrap_or_else(|err| panic!("Failed to line up the ghosts: {err}"))
+            .expect("ghosts never stand on end nodes simultaneously")
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 18727);
+        assert_eq!(p2, 18_024_643_846_273);
+    }
+}
+
+impl Parse for Day8 {
+    type Parsed<'a> = (String, Map);
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<(String, Map), ParseError> {
+        Self::parse(inp)
+    }
+}
@@ -0,0 +1,99 @@
+//! Day 9: Mirage Maintenance
+//!
+//! <https://adventofcode.com/2023/day/9>
+use std::fmt::Display;
+use crate::{Solution, Parse, ParseError, Span, span, in_span, Polynomial};
+
+pub struct Day9;
+
+impl Day9 {
+    /// Reads each line's terms,
+    /// checking that the sequence extrapolates to whole numbers both ways without overflowing
+    fn parse(inp: &str) -> Result<Vec<Vec<i128>>, ParseError> {
+        let _span = span("parse");
+        Span::new(inp)
+            .lines()
+            .map(|line| {
+                let sequence = line
+                    .words()
+                    .map(|term| term.parse::<i128>())
+                    .collect::<Result<Vec<i128>, _>>()?;
+                #[allow(clippy::cast_possible_wrap)]
+                for x in [-1, sequence.len() as i128] {
+                    Self::extrapolate_sequence(&sequence, x)
+                        .map_err(|err| line.error(format!("cannot extrapolate `{line}` to {x}: {err}")))?;
+                }
+                Ok(sequence)
+            })
+            .collect()
+    }
+
+    /// The sequence's term at `x`, if it is a whole number
+    fn extrapolate_sequence(sequence: &[i128], x: i128) -> Result<i128, String> {
+        Polynomial::fit_sequence(sequence.iter().copied())
+            .and_then(|polynomial| polynomial.evaluate(x))
+            .map_err(|err| err.to_string())?
+            .to_integer()
+            .ok_or_else(|| "the term is not a whole number".to_string())
+    }
+
+    /// # Panics
+    ///
+    /// If the input is malformed
+    fn get_sequences<T: Display>(inp: T) -> Vec<Vec<i128>> {
+        Self::parse(&inp.to_string()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Extrapolates every sequence to the term at `get_x(sequence length)`
+    /// and sums them up
+    ///
+    /// # Panics
+    ///
+    /// If the input is malformed
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn extrapolate<T, F>(inp: T, get_x: F) -> u64
+    where
+        T: Display,
+        F: Fn(usize) -> i128,
+    {
+        Self::get_sequences(inp)
+            .into_iter()
+            .map(|sequence| Self::extrapolate_sequence(&sequence, get_x(sequence.len()))
+                .unwrap_or_else(|err| panic!("{err}"))
+            )
+            .sum::<i128>() as u64
+    }
+}
+
+impl Solution for Day9 {
+    const NAME: &'static str = "Mirage Maintenance";
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn part_one<T: Display>(&self, inp: T) -> u64 {
+        Self::extrapolate(inp, |len| len as i128)
+    }
+
+    fn part_two<T: Display>(&self, inp: T) -> u64 {
+        Self::extrapolate(inp, |_| -1)
+    }
+
+    fn run(&self, inp: String) {
+        let p1 = in_span("part_one", || self.part_one(&inp));
+        let p2 = in_span("part_two", || self.part_two(&inp));
+
+        println!("Part 1: {p1}");
+        println!("Part 2: {p2}");
+
+        assert_eq!(p1, 1_647_269_739);
+        assert_eq!(p2, 864);
+    }
+}
+
+impl Parse for Day9 {
+    type Parsed<'a> = Vec<Vec<i128>>;
+    type Error = ParseError;
+
+    fn parse_input(&self, inp: &str) -> Result<Vec<Vec<i128>>, ParseError> {
+        Self::parse(inp)
+    }
+}
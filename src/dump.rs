@@ -0,0 +1,63 @@
+//! Writing out a day's parsed input and intermediate results as JSON, for other tools to inspect
+
+use std::{fmt, str::FromStr};
+use crate::Parse;
+
+/// How far into solving the day's model is dumped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Stage {
+    /// The input as parsed
+    #[default]
+    Parsed,
+    /// What the day works out from the parsed input on the way to the answers,
+    /// such as Day 22's support mappings
+    Intermediate,
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(stage: &str) -> Result<Self, Self::Err> {
+        match stage {
+            "parsed" => Ok(Self::Parsed),
+            "intermediate" => Ok(Self::Intermediate),
+            _ => Err(format!("Invalid stage `{stage}`, expected `parsed` or `intermediate`")),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Parsed => "parsed",
+            Self::Intermediate => "intermediate",
+        })
+    }
+}
+
+/// A day's intermediate stage, converted to JSON so that [`Parse::intermediate`] can return any serializable type
+pub type Intermediate = serde_json::Result<serde_json::Value>;
+
+/// The day's model of the input at the stage, as JSON
+///
+/// # Errors
+///
+/// If the input is malformed, the day has no intermediate stage or the model failed to be serialized
+pub fn dump<D: Parse>(cls: &D, inp: &str, stage: Stage, pretty: bool) -> Result<String, String> {
+    let parsed = cls
+        .parse_input(inp)
+        .map_err(|err| err.to_string())?;
+    let model = match stage {
+        Stage::Parsed => serde_json::to_value(parsed),
+        Stage::Intermediate => cls
+            .intermediate(parsed)
+            .ok_or_else(|| "The day has no intermediate stage, it solves straight from the parsed input".to_string())?,
+    };
+    model
+        .and_then(|model| if pretty {
+            serde_json::to_string_pretty(&model)
+        } else {
+            serde_json::to_string(&model)
+        })
+        .map_err(|err| format!("Failed to serialize the {stage} model: {err}"))
+}
@@ -0,0 +1,462 @@
+//! Dependency-free image export (PPM and PNG) of canvases and polygons
+
+#[cfg(feature = "fs")]
+use std::{
+    fs,
+    io,
+    path::Path,
+};
+use crate::{Canvas, Color, Polygon};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// Maps the terminal colours used by a [`Canvas`] onto actual pixel colours
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    background: Rgb,
+    colors: [Rgb; 9],
+}
+
+impl Palette {
+    #[must_use]
+    pub const fn dark() -> Self {
+        Self {
+            background: Rgb(24, 24, 32),
+            colors: [
+                Rgb(0, 0, 0),
+                Rgb(230, 70, 70),
+                Rgb(90, 200, 90),
+                Rgb(240, 200, 60),
+                Rgb(70, 120, 230),
+                Rgb(200, 90, 200),
+                Rgb(70, 200, 210),
+                Rgb(235, 235, 235),
+                Rgb(90, 90, 100),
+            ],
+        }
+    }
+
+    #[must_use]
+    pub const fn light() -> Self {
+        Self {
+            background: Rgb(250, 250, 245),
+            colors: [
+                Rgb(0, 0, 0),
+                Rgb(200, 30, 30),
+                Rgb(30, 150, 40),
+                Rgb(210, 150, 0),
+                Rgb(30, 70, 200),
+                Rgb(160, 40, 160),
+                Rgb(20, 150, 160),
+                Rgb(120, 120, 120),
+                Rgb(190, 190, 195),
+            ],
+        }
+    }
+
+    /// Looks up a palette by name, either `"dark"` or `"light"`
+    #[must_use]
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_background(mut self, rgb: Rgb) -> Self {
+        self.background = rgb;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_color(mut self, color: Color, rgb: Rgb) -> Self {
+        self.colors[Self::index(color)] = rgb;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn background(&self) -> Rgb {
+        self.background
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get(&self, color: Color) -> Rgb {
+        self.colors[Self::index(color)]
+    }
+
+    #[inline]
+    #[must_use]
+    const fn index(color: Color) -> usize {
+        match color {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::Gray => 8,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    #[must_use]
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width, height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Draws each cell of the canvas as a `scale` by `scale` block,
+    /// leaving uncoloured cells as the background
+    ///
+    /// # Panics
+    ///
+    /// If `scale` is 0
+    #[must_use]
+    pub fn from_canvas(canvas: &Canvas, palette: &Palette, scale: usize) -> Self {
+        assert!(scale > 0, "Image scale must be positive");
+        let mut image = Self::new(
+            canvas.n_cols() * scale,
+            canvas.n_rows() * scale,
+            palette.background(),
+        );
+
+        for row in 0..canvas.n_rows() {
+            for col in 0..canvas.n_cols() {
+                if let Some(color) = canvas
+                    .get((row, col))
+                    .and_then(|cell| cell.color)
+                {
+                    image.fill_rect(
+                        (row * scale, col * scale),
+                        (scale, scale),
+                        palette.get(color),
+                    );
+                }
+            }
+        }
+        image
+    }
+
+    /// Draws the polygon, whose vertices are `(row, col)` points,
+    /// shrunk down uniformly so that its longest side spans at most `max_side` pixels
+    ///
+    /// Each pixel is filled if its center lies inside the polygon,
+    /// with the outline traced on top so thin parts stay visible
+    ///
+    /// # Panics
+    ///
+    /// If `max_side` is 0
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_polygon(
+        polygon: &Polygon,
+        palette: &Palette,
+        (fill, outline): (Color, Color),
+        max_side: usize,
+    ) -> Self {
+        assert!(max_side > 0, "Image side must be positive");
+        let vertices = polygon.vertices();
+        let (min_row, max_row, min_col, max_col) = vertices
+            .iter()
+            .fold(
+                (i64::MAX, i64::MIN, i64::MAX, i64::MIN),
+                |(min_row, max_row, min_col, max_col), &(row, col)| (
+                    min_row.min(row), max_row.max(row),
+                    min_col.min(col), max_col.max(col),
+                ),
+            );
+        let (span_rows, span_cols) = (
+            (max_row - min_row + 1) as f64,
+            (max_col - min_col + 1) as f64,
+        );
+        // polygon units per pixel, never enlarging small polygons
+        let unit = (span_rows.max(span_cols) / max_side as f64).max(1.0);
+        let (height, width) = (
+            (span_rows / unit).ceil() as usize,
+            (span_cols / unit).ceil() as usize,
+        );
+        let to_pixel = |(row, col): (f64, f64)| (
+            (((row - min_row as f64) / unit) as usize).min(height - 1),
+            (((col - min_col as f64) / unit) as usize).min(width - 1),
+        );
+
+        let mut image = Self::new(width, height, palette.background());
+
+        // scanline fill, with the crossings of each pixel row's center
+        // found using the same half-open rule as `Polygon::locate`
+        for y in 0..height {
+            let row = (y as f64 + 0.5).mul_add(unit, min_row as f64);
+            let mut crossings = polygon
+                .edges()
+                .filter(|&((a_row, _), (b_row, _))| (a_row as f64 > row) != (b_row as f64 > row))
+                .map(|((a_row, a_col), (b_row, b_col))| {
+                    let t = (row - a_row as f64) / (b_row - a_row) as f64;
+                    t.mul_add((b_col - a_col) as f64, a_col as f64)
+                })
+                .collect::<Vec<f64>>();
+            crossings.sort_by(f64::total_cmp);
+
+            for pair in crossings.chunks_exact(2) {
+                let start = ((pair[0] - min_col as f64) / unit - 0.5)
+                    .ceil()
+                    .max(0.0);
+                let end = ((pair[1] - min_col as f64) / unit - 0.5)
+                    .floor()
+                    .min((width - 1) as f64);
+                if start <= end {
+                    image.fill_rect((y, start as usize), (1, (end - start) as usize + 1), palette.get(fill));
+                }
+            }
+        }
+
+        for ((a_row, a_col), (b_row, b_col)) in polygon.edges() {
+            let steps = (a_row.abs_diff(b_row).max(a_col.abs_diff(b_col)) as f64 / unit)
+                .ceil() as usize;
+            for step in 0..=steps {
+                let t = step as f64 / steps.max(1) as f64;
+                image.set_pixel(
+                    to_pixel((
+                        t.mul_add((b_row - a_row) as f64, a_row as f64),
+                        t.mul_add((b_col - a_col) as f64, a_col as f64),
+                    )),
+                    palette.get(outline),
+                );
+            }
+        }
+        image
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get_pixel(&self, (row, col): (usize, usize)) -> Option<Rgb> {
+        (row < self.height && col < self.width)
+            .then(|| self.pixels[row * self.width + col])
+    }
+
+    /// Colours a pixel, ignoring it if out of bounds
+    #[inline]
+    pub fn set_pixel(&mut self, (row, col): (usize, usize), rgb: Rgb) {
+        if row < self.height && col < self.width {
+            self.pixels[row * self.width + col] = rgb;
+        }
+    }
+
+    /// Colours a `(height, width)` block of pixels starting from its top left
+    pub fn fill_rect(&mut self, (row, col): (usize, usize), (height, width): (usize, usize), rgb: Rgb) {
+        for i in row..(row + height).min(self.height) {
+            for j in col..(col + width).min(self.width) {
+                self.pixels[i * self.width + j] = rgb;
+            }
+        }
+    }
+
+    /// Encodes the image as a binary (P6) PPM
+    #[must_use]
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height)
+            .into_bytes();
+        for &Rgb(r, g, b) in &self.pixels {
+            bytes.extend([r, g, b]);
+        }
+        bytes
+    }
+
+    /// Encodes the image as an 8-bit RGB PNG
+    ///
+    /// The pixel data is stored without compression,
+    /// so that no deflate implementation is needed
+    ///
+    /// # Panics
+    ///
+    /// If either dimension does not fit in a `u32`
+    #[must_use]
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            // filter type: none
+            raw.push(0);
+            for &Rgb(r, g, b) in row {
+                raw.extend([r, g, b]);
+            }
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend(u32::try_from(self.width).unwrap().to_be_bytes());
+        header.extend(u32::try_from(self.height).unwrap().to_be_bytes());
+        // bit depth 8, colour type RGB, default compression, filtering and no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_chunk(&mut bytes, b"IHDR", &header);
+        write_chunk(&mut bytes, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+
+    /// Writes the image as a PNG or PPM, depending on the path's extension
+    ///
+    /// # Errors
+    ///
+    /// If the extension is neither `png` nor `ppm`, or failed to write the file
+    #[cfg(feature = "fs")]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let bytes = match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+        {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unsupported image format for {}, expected .png or .ppm", path.display()),
+            )),
+        };
+        fs::write(path, bytes)
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// Appends a PNG chunk: its length, type, data and the CRC over the type and data
+fn write_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend(u32::try_from(data.len()).unwrap().to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+/// Wraps the data in a zlib stream made of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+    let mut blocks = data.chunks(usize::from(u16::MAX)).peekable();
+
+    if blocks.peek().is_none() {
+        bytes.extend([1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = u16::try_from(block.len()).unwrap();
+        bytes.push(u8::from(blocks.peek().is_none()));
+        bytes.extend(len.to_le_bytes());
+        bytes.extend((!len).to_le_bytes());
+        bytes.extend(block);
+    }
+    bytes.extend(adler32(data).to_be_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    /// Reads back the stored deflate blocks of a zlib stream, checking its framing
+    fn unstore(zlib: &[u8]) -> Vec<u8> {
+        assert_eq!(zlib[..2], [0x78, 0x01]);
+        let (mut data, mut rest) = (Vec::new(), &zlib[2..]);
+        loop {
+            let is_final = rest[0] == 1;
+            let len = u16::from_le_bytes([rest[1], rest[2]]);
+            assert_eq!(!len, u16::from_le_bytes([rest[3], rest[4]]));
+            data.extend(&rest[5..5 + usize::from(len)]);
+            rest = &rest[5 + usize::from(len)..];
+            if is_final {
+                break;
+            }
+        }
+        assert_eq!(rest, adler32(&data).to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_png() {
+        // 120 rows of 200 pixels make more raw data than fits in one stored block
+        let mut image = Image::new(200, 120, Rgb(0, 0, 0));
+        image.fill_rect((10, 20), (30, 40), Rgb(255, 128, 1));
+        let png = image.to_png();
+        assert_eq!(png[..8], *b"\x89PNG\r\n\x1a\n");
+
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (kind, data) = (&rest[4..8], &rest[8..8 + len]);
+            let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+            assert_eq!(crc, crc32(&rest[4..8 + len]));
+            chunks.push((kind, data));
+            rest = &rest[12 + len..];
+        }
+        assert_eq!(chunks.iter().map(|&(kind, _)| kind).collect::<Vec<_>>(), [b"IHDR", b"IDAT", b"IEND"]);
+        assert_eq!(chunks[0].1, [0, 0, 0, 200, 0, 0, 0, 120, 8, 2, 0, 0, 0]);
+
+        let raw = unstore(chunks[1].1);
+        assert_eq!(raw.len(), 120 * (1 + 200 * 3));
+        assert!(raw.len() > usize::from(u16::MAX));
+        let row = &raw[15 * 601..16 * 601];
+        assert_eq!(row[0], 0);
+        assert_eq!(row[1 + 19 * 3..1 + 21 * 3], [0, 0, 0, 255, 128, 1]);
+    }
+}
@@ -1,39 +1,496 @@
+#![cfg_attr(feature = "nightly", feature(iter_array_chunks))]
+
+use std::env;
+#[cfg(feature = "fs")]
 use std::{
-    time::Instant,
+    collections::BTreeMap,
+    io,
+    process,
+    time::{Duration, Instant},
     fs::read_to_string
 };
 
 pub use solution::*;
 pub use util::*;
+pub use visualize::*;
+pub use image::*;
+pub use animation::*;
+pub use trace::{span, in_span};
+pub use options::*;
+pub use config::{config, ConfigError, Params};
+pub use repl::{Explore, ReplCommand, GRID_COMMANDS, grid_command, parse_arg};
+pub use dump::{Intermediate, Stage};
+pub use days::{days, solve_part};
 
 pub mod solution;
+pub mod days;
 pub mod util;
+pub mod visualize;
+pub mod image;
+pub mod animation;
+pub mod trace;
+#[cfg(feature = "fs")]
+pub mod perf;
+pub mod memory;
+pub mod options;
+pub mod config;
+pub mod repl;
+pub mod dump;
+#[cfg(feature = "fs")]
+pub mod serve;
+#[cfg(feature = "fs")]
+pub mod cli;
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod wasm;
+
+#[cfg(feature = "fs")]
+use trace::{ChromeTrace, FlameSummary};
+#[cfg(feature = "fs")]
+use perf::PerfRecorder;
+#[cfg(feature = "fs")]
+use memory::MemorySummary;
 
 /// # Panics
 ///
 /// Will panic if failed to read input file for specified day for whatever reason
+#[cfg(feature = "fs")]
 #[inline]
 #[must_use]
 pub fn get_input(day: u8) -> String {
-    read_to_string(format!("./inputs/day{day}.txt"))
+    read_to_string(config().inputs_dir().join(format!("day{day}.txt")))
         .unwrap_or_else(|_| panic!("Failed to read input for Day {day}"))
 }
 
-pub fn run_day<D: Solution>(day: u8, cls: &D) {
-    let text = format!(" Day [{day}] Solution - {} ", cls.name());
-    let line = format!(
-        "+------+{}+",
-        "-".repeat(text.chars().count())
-    );
-    println!("\n{line}\n| RUST |{text}|\n{line}");
+/// # Errors
+///
+/// If failed to read the variant's file, with a message naming it
+#[cfg(feature = "fs")]
+pub fn read_input(day: u8, variant: &InputVariant) -> Result<String, String> {
+    match variant.path(day) {
+        Some(path) => read_to_string(&path)
+            .map_err(|err| format!("Failed to read `{variant}` input for Day {day} from {}: {err}", path.display())),
+        None => io::read_to_string(io::stdin())
+            .map_err(|err| format!("Failed to read input for Day {day} from stdin: {err}")),
+    }
+}
+
+/// Reads the day's input selected by `--input <puzzle|name|path>`, see [`InputVariant`]
+///
+/// # Panics
+///
+/// If the variant is invalid or failed to read its file
+#[cfg(feature = "fs")]
+#[must_use]
+pub fn get_input_variant(day: u8) -> String {
+    let variant = get_flag_value("--input")
+        .map_or(Ok(InputVariant::Puzzle), |variant| variant.parse())
+        .unwrap_or_else(|err| panic!("{err}"));
+    read_input(day, &variant)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// The flags that visualize, animate or explore a day instead of solving it
+#[cfg(feature = "fs")]
+pub const MODE_FLAGS: [&str; 5] = ["--visualize", "--image", "--animate", "--frames", "--repl"];
+
+/// Prints an invalid option and exits, rather than panicking,
+/// so the CLI can pass the message on as is
+#[cfg(feature = "fs")]
+fn exit_with_error(err: &str) -> ! {
+    eprintln!("error: {err}");
+    process::exit(2)
+}
+
+/// The day's solution with its puzzle parameters from `aoc.toml`,
+/// or `None` if they are its own, see [`Solution::configure`]
+#[cfg(feature = "fs")]
+fn configure_day<D: Solution>(day: u8, cls: &D) -> Option<D> {
+    let config = config::try_config()
+        .unwrap_or_else(|err| exit_with_error(err));
+    cls
+        .configure(&config.params(day))
+        .unwrap_or_else(|err| exit_with_error(&format!("Invalid config: {err}")))
+}
+
+/// Runs the day's solution according to its [`RunOptions`]
+///
+/// The day's puzzle parameters are read from `aoc.toml` and passed to the solution
+/// through [`Solution::configure`], as they are by the other `*_day` helpers.
+///
+/// With the default options, the day's own [`Solution::run`] prints both answers
+/// and checks them against the puzzle input's.
+/// Otherwise, or when `aoc.toml` sets the day's parameters to other than the puzzle's,
+/// the parts are solved directly, as selected and without checking the answers.
+/// With `--dump`, only the day's model of its input is written out, see [`Parse`].
+/// With any of [`MODE_FLAGS`], nothing is solved,
+/// leaving the day's binary to go on to [`visualize_day`], [`animate_day`] or [`repl_day`]
+#[cfg(feature = "fs")]
+pub fn run_day<D: Solution + Parse>(day: u8, cls: &D) {
+    if let Some(stage) = get_flag_value("--dump") {
+        return dump_day(day, cls, &stage);
+    }
+    if MODE_FLAGS.iter().any(|flag| has_flag(flag)) {
+        return;
+    }
+    let config = config::try_config()
+        .unwrap_or_else(|err| exit_with_error(err));
+    let configured = configure_day(day, cls);
+    let cls = configured.as_ref().unwrap_or(cls);
+    let options = RunOptions::from_args()
+        .unwrap_or_else(|err| exit_with_error(&err));
+    let text_output = options.format == OutputFormat::Text;
+
+    if text_output && options.verbosity > Verbosity::Quiet {
+        let text = format!(" Day [{day}] Solution - {} ", cls.name());
+        let line = format!(
+            "+------+{}+",
+            "-".repeat(text.chars().count())
+        );
+        println!("\n{line}\n| RUST |{text}|\n{line}");
+    }
 
-    let input = get_input(day);
+    // with the `alloc-stats` feature, heap usage is reported alongside the execution time
+    let memory = memory::is_counting().then(MemorySummary::new);
+    if let Some(memory) = &memory {
+        trace::add_subscriber(memory.clone());
+    }
+
+    // `--trace <file.json>` writes a Chrome trace,
+    // `--trace folded` prints folded stacks and any other value prints a flame summary,
+    // as does `--verbose` on its own
+    // and with `--format json`, summaries go to stderr to leave stdout to the answers
+    let trace = get_flag_value("--trace");
+    let flame = |summary: FlameSummary| if text_output { summary } else { summary.on_stderr() };
+    match trace.as_deref() {
+        Some(path) if path.ends_with(".json") => trace::add_subscriber(ChromeTrace::new(path)),
+        Some("folded") => trace::add_subscriber(flame(FlameSummary::folded())),
+        Some(_) => trace::add_subscriber(flame(FlameSummary::new())),
+        None if options.verbosity == Verbosity::Verbose && text_output => trace::add_subscriber(FlameSummary::new()),
+        None => {},
+    }
+    let record = has_flag("--record");
+    if record {
+        trace::add_subscriber(get_perf_recorder(day));
+    }
+
+    let input = read_input(day, &options.input)
+        .unwrap_or_else(|err| exit_with_error(&err));
     // benchmark and run
     let instant = Instant::now();
-    cls.run(input);
-    let text = format!("Execution time: {:?}", instant.elapsed());
+    if let Some(runs) = options.runs {
+        bench_parts(day, cls, &input, &options, runs, memory.as_ref());
+    } else if options.is_default_run() && configured.is_none() {
+        in_span("run", || cls.run(input));
+    } else {
+        let answers = in_span("run", || options
+            .parts()
+            .into_iter()
+            .map(|part| (part, in_span(part.span_name(), || solve_with(cls, part, &input))))
+            .collect::<Vec<(Part, u64)>>()
+        );
+        let elapsed = instant.elapsed();
+        print_answers(day, cls, &options, &answers, elapsed, memory.as_ref());
+    }
+    let elapsed = instant.elapsed();
+    if text_output && options.verbosity > Verbosity::Quiet {
+        let time = format!("Execution time: {elapsed:?}");
+        let text = match &memory {
+            Some(memory) => format!("{time} | heap {}", memory.report()),
+            None => time.clone(),
+        };
+        println!(
+            "{text}\n{}",
+            "=".repeat(time.chars().count())
+        );
+    }
+    // the budget is only meaningful for a single run of the parts
+    if let Some(budget) = config
+        .budget(day)
+        .filter(|&budget| options.runs.is_none() && elapsed > budget)
+    {
+        eprintln!("warning: Day {day} took {elapsed:?}, over its budget of {budget:?}");
+    }
+
+    if trace::is_tracing() {
+        trace::finish_subscribers()
+            .unwrap_or_else(|err| panic!("Failed to write trace: {err}"));
+    }
+}
+
+#[cfg(feature = "fs")]
+fn solve_with<D: Solution>(cls: &D, part: Part, input: &str) -> u64 {
+    match part {
+        Part::One => cls.part_one(input),
+        Part::Two => cls.part_two(input),
+    }
+}
+
+#[cfg(feature = "fs")]
+fn print_answers<D: Solution>(
+    day: u8,
+    cls: &D,
+    options: &RunOptions,
+    answers: &[(Part, u64)],
+    elapsed: Duration,
+    memory: Option<&MemorySummary>,
+) {
+    match options.format {
+        OutputFormat::Text => for (part, answer) in answers {
+            println!("Part {}: {answer}", part.number());
+        },
+        OutputFormat::Json => {
+            let answer = |part| answers
+                .iter()
+                .find(|&&(other, _)| other == part)
+                .map(|&(_, answer)| answer);
+            print_json(&AnswersOutput {
+                day,
+                name: cls.name().to_string(),
+                input: options.input.to_string(),
+                part_one: answer(Part::One),
+                part_two: answer(Part::Two),
+                time_ns: u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX),
+                heap: heap_output(memory),
+            });
+        },
+    }
+}
+
+/// Each phase's heap usage, for the JSON outputs
+#[cfg(feature = "fs")]
+fn heap_output(memory: Option<&MemorySummary>) -> BTreeMap<String, HeapOutput> {
+    memory
+        .map(MemorySummary::phases)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(phase, stats)| (phase.to_string(), stats.into()))
+        .collect()
+}
+
+/// Prints one of the outputs in [`options`] on a single line
+#[cfg(feature = "fs")]
+fn print_json<T: serde::Serialize>(output: &T) {
     println!(
-        "{text}\n{}",
-        "=".repeat(text.chars().count())
+        "{}",
+        serde_json::to_string(output).expect("Outputs always serialize"),
+    );
+}
+
+/// Solves each selected part `runs` times,
+/// reporting the fastest, median and mean times
+#[cfg(feature = "fs")]
+fn bench_parts<D: Solution>(
+    day: u8,
+    cls: &D,
+    input: &str,
+    options: &RunOptions,
+    runs: usize,
+    memory: Option<&MemorySummary>,
+) {
+    let mut results = Vec::new();
+    for part in options.parts() {
+        let mut times = (0..runs)
+            .map(|_| {
+                let instant = Instant::now();
+                in_span(part.span_name(), || solve_with(cls, part, input));
+                instant.elapsed()
+            })
+            .collect::<Vec<Duration>>();
+        times.sort_unstable();
+        let mean = times
+            .iter()
+            .sum::<Duration>() / u32::try_from(runs).unwrap_or(u32::MAX);
+        results.push((part, times[0], times[runs / 2], mean));
+    }
+
+    match options.format {
+        OutputFormat::Text => for (part, min, median, mean) in results {
+            println!("Part {}: min {min:.3?}, median {median:.3?}, mean {mean:.3?} over {runs} runs", part.number());
+        },
+        OutputFormat::Json => {
+            let nanos = |time: Duration| u64::try_from(time.as_nanos()).unwrap_or(u64::MAX);
+            let timings = |part| results
+                .iter()
+                .find(|&&(other, ..)| other == part)
+                .map(|&(_, min, median, mean)| TimingsOutput {
+                    min_ns: nanos(min),
+                    median_ns: nanos(median),
+                    mean_ns: nanos(mean),
+                });
+            print_json(&BenchOutput {
+                day,
+                name: cls.name().to_string(),
+                input: options.input.to_string(),
+                runs,
+                part_one: timings(Part::One),
+                part_two: timings(Part::Two),
+                heap: heap_output(memory),
+            });
+        },
+    }
+}
+
+/// Whether the flag was passed in the program's arguments
+#[must_use]
+pub fn has_flag(flag: &str) -> bool {
+    env::args()
+        .skip(1)
+        .any(|arg| arg == flag)
+}
+
+/// Sets up recording the day's timings from the `--history <path>`,
+/// `--threshold <percent>` (10 by default) and `--baseline <commit>` flags
+///
+/// # Panics
+///
+/// If the threshold is not a number
+#[cfg(feature = "fs")]
+#[must_use]
+pub fn get_perf_recorder(day: u8) -> PerfRecorder {
+    let threshold = get_flag_value("--threshold")
+        .map_or(10.0, |threshold| threshold
+            .parse::<f64>()
+            .unwrap_or_else(|_| panic!("Invalid regression threshold `{threshold}`"))
+        );
+
+    PerfRecorder::new(
+        day,
+        get_flag_value("--history")
+            .unwrap_or_else(|| perf::DEFAULT_HISTORY_PATH.to_string()),
+        threshold / 100.0,
+        get_flag_value("--baseline"),
+    )
+}
+
+/// Finds the value following a flag in the program's arguments, e.g. `--scale 4`
+#[must_use]
+pub fn get_flag_value(flag: &str) -> Option<String> {
+    env::args()
+        .skip_while(|arg| arg != flag)
+        .nth(1)
+}
+
+/// Reads the `--scale <pixels per cell>` and `--palette <dark|light>` image flags
+///
+/// # Panics
+///
+/// If either flag is invalid
+#[must_use]
+pub fn get_image_options() -> (Palette, usize) {
+    let scale = get_flag_value("--scale")
+        .map_or(4, |scale| scale
+            .parse::<usize>()
+            .unwrap_or_else(|_| panic!("Invalid image scale `{scale}`"))
+        );
+    let palette = get_flag_value("--palette")
+        .map_or_else(Palette::default, |name| Palette::named(&name)
+            .unwrap_or_else(|| panic!("Unknown palette `{name}`, expected `dark` or `light`"))
+        );
+    (palette, scale)
+}
+
+/// Draws the day's grid state if the `--visualize` flag was passed,
+/// and writes it as an image if `--image <path.png|path.ppm>` was passed
+/// (see [`get_image_options`] for the other image flags)
+///
+/// # Panics
+///
+/// If the image flags are invalid or failed to write the image
+#[cfg(feature = "fs")]
+pub fn visualize_day<D: Visualize + Solution>(day: u8, cls: &D) {
+    if !has_flag("--visualize") && !has_flag("--image") {
+        return;
+    }
+    let configured = configure_day(day, cls);
+    let cls = configured.as_ref().unwrap_or(cls);
+
+    if has_flag("--visualize") {
+        println!("{}", cls.visualize(get_input_variant(day)));
+    }
+
+    if let Some(path) = get_flag_value("--image") {
+        let (palette, scale) = get_image_options();
+
+        cls.rasterize(get_input_variant(day), &palette, scale)
+            .save(&path)
+            .unwrap_or_else(|err| panic!("Failed to write image to {path}: {err}"));
+        println!("Image written to {path}");
+    }
+}
+
+/// Records the day's simulation if either the `--animate` flag was passed,
+/// to play it back in the terminal with `--delay <ms>` between frames (50 by default),
+/// or `--frames <dir>` was passed, to write each frame there as a numbered image
+/// (see [`get_image_options`] for the other image flags)
+///
+/// `--every <n>` keeps only every n-th frame
+///
+/// # Panics
+///
+/// If the flags are invalid or failed to write the frames
+#[cfg(feature = "fs")]
+pub fn animate_day<D: Animate + Solution>(day: u8, cls: &D) {
+    let play = has_flag("--animate");
+    let frames_dir = get_flag_value("--frames");
+    if !play && frames_dir.is_none() {
+        return;
+    }
+    let configured = configure_day(day, cls);
+    let cls = configured.as_ref().unwrap_or(cls);
+
+    let parse_flag = |flag: &str, default: u64| get_flag_value(flag)
+        .map_or(default, |value| value
+            .parse::<u64>()
+            .unwrap_or_else(|_| panic!("Invalid value `{value}` for {flag}"))
+        );
+    let mut animation = Animation::with_stride(
+        usize::try_from(parse_flag("--every", 1)).unwrap()
     );
-}
\ No newline at end of file
+    cls.animate(get_input_variant(day), &mut animation);
+
+    if play {
+        animation
+            .play(Duration::from_millis(parse_flag("--delay", 50)))
+            .unwrap_or_else(|err| panic!("Failed to play animation: {err}"));
+    }
+    if let Some(dir) = frames_dir {
+        let (palette, scale) = get_image_options();
+
+        animation
+            .save_frames(&dir, &palette, scale)
+            .unwrap_or_else(|err| panic!("Failed to write frames to {dir}: {err}"));
+        println!("{} frames written to {dir}", animation.len());
+    }
+}
+
+/// Opens a REPL over the day's parsed input if the `--repl` flag was passed,
+/// reading the input selected by `--input` again on every `reload`
+///
+/// # Panics
+///
+/// If failed to read the input or to use stdin and stdout
+#[cfg(feature = "fs")]
+pub fn repl_day<D: Explore + Solution>(day: u8, cls: &D) {
+    if has_flag("--repl") {
+        let configured = configure_day(day, cls);
+        repl::run_repl(day, configured.as_ref().unwrap_or(cls), || get_input_variant(day))
+            .unwrap_or_else(|err| panic!("REPL failed: {err}"));
+    }
+}
+
+/// Writes the day's model of its input at the stage passed to `--dump <parsed|intermediate>` as JSON,
+/// on one line with `--format json` and pretty-printed otherwise
+#[cfg(feature = "fs")]
+fn dump_day<D: Parse>(day: u8, cls: &D, stage: &str) {
+    let stage = stage
+        .parse::<Stage>()
+        .unwrap_or_else(|err| exit_with_error(&err));
+    let options = RunOptions::from_args()
+        .unwrap_or_else(|err| exit_with_error(&err));
+    let input = read_input(day, &options.input)
+        .unwrap_or_else(|err| exit_with_error(&err));
+
+    let json = dump::dump(cls, &input, stage, options.format == OutputFormat::Text)
+        .unwrap_or_else(|err| exit_with_error(&err));
+    println!("{json}");
+}
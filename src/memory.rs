@@ -0,0 +1,231 @@
+//! Heap usage accounting through a counting global allocator
+//!
+//! The allocator is only installed with the `alloc-stats` feature,
+//! otherwise every snapshot reads as zero and [`is_counting`] is false.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::{self, Display},
+    io,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use crate::trace::{SpanRecord, Subscriber};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator, counting allocations and tracking the peak heap size
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // counted as freeing the old block and allocating the new one
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Whether the counting allocator is installed
+#[inline]
+#[must_use]
+pub const fn is_counting() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// The allocator's counters at some point in time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocSnapshot {
+    /// Bytes currently allocated
+    pub current: usize,
+    /// Bytes allocated at the peak since the last [`reset_peak`]
+    pub peak: usize,
+    /// Number of allocations made so far
+    pub allocations: usize,
+    /// Total bytes allocated so far, including those since freed
+    pub allocated: usize,
+}
+
+impl AllocSnapshot {
+    #[must_use]
+    pub fn now() -> Self {
+        Self {
+            current: CURRENT.load(Ordering::Relaxed),
+            peak: PEAK.load(Ordering::Relaxed),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated: ALLOCATED.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Restarts peak tracking from the current heap size, returning the peak so far
+pub fn reset_peak() -> usize {
+    PEAK.swap(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed)
+}
+
+/// Restores a peak returned by [`reset_peak`], unless it has since been exceeded
+pub fn restore_peak(peak: usize) {
+    PEAK.fetch_max(peak, Ordering::Relaxed);
+}
+
+/// Heap usage over a span of code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// The most bytes allocated at once during the span
+    pub peak: usize,
+    pub allocations: usize,
+    /// Total bytes allocated during the span, including those since freed
+    pub allocated: usize,
+}
+
+impl AllocStats {
+    /// The usage between two snapshots,
+    /// where peak tracking was reset when `start` was taken
+    #[must_use]
+    pub const fn between(start: &AllocSnapshot, end: &AllocSnapshot) -> Self {
+        Self {
+            peak: end.peak,
+            allocations: end.allocations.saturating_sub(start.allocations),
+            allocated: end.allocated.saturating_sub(start.allocated),
+        }
+    }
+
+    /// Combines the usage of two spans, such as the same phase run twice
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            peak: self.peak.max(other.peak),
+            allocations: self.allocations + other.allocations,
+            allocated: self.allocated + other.allocated,
+        }
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 MiB`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    #[allow(clippy::cast_precision_loss)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        f.pad(&format!("{size:.2} {}", UNITS[unit]))
+    }
+}
+
+/// Totals the heap usage of the `parse`, `part_one` and `part_two` spans,
+/// to be reported alongside the execution time once the run is done.
+/// Clones share their totals, so one can be kept while another is added as a subscriber
+#[derive(Debug, Clone, Default)]
+pub struct MemorySummary {
+    phases: Rc<RefCell<BTreeMap<&'static str, AllocStats>>>,
+}
+
+impl MemorySummary {
+    const PHASES: [&'static str; 3] = ["parse", "part_one", "part_two"];
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The heap usage of each phase that ran, in the order they run in
+    #[must_use]
+    pub fn phases(&self) -> Vec<(&'static str, AllocStats)> {
+        let phases = self.phases.borrow();
+        Self::PHASES
+            .into_iter()
+            .filter_map(|phase| phases
+                .get(phase)
+                .map(|&stats| (phase, stats))
+            )
+            .collect()
+    }
+
+    /// The heap usage of each phase on a single line, e.g.
+    /// `parse: peak 1.50 KiB, 12 allocations, 3.00 KiB allocated; part_one: ...`
+    #[must_use]
+    pub fn report(&self) -> String {
+        self.phases()
+            .into_iter()
+            .map(|(phase, stats)| format!(
+                "{phase}: peak {}, {} allocations, {} allocated",
+                Bytes(stats.peak),
+                stats.allocations,
+                Bytes(stats.allocated),
+            ))
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+}
+
+impl Subscriber for MemorySummary {
+    fn on_close(&mut self, record: SpanRecord) {
+        if let Some(stats) = record.memory
+            .filter(|_| Self::PHASES.contains(&record.name()))
+        {
+            let mut phases = self.phases.borrow_mut();
+            let total = phases
+                .entry(record.name())
+                .or_default();
+            *total = total.merge(stats);
+        }
+    }
+
+    /// The totals are read back through [`MemorySummary::report`] instead
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+//! Options understood by every day's binary, as passed on by the `aoc-2023` CLI
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+};
+use serde::{Deserialize, Serialize};
+use crate::{config, memory::AllocStats};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// The name of the part's span, `part_one` or `part_two`
+    #[must_use]
+    pub const fn span_name(self) -> &'static str {
+        match self {
+            Self::One => "part_one",
+            Self::Two => "part_two",
+        }
+    }
+
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Self, Self::Err> {
+        match part {
+            "1" | "one" => Ok(Self::One),
+            "2" | "two" => Ok(Self::Two),
+            _ => Err(format!("Invalid part `{part}`, expected 1 or 2")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// One JSON object per day, for scripts
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Invalid output format `{format}`, expected `text` or `json`")),
+        }
+    }
+}
+
+/// A day's answers as printed with `--format json`, which the CLI reads back
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswersOutput {
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<u64>,
+    pub time_ns: u64,
+    /// Each phase's heap usage, when built with the `alloc-stats` feature
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub heap: BTreeMap<String, HeapOutput>,
+}
+
+impl AnswersOutput {
+    /// Reads the first line of a day's output that holds its answers,
+    /// skipping anything else it printed
+    #[must_use]
+    pub fn from_output(stdout: &str) -> Option<Self> {
+        stdout
+            .lines()
+            .find_map(|line| serde_json::from_str(line).ok())
+    }
+
+    #[must_use]
+    pub const fn answer(&self, part: Part) -> Option<u64> {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+/// A part's timings over several runs, as printed with `bench --format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimingsOutput {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+/// A day's timings as printed with `bench --format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchOutput {
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    pub runs: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<TimingsOutput>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<TimingsOutput>,
+    /// Each phase's heap usage over every run, when built with the `alloc-stats` feature
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub heap: BTreeMap<String, HeapOutput>,
+}
+
+/// A phase's heap usage, as printed with `--format json`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeapOutput {
+    pub peak_bytes: usize,
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+}
+
+impl From<AllocStats> for HeapOutput {
+    fn from(stats: AllocStats) -> Self {
+        Self {
+            peak_bytes: stats.peak,
+            allocations: stats.allocations,
+            allocated_bytes: stats.allocated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only the answers
+    Quiet,
+    #[default]
+    Normal,
+    /// Also the time taken by each part
+    Verbose,
+}
+
+/// Which input a day reads, see [`InputVariant::path`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputVariant {
+    /// `inputs/day{day}.txt`, the puzzle input the answers are checked against,
+    /// under the inputs directory set in `aoc.toml`
+    #[default]
+    Puzzle,
+    /// `inputs/day{day}.{name}.txt`, such as `example`
+    Named(String),
+    /// Any file, when the variant contains a path separator or ends in `.txt`
+    File(PathBuf),
+    /// Standard input, as `-`
+    Stdin,
+}
+
+impl InputVariant {
+    /// The file the input is read from, if not standard input
+    #[must_use]
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Puzzle => Some(config().inputs_dir().join(format!("day{day}.txt"))),
+            Self::Named(name) => Some(config().inputs_dir().join(format!("day{day}.{name}.txt"))),
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+}
+
+impl FromStr for InputVariant {
+    type Err = String;
+
+    fn from_str(variant: &str) -> Result<Self, Self::Err> {
+        Ok(match variant {
+            "" => return Err("Input variant cannot be empty".to_string()),
+            "puzzle" => Self::Puzzle,
+            "-" => Self::Stdin,
+            path if path.contains(['/', '\\']) || path.ends_with(".txt") => Self::File(path.into()),
+            name => Self::Named(name.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for InputVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Puzzle => f.write_str("puzzle"),
+            Self::Named(name) => f.write_str(name),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => f.write_str("stdin"),
+        }
+    }
+}
+
+/// How a day's binary should run, read from its arguments
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// `--part <1|2>`, both parts if not provided
+    pub part: Option<Part>,
+    /// `--input <puzzle|name|path|->`
+    pub input: InputVariant,
+    /// `--format <text|json>`, or as set in `aoc.toml`
+    pub format: OutputFormat,
+    /// `-q`/`--quiet` or `-v`/`--verbose`
+    pub verbosity: Verbosity,
+    /// `--runs <n>` to benchmark the parts over n runs
+    pub runs: Option<usize>,
+}
+
+impl RunOptions {
+    /// # Errors
+    ///
+    /// If any of the options has an invalid value
+    pub fn from_args() -> Result<Self, String> {
+        let mut options = Self::parse(env::args().skip(1))?;
+        if !env::args().any(|arg| arg == "--format") {
+            options.format = config().format();
+        }
+        Ok(options)
+    }
+
+    /// Reads the options out of the arguments, ignoring any others
+    ///
+    /// # Errors
+    ///
+    /// If any of the options has an invalid value
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args
+                .next()
+                .ok_or_else(|| format!("Missing value for {arg}"));
+
+            match arg.as_str() {
+                "--part" => options.part = Some(value()?.parse()?),
+                "--input" => options.input = value()?.parse()?,
+                "--format" => options.format = value()?.parse()?,
+                "--runs" => options.runs = Some(value()?
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or("The number of runs must be a positive integer")?
+                ),
+                "-q" | "--quiet" => options.verbosity = Verbosity::Quiet,
+                "-v" | "--verbose" => options.verbosity = Verbosity::Verbose,
+                _ => {},
+            }
+        }
+        Ok(options)
+    }
+
+    /// Whether the day's own `run` can be used as is,
+    /// printing both answers and checking them against the puzzle input's
+    #[must_use]
+    pub fn is_default_run(&self) -> bool {
+        self.part.is_none()
+            && self.input == InputVariant::Puzzle
+            && self.format == OutputFormat::Text
+            && self.runs.is_none()
+    }
+
+    /// The parts to run, in order
+    #[must_use]
+    pub fn parts(&self) -> Vec<Part> {
+        self.part.map_or_else(|| vec![Part::One, Part::Two], |part| vec![part])
+    }
+}
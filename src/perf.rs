@@ -0,0 +1,407 @@
+//! Local history of solution timings, with regression checks against a baseline
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Display, Write as _},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use crate::trace::{SpanRecord, Subscriber};
+
+/// Where timings are stored by default, relative to the repository root
+pub const DEFAULT_HISTORY_PATH: &str = "perf/history.tsv";
+
+/// How many of the most recent runs the default baseline is the median of
+pub const BASELINE_RUNS: usize = 5;
+
+/// How many of the most recent runs are drawn in a report's trend
+const TREND_RUNS: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerfRecord {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub commit: String,
+    pub machine: String,
+    /// 0 for the total across every day
+    pub day: u8,
+    /// `run`, `part_one`, `part_two` or `total`
+    pub phase: String,
+    pub duration: Duration,
+}
+
+impl PerfRecord {
+    /// Creates a record for the current commit and machine, timestamped now
+    #[must_use]
+    pub fn now(day: u8, phase: &str, duration: Duration) -> Self {
+        Self {
+            timestamp: unix_timestamp(),
+            commit: current_commit(),
+            machine: machine_name(),
+            day,
+            phase: phase.to_string(),
+            duration,
+        }
+    }
+
+    fn to_line(&self) -> String {
+        let clean = |field: &str| field.replace(['\t', '\n', '\r'], " ");
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            clean(&self.commit),
+            clean(&self.machine),
+            self.day,
+            clean(&self.phase),
+            self.duration.as_nanos(),
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let record = Self {
+            timestamp: fields.next()?.parse().ok()?,
+            commit: fields.next()?.to_string(),
+            machine: fields.next()?.to_string(),
+            day: fields.next()?.parse().ok()?,
+            phase: fields.next()?.to_string(),
+            duration: Duration::from_nanos(fields.next()?.parse().ok()?),
+        };
+        fields
+            .next()
+            .is_none()
+            .then_some(record)
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// The short hash of the checked out commit, marked with `-dirty` if there are uncommitted changes
+#[must_use]
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+    git(&["rev-parse", "--short", "HEAD"])
+        .map_or_else(|| "unknown".to_string(), |commit|
+            if git(&["status", "--porcelain", "--untracked-files=no"])
+                .is_some_and(|status| !status.is_empty())
+            {
+                format!("{commit}-dirty")
+            } else {
+                commit
+            }
+        )
+}
+
+/// The host name along with the OS and architecture, e.g. `box (linux-x86_64)`
+#[must_use]
+pub fn machine_name() -> String {
+    let host = env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    format!("{host} ({}-{})", env::consts::OS, env::consts::ARCH)
+}
+
+/// A timing that got slower than its baseline by more than the allowed threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: String,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// How much slower the current timing is, e.g. 0.25 for 25% slower
+    #[must_use]
+    pub fn slowdown(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Regression in {} {}: {:.3?} against a baseline of {:.3?} ({:+.1}%)",
+            day_label(self.day),
+            self.phase,
+            self.current,
+            self.baseline,
+            100.0 * self.slowdown(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerfHistory {
+    path: PathBuf,
+    records: Vec<PerfRecord>,
+}
+
+impl PerfHistory {
+    /// Reads the history, which is empty if the file does not exist yet
+    ///
+    /// Malformed lines are skipped
+    ///
+    /// # Errors
+    ///
+    /// If the file exists but could not be read
+    pub fn load<P: Into<PathBuf>>(path: P) -> io::Result<Self> {
+        let path = path.into();
+        let records = match fs::read_to_string(&path) {
+            Ok(raw) => raw
+                .lines()
+                .filter_map(PerfRecord::from_line)
+                .collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(Self { path, records })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn records(&self) -> &[PerfRecord] {
+        &self.records
+    }
+
+    /// Adds the records to the end of the history file
+    ///
+    /// # Errors
+    ///
+    /// If failed to create or write to the file
+    pub fn append(&mut self, records: &[PerfRecord]) -> io::Result<()> {
+        if let Some(dir) = self.path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+        {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        for record in records {
+            writeln!(file, "{}", record.to_line())?;
+        }
+        self.records.extend_from_slice(records);
+        Ok(())
+    }
+
+    /// The past timings of a day's phase on a machine, oldest first
+    fn timings<'a>(&'a self, day: u8, phase: &'a str, machine: &'a str) -> impl Iterator<Item = &'a PerfRecord> {
+        self.records
+            .iter()
+            .filter(move |record| record.day == day && record.phase == phase && record.machine == machine)
+    }
+
+    /// The median timing of a day's phase on a machine,
+    /// either over every run at the `commit` if provided,
+    /// or else over the last [`BASELINE_RUNS`] runs
+    #[must_use]
+    pub fn baseline(&self, day: u8, phase: &str, machine: &str, commit: Option<&str>) -> Option<Duration> {
+        let mut durations = match commit {
+            Some(commit) => self.timings(day, phase, machine)
+                .filter(|record| record.commit == commit)
+                .map(|record| record.duration)
+                .collect::<Vec<Duration>>(),
+            None => {
+                let durations = self.timings(day, phase, machine)
+                    .map(|record| record.duration)
+                    .collect::<Vec<Duration>>();
+                durations[durations.len().saturating_sub(BASELINE_RUNS)..].to_vec()
+            },
+        };
+        durations.sort_unstable();
+        durations
+            .get(durations.len() / 2)
+            .copied()
+    }
+
+    /// Compares a new timing against its baseline,
+    /// flagging it if it is slower by more than `threshold` (e.g. 0.1 for 10%)
+    #[must_use]
+    pub fn check(&self, record: &PerfRecord, threshold: f64, commit: Option<&str>) -> Option<Regression> {
+        self.baseline(record.day, &record.phase, &record.machine, commit)
+            .map(|baseline| Regression {
+                day: record.day,
+                phase: record.phase.clone(),
+                baseline,
+                current: record.duration,
+            })
+            .filter(|regression| regression.slowdown() > threshold)
+    }
+
+    /// Tabulates the latest, baseline and best timings of every day's phases per machine,
+    /// along with a trend over the most recent runs,
+    /// optionally only for a single day
+    #[must_use]
+    pub fn report(&self, day: Option<u8>) -> String {
+        let mut groups: BTreeMap<&str, BTreeMap<(u8, &str), Vec<&PerfRecord>>> = BTreeMap::new();
+        for record in self.records
+            .iter()
+            .filter(|record| day.is_none_or(|day| record.day == day))
+        {
+            groups
+                .entry(&record.machine)
+                .or_default()
+                .entry((record.day, &record.phase))
+                .or_default()
+                .push(record);
+        }
+        if groups.is_empty() {
+            return format!("No timings recorded in {} yet\n", self.path.display());
+        }
+
+        let mut report = String::new();
+        for (machine, timings) in groups {
+            let _ = writeln!(report, "{machine}");
+            let _ = writeln!(
+                report,
+                "  {:<7} {:<9} {:>5} {:>12} {:>12} {:>12} {:>8}  Trend",
+                "Day", "Phase", "Runs", "Latest", "Baseline", "Best", "Change",
+            );
+            for ((day, phase), records) in timings {
+                let latest = records[records.len() - 1];
+                let best = records
+                    .iter()
+                    .map(|record| record.duration)
+                    .min()
+                    .unwrap_or_default();
+                // the baseline the latest run was compared against, from the runs before it
+                let previous = &records[..records.len() - 1];
+                let mut baseline = previous[previous.len().saturating_sub(BASELINE_RUNS)..]
+                    .iter()
+                    .map(|record| record.duration)
+                    .collect::<Vec<Duration>>();
+                baseline.sort_unstable();
+                let baseline = baseline.get(baseline.len() / 2).copied();
+
+                let _ = writeln!(
+                    report,
+                    "  {:<7} {phase:<9} {:>5} {:>12} {:>12} {:>12} {:>8}  {}",
+                    day_label(day),
+                    records.len(),
+                    format!("{:.3?}", latest.duration),
+                    baseline.map_or_else(|| "-".to_string(), |baseline| format!("{baseline:.3?}")),
+                    format!("{best:.3?}"),
+                    baseline.map_or_else(|| "-".to_string(), |baseline| format!(
+                        "{:+.1}%",
+                        100.0 * (latest.duration.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0),
+                    )),
+                    sparkline(&records[records.len().saturating_sub(TREND_RUNS)..]),
+                );
+            }
+        }
+        report
+    }
+}
+
+fn day_label(day: u8) -> String {
+    if day == 0 { "All".to_string() } else { format!("Day {day}") }
+}
+
+/// Draws timings as bars from ▁ (fastest) to █ (slowest)
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn sparkline(records: &[&PerfRecord]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let (min, max) = records
+        .iter()
+        .map(|record| record.duration)
+        .fold((Duration::MAX, Duration::ZERO), |(min, max), duration| (min.min(duration), max.max(duration)));
+    let range = max.saturating_sub(min).as_secs_f64();
+
+    records
+        .iter()
+        .map(|record| if range > 0.0 {
+            let level = (record.duration - min).as_secs_f64() / range;
+            BARS[((level * (BARS.len() - 1) as f64).round() as usize).min(BARS.len() - 1)]
+        } else {
+            BARS[0]
+        })
+        .collect()
+}
+
+/// Collects the timings of a day's `run`, `part_one` and `part_two` spans,
+/// appending them to the history once finished and reporting any regressions
+pub struct PerfRecorder {
+    day: u8,
+    commit: String,
+    machine: String,
+    history: PathBuf,
+    threshold: f64,
+    baseline_commit: Option<String>,
+    records: Vec<PerfRecord>,
+}
+
+impl PerfRecorder {
+    /// Flags timings slower than their baseline by more than `threshold` (e.g. 0.1 for 10%),
+    /// with the baseline taken from the runs at `baseline_commit` if provided
+    #[must_use]
+    pub fn new<P: Into<PathBuf>>(day: u8, history: P, threshold: f64, baseline_commit: Option<String>) -> Self {
+        Self {
+            day,
+            commit: current_commit(),
+            machine: machine_name(),
+            history: history.into(),
+            threshold,
+            baseline_commit,
+            records: Vec::new(),
+        }
+    }
+}
+
+impl Subscriber for PerfRecorder {
+    fn on_close(&mut self, record: SpanRecord) {
+        if matches!(record.path[..], ["run"] | ["run", "part_one" | "part_two"]) {
+            self.records.push(PerfRecord {
+                timestamp: unix_timestamp(),
+                commit: self.commit.clone(),
+                machine: self.machine.clone(),
+                day: self.day,
+                phase: record.name().to_string(),
+                duration: record.duration,
+            });
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let mut history = PerfHistory::load(&self.history)?;
+
+        for record in &self.records {
+            if let Some(regression) = history.check(record, self.threshold, self.baseline_commit.as_deref()) {
+                eprintln!("{regression}");
+            }
+        }
+        history.append(&self.records)?;
+        // on stderr, as the day's stdout may be its answers in JSON
+        eprintln!("Timings recorded in {}", self.history.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+//! An interactive prompt for poking at a day's parsed input
+
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+use crate::Parse;
+
+/// A command understood by a day's REPL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplCommand {
+    pub name: &'static str,
+    /// The arguments it takes, e.g. `<row> <col>`
+    pub args: &'static str,
+    pub about: &'static str,
+}
+
+impl ReplCommand {
+    #[must_use]
+    pub const fn new(name: &'static str, args: &'static str, about: &'static str) -> Self {
+        Self { name, args, about }
+    }
+}
+
+/// The commands every REPL has
+const BUILTIN_COMMANDS: [ReplCommand; 3] = [
+    ReplCommand::new("help", "", "List the commands"),
+    ReplCommand::new("reload", "", "Read the input again and reparse it"),
+    ReplCommand::new("quit", "", "Leave the REPL (also `exit` or Ctrl+D)"),
+];
+
+/// The commands of [`grid_command`], for days whose model is a grid
+pub const GRID_COMMANDS: [ReplCommand; 4] = [
+    ReplCommand::new("size", "", "Print the grid's dimensions"),
+    ReplCommand::new("cell", "<row> <col>", "Print the tile at a cell"),
+    ReplCommand::new("find", "<tile>", "List the cells holding a tile"),
+    ReplCommand::new("show", "[row col height width]", "Print the grid, or a window of it"),
+];
+
+/// A day's commands over its [parsed input](Parse::Parsed)
+pub trait Explore: Parse {
+    /// The commands handled by [`Self::execute`]
+    fn commands(&self) -> Vec<ReplCommand>;
+
+    /// Runs one of the day's commands against the model, returning what to print
+    ///
+    /// # Errors
+    ///
+    /// If the command is unknown or its arguments are invalid
+    fn execute(&self, model: &mut Self::Parsed<'_>, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// Parses the argument at `index`, describing it by `name` in errors
+///
+/// # Errors
+///
+/// If the argument is missing or invalid
+pub fn parse_arg<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<T, String> {
+    let arg = args
+        .get(index)
+        .ok_or_else(|| format!("Missing {name}"))?;
+    arg.parse()
+        .map_err(|_| format!("Invalid {name} `{arg}`"))
+}
+
+/// Handles the [`GRID_COMMANDS`], returning `None` for any other command
+#[must_use]
+pub fn grid_command<G: AsRef<[u8]>>(grid: &[G], command: &str, args: &[&str]) -> Option<Result<String, String>> {
+    let n_rows = grid.len();
+    let n_cols = grid
+        .first()
+        .map_or(0, |row| row.as_ref().len());
+    let tile = |row: usize, col: usize| grid
+        .get(row)
+        .and_then(|line| line.as_ref().get(col))
+        .map(|&tile| tile as char)
+        .ok_or_else(|| format!("({row}, {col}) is outside the {n_rows}x{n_cols} grid"));
+
+    Some(match command {
+        "size" => Ok(format!("{n_rows} rows, {n_cols} columns")),
+        "cell" => parse_arg::<usize>(args, 0, "row")
+            .and_then(|row| Ok((row, parse_arg::<usize>(args, 1, "column")?)))
+            .and_then(|(row, col)| tile(row, col).map(|tile| format!("({row}, {col}) = {tile}"))),
+        "find" => parse_arg::<char>(args, 0, "tile")
+            .map(|target| {
+                let cells = grid
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(row, line)| line
+                        .as_ref()
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &tile)| tile as char == target)
+                        .map(move |(col, _)| (row, col))
+                    )
+                    .collect::<Vec<(usize, usize)>>();
+                let shown = cells
+                    .iter()
+                    .take(20)
+                    .map(|(row, col)| format!("({row}, {col})"))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                let more = cells.len().saturating_sub(20);
+                format!(
+                    "{} cell{}: {shown}{}",
+                    cells.len(),
+                    if cells.len() == 1 { "" } else { "s" },
+                    if more > 0 { format!(" and {more} more") } else { String::new() },
+                )
+            }),
+        "show" => {
+            let window = if args.is_empty() {
+                Ok((0, 0, n_rows, n_cols))
+            } else {
+                (0..4)
+                    .map(|i| parse_arg::<usize>(args, i, ["row", "column", "height", "width"][i]))
+                    .collect::<Result<Vec<usize>, String>>()
+                    .map(|window| (window[0], window[1], window[2], window[3]))
+            };
+            window.map(|(row, col, height, width)| {
+                let mut shown = String::new();
+                for line in grid
+                    .iter()
+                    .skip(row)
+                    .take(height)
+                {
+                    let line = line.as_ref();
+                    let _ = writeln!(
+                        shown,
+                        "{}",
+                        String::from_utf8_lossy(&line[col.min(line.len())..(col + width).min(line.len())]),
+                    );
+                }
+                shown.trim_end().to_string()
+            })
+        },
+        _ => return None,
+    })
+}
+
+fn help(commands: &[ReplCommand]) -> String {
+    commands
+        .iter()
+        .chain(&BUILTIN_COMMANDS)
+        .map(|command| format!(
+            "  {:<36} {}\n",
+            format!("{} {}", command.name, command.args).trim_end(),
+            command.about,
+        ))
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+/// Reads commands from stdin and runs them against the model of the input returned by `read_input`,
+/// until `quit` or the end of stdin
+///
+/// # Errors
+///
+/// If failed to read from stdin or write to stdout
+pub fn run_repl<D, F>(day: u8, cls: &D, mut read_input: F) -> io::Result<()>
+where
+    D: Explore,
+    F: FnMut() -> String,
+{
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let commands = cls.commands();
+    println!("Day {day} REPL, type `help` for the commands");
+
+    // the model borrows the input, so reloading swaps the input out once its model is dropped
+    'reload: loop {
+        let input = read_input();
+        let mut model = match cls.parse_input(&input) {
+            Ok(model) => Some(model),
+            Err(err) => {
+                println!("error: failed to parse the input: {err}");
+                None
+            },
+        };
+
+        loop {
+            print!("day{day}> ");
+            io::stdout().flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                println!();
+                return Ok(());
+            };
+            let mut words = line.split_whitespace();
+            let Some(command) = words.next() else {
+                continue;
+            };
+            let args = words.collect::<Vec<&str>>();
+
+            match (command, model.as_mut()) {
+                ("quit" | "exit", _) => return Ok(()),
+                ("help", _) => println!("{}", help(&commands)),
+                ("reload", _) => continue 'reload,
+                (_, None) => println!("error: the input failed to parse, fix it and `reload`"),
+                (command, Some(model)) if commands.iter().any(|known| known.name == command) => {
+                    match cls.execute(model, command, &args) {
+                        Ok(output) if output.is_empty() => {},
+                        Ok(output) => println!("{output}"),
+                        Err(err) => println!("error: {err}"),
+                    }
+                },
+                (command, _) => println!("error: unknown command `{command}`, type `help` for the commands"),
+            }
+        }
+    }
+}
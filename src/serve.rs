@@ -0,0 +1,427 @@
+//! A minimal HTTP service for solving puzzles from other tools, bound to localhost only
+//!
+//! - `GET /days` lists the solved days with their names
+//! - `POST /solve/{day}/{part}` solves a part with the request body as the input
+
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+use serde::Serialize;
+use crate::Part;
+
+/// Inputs larger than this are refused
+pub const MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+
+/// How long a client has to send its request before the connection is dropped
+pub const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A part's answer along with how long solving it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: u64,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The day has no solution
+    NotFound(u8),
+    /// The solver failed on the input, such as panicking on a malformed one
+    Failed(String),
+    /// The solver could not be run at all
+    Unavailable(String),
+    /// The solver took longer than it's given
+    TimedOut(Duration),
+}
+
+impl SolveError {
+    const fn status(&self) -> u16 {
+        match self {
+            Self::NotFound(_) => 404,
+            Self::Failed(_) => 422,
+            Self::Unavailable(_) => 503,
+            Self::TimedOut(_) => 504,
+        }
+    }
+
+    const fn kind(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) => "not_found",
+            Self::Failed(_) => "solver_failed",
+            Self::Unavailable(_) => "unavailable",
+            Self::TimedOut(_) => "timeout",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::NotFound(day) => format!("Solution does not exist yet for day {day}"),
+            Self::Failed(message) | Self::Unavailable(message) => message.clone(),
+            Self::TimedOut(limit) => format!("The solver took over {}s", limit.as_secs()),
+        }
+    }
+}
+
+/// Where the service gets its answers from
+pub trait Solve {
+    /// The solved days with their puzzle names, in order
+    fn days(&self) -> Vec<(u8, String)>;
+
+    /// # Errors
+    ///
+    /// If the day has no solution or failed to solve the input
+    fn solve(&self, day: u8, part: Part, input: &str) -> Result<Solved, SolveError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+impl Request {
+    /// Reads a request's head and body, as sized by its `Content-Length`
+    ///
+    /// # Errors
+    ///
+    /// If the request is malformed, too large or failed to be read
+    pub fn read_from<R: Read>(reader: R) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+        let mut reader = BufReader::new(reader);
+
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let mut words = line.split_whitespace();
+        let (Some(method), Some(path)) = (words.next(), words.next()) else {
+            return Err(invalid("Malformed request line"));
+        };
+        let (method, path) = (method.to_string(), path.to_string());
+
+        let mut content_length = 0;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| invalid("Invalid Content-Length"))?;
+                }
+            }
+        }
+        if content_length > MAX_BODY_SIZE {
+            return Err(io::Error::new(io::ErrorKind::FileTooLarge, "Request body is too large"));
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+        let body = String::from_utf8(body)
+            .map_err(|_| invalid("Request body is not UTF-8"))?;
+
+        Ok(Self { method, path, body })
+    }
+}
+
+#[derive(Serialize)]
+struct DayBody {
+    day: u8,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct DaysBody {
+    days: Vec<DayBody>,
+}
+
+#[derive(Serialize)]
+struct SolvedBody {
+    day: u8,
+    part: u8,
+    answer: u64,
+    time_ns: u64,
+}
+
+#[derive(Serialize)]
+struct ErrorDetail<'a> {
+    kind: &'a str,
+    message: &'a str,
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: ErrorDetail<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// Always JSON
+    pub body: String,
+}
+
+impl Response {
+    fn json<T: Serialize>(status: u16, body: &T) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string(body).expect("Responses always serialize"),
+        }
+    }
+
+    /// An error as `{"error":{"kind":...,"message":...}}`
+    #[must_use]
+    pub fn error(status: u16, kind: &str, message: &str) -> Self {
+        Self::json(status, &ErrorBody {
+            error: ErrorDetail { kind, message },
+        })
+    }
+
+    const fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            408 => "Request Timeout",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// # Errors
+    ///
+    /// If failed to write the response
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body,
+        )?;
+        writer.flush()
+    }
+}
+
+/// Routes a request to the listing or the solver
+pub fn handle<S: Solve + ?Sized>(solver: &S, request: &Request) -> Response {
+    let segments = request.path
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<&str>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", [] | ["days"]) => {
+            let days = solver
+                .days()
+                .into_iter()
+                .map(|(day, name)| DayBody { day, name })
+                .collect();
+            Response::json(200, &DaysBody { days })
+        },
+        ("POST", ["solve", day, part]) => {
+            let Some(day) = day
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+            else {
+                return Response::error(400, "invalid_day", &format!("Invalid day `{day}`, expected a number from 1 to 25"));
+            };
+            let part = match part.parse::<Part>() {
+                Ok(part) => part,
+                Err(message) => return Response::error(400, "invalid_part", &message),
+            };
+            if request.body.trim().is_empty() {
+                return Response::error(400, "empty_input", "The request body must hold the puzzle input");
+            }
+
+            match solver.solve(day, part, &request.body) {
+                Ok(solved) => Response::json(200, &SolvedBody {
+                    day,
+                    part: part.number(),
+                    answer: solved.answer,
+                    time_ns: u64::try_from(solved.time.as_nanos()).unwrap_or(u64::MAX),
+                }),
+                Err(err) => Response::error(err.status(), err.kind(), &err.message()),
+            }
+        },
+        (_, [] | ["days"] | ["solve", _, _]) => Response::error(
+            405,
+            "method_not_allowed",
+            &format!("{} is not allowed on {}", request.method, request.path),
+        ),
+        _ => Response::error(404, "not_found", &format!("No route for {}", request.path)),
+    }
+}
+
+fn serve_connection<S: Solve + ?Sized>(solver: &S, mut stream: TcpStream) -> io::Result<()> {
+    // a client that never finishes its request would otherwise hold its thread forever
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    stream.set_write_timeout(Some(READ_TIMEOUT))?;
+    let response = match Request::read_from(&mut stream) {
+        Ok(request) => handle(solver, &request),
+        Err(err) if err.kind() == io::ErrorKind::FileTooLarge => Response::error(413, "too_large", &err.to_string()),
+        Err(err) if matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => {
+            Response::error(408, "timeout", "The request took too long to send")
+        },
+        Err(err) => Response::error(400, "bad_request", &err.to_string()),
+    };
+    response.write_to(&mut stream)
+}
+
+pub struct Server<S> {
+    listener: TcpListener,
+    solver: Arc<S>,
+}
+
+impl<S: Solve + Send + Sync + 'static> Server<S> {
+    /// # Errors
+    ///
+    /// If the address is not a loopback one, as the service is meant for local tools only,
+    /// or failed to bind to it
+    pub fn bind<A: ToSocketAddrs>(addr: A, solver: S) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        if !listener.local_addr()?.ip().is_loopback() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The solver service only listens on localhost",
+            ));
+        }
+        Ok(Self {
+            listener,
+            solver: Arc::new(solver),
+        })
+    }
+
+    /// # Errors
+    ///
+    /// If failed to get the address the server is bound to
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Handles connections until the process exits, each on its own thread
+    ///
+    /// # Errors
+    ///
+    /// If failed to accept a connection
+    pub fn run(self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            let solver = Arc::clone(&self.solver);
+            thread::spawn(move || serve_connection(&*solver, stream));
+        }
+        Ok(())
+    }
+
+    /// Runs the server on a background thread, such as for an in-process client,
+    /// returning the address it listens on
+    ///
+    /// # Errors
+    ///
+    /// If failed to get the address the server is bound to
+    pub fn spawn(self) -> io::Result<SocketAddr> {
+        let addr = self.local_addr()?;
+        thread::spawn(move || self.run());
+        Ok(addr)
+    }
+}
+
+/// Sends a request to the service, returning the response's status and body
+///
+/// # Errors
+///
+/// If failed to connect or the response is malformed
+pub fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> io::Result<(u16, String)> {
+    let mut stream = TcpStream::connect(addr)?;
+    let mut head = String::new();
+    let _ = write!(
+        head,
+        "{method} {path} HTTP/1.1\r\nHost: {addr}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len(),
+    );
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Malformed response");
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(invalid)?;
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(invalid)?;
+
+    Ok((status, body.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers with the number of lines in the input, failing on day 2
+    struct LineCounter;
+
+    impl Solve for LineCounter {
+        fn days(&self) -> Vec<(u8, String)> {
+            vec![(1, "Line \"Counting\"".to_string()), (2, "Failing".to_string())]
+        }
+
+        fn solve(&self, day: u8, part: Part, input: &str) -> Result<Solved, SolveError> {
+            match day {
+                1 => Ok(Solved {
+                    answer: input.lines().count() as u64 * u64::from(part.number()),
+                    time: Duration::from_micros(5),
+                }),
+                2 => Err(SolveError::Failed("malformed input".to_string())),
+                day => Err(SolveError::NotFound(day)),
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let addr = Server::bind("127.0.0.1:0", LineCounter)
+            .and_then(Server::spawn)
+            .unwrap();
+
+        assert_eq!(
+            request(addr, "GET", "/days", "").unwrap(),
+            (200, r#"{"days":[{"day":1,"name":"Line \"Counting\""},{"day":2,"name":"Failing"}]}"#.to_string()),
+        );
+        assert_eq!(
+            request(addr, "POST", "/solve/1/2", "a\nb\nc\n").unwrap(),
+            (200, r#"{"day":1,"part":2,"answer":6,"time_ns":5000}"#.to_string()),
+        );
+        assert_eq!(
+            request(addr, "POST", "/solve/2/1", "a").unwrap(),
+            (422, r#"{"error":{"kind":"solver_failed","message":"malformed input"}}"#.to_string()),
+        );
+        assert_eq!(request(addr, "POST", "/solve/3/1", "a").unwrap().0, 404);
+        assert_eq!(request(addr, "POST", "/solve/26/1", "a").unwrap().0, 400);
+        assert_eq!(request(addr, "POST", "/solve/1/3", "a").unwrap().0, 400);
+        assert_eq!(request(addr, "GET", "/solve/1/1", "").unwrap().0, 405);
+        assert_eq!(request(addr, "GET", "/nowhere", "").unwrap().0, 404);
+
+        assert!(Server::bind("0.0.0.0:0", LineCounter).is_err());
+    }
+}
@@ -1,17 +1,68 @@
 use std::fmt::Display;
+use serde::Serialize;
+use crate::{
+    config::{ConfigError, Params},
+    dump::Intermediate,
+};
 
+/// A day's solution
+///
+/// The answers are `u64` rather than `usize` so they fit on 32-bit targets such as `wasm32`
 pub trait Solution {
     const NAME: &'static str;
 
-    fn part_one<T: Display>(&self, inp: T) -> usize;
+    fn part_one<T: Display>(&self, inp: T) -> u64;
 
-    fn part_two<T: Display>(&self, inp: T) -> usize;
+    fn part_two<T: Display>(&self, inp: T) -> u64;
 
+    /// Solves both parts and checks the answers against the puzzle input's,
+    /// with the solution's own parameters, so the default solution is checked whatever `aoc.toml` sets
     fn run(&self, inp: String);
 
+    /// The solution with the day's puzzle parameters, such as Day 21's steps, set from `aoc.toml`,
+    /// or `None` if they are the same as its own, which days without any parameters always are
+    ///
+    /// # Errors
+    ///
+    /// If a parameter is unknown to the day or has the wrong type
+    fn configure(&self, params: &Params) -> Result<Option<Self>, ConfigError>
+    where
+        Self: Sized,
+    {
+        params
+            .expect_only(&[])
+            .map(|()| None)
+    }
+
     #[inline]
     #[must_use]
     fn name(&self) -> &'static str {
         Self::NAME
     }
-}
\ No newline at end of file
+}
+
+/// A day's model of its input, which its parts start with,
+/// so that it can be benchmarked, fuzzed, explored in the REPL and dumped as JSON apart from solving
+///
+/// The parts panic on a malformed input, as their answers have no room for an error,
+/// whereas parsing returns it, such as a [`ParseError`](crate::ParseError) pointing at where it is.
+/// Days that solve straight from the raw input or read it differently in each part,
+/// such as Days 1, 6 and 15, parse it as far as their parts have in common
+pub trait Parse {
+    /// The parsed input, which may borrow from it
+    type Parsed<'a>: Serialize;
+
+    /// Why the input is malformed
+    type Error: Display;
+
+    /// # Errors
+    ///
+    /// If the input is malformed
+    fn parse_input<'a>(&self, inp: &'a str) -> Result<Self::Parsed<'a>, Self::Error>;
+
+    /// What the day works out from the parsed input on the way to the answers, see [`Stage::Intermediate`](crate::Stage::Intermediate),
+    /// or `None` if it has nothing in between
+    fn intermediate(&self, _parsed: Self::Parsed<'_>) -> Option<Intermediate> {
+        None
+    }
+}
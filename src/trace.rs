@@ -0,0 +1,355 @@
+//! Lightweight span instrumentation with pluggable subscribers
+//!
+//! Spans are opened with [`span`] (or [`in_span`]) and closed when their guard is dropped.
+//! Nothing is recorded unless a [`Subscriber`] was added on the current thread,
+//! so instrumented code stays cheap when tracing is off.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Write as _,
+    io,
+    time::{Duration, Instant},
+};
+#[cfg(feature = "fs")]
+use std::{fs, path::PathBuf};
+use crate::memory::{self, AllocSnapshot, AllocStats};
+
+/// A span that has been closed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanRecord {
+    /// The names of the enclosing spans, outermost first, ending with this span's name
+    pub path: Vec<&'static str>,
+    /// When the span was opened, relative to when the subscriber was installed
+    pub start: Duration,
+    pub duration: Duration,
+    /// The span's heap usage, if the counting allocator is installed
+    pub memory: Option<AllocStats>,
+}
+
+impl SpanRecord {
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.path
+            .last()
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+pub trait Subscriber {
+    /// Called whenever a span is closed,
+    /// so nested spans are seen before the spans enclosing them
+    fn on_close(&mut self, record: SpanRecord);
+
+    /// Called once tracing is done, to report what was collected
+    ///
+    /// # Errors
+    ///
+    /// If failed to write the report
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+struct Tracer {
+    subscribers: Vec<Box<dyn Subscriber>>,
+    origin: Instant,
+    open: Vec<&'static str>,
+}
+
+thread_local! {
+    static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+}
+
+/// Adds a subscriber for spans on the current thread,
+/// alongside any already added
+pub fn add_subscriber<S: Subscriber + 'static>(subscriber: S) {
+    TRACER.with_borrow_mut(|tracer| tracer
+        .get_or_insert_with(|| Tracer {
+            subscribers: Vec::new(),
+            origin: Instant::now(),
+            open: Vec::new(),
+        })
+        .subscribers
+        .push(Box::new(subscriber))
+    );
+}
+
+/// Removes the current thread's subscribers and lets each report what it collected
+///
+/// # Errors
+///
+/// If a subscriber failed to write its report, after every subscriber has been finished
+pub fn finish_subscribers() -> io::Result<()> {
+    TRACER
+        .with_borrow_mut(Option::take)
+        .map_or(Ok(()), |tracer| tracer.subscribers
+            .into_iter()
+            .map(|mut subscriber| subscriber.finish())
+            .fold(Ok(()), Result::and)
+        )
+}
+
+#[inline]
+#[must_use]
+pub fn is_tracing() -> bool {
+    TRACER.with_borrow(Option::is_some)
+}
+
+/// Closes its span when dropped
+#[must_use = "the span is closed as soon as its guard is dropped"]
+pub struct SpanGuard {
+    start: Option<Instant>,
+    /// The allocator's counters when the span was opened, and the peak before it
+    memory: Option<(AllocSnapshot, usize)>,
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let duration = start.elapsed();
+        let memory = self.memory.map(|(snapshot, outer_peak)| {
+            let stats = AllocStats::between(&snapshot, &AllocSnapshot::now());
+            memory::restore_peak(outer_peak);
+            stats
+        });
+
+        TRACER.with_borrow_mut(|tracer| if let Some(tracer) = tracer {
+            let record = SpanRecord {
+                path: tracer.open.clone(),
+                start: start.saturating_duration_since(tracer.origin),
+                duration,
+                memory,
+            };
+            tracer.open.pop();
+            for subscriber in &mut tracer.subscribers {
+                subscriber.on_close(record.clone());
+            }
+        });
+    }
+}
+
+/// Opens a span lasting until the returned guard is dropped
+///
+/// ```ignore
+/// let _span = span("parse");
+/// ```
+#[inline]
+pub fn span(name: &'static str) -> SpanGuard {
+    let start = TRACER.with_borrow_mut(|tracer| tracer
+        .as_mut()
+        .map(|tracer| {
+            tracer.open.push(name);
+            Instant::now()
+        })
+    );
+    let memory = (start.is_some() && memory::is_counting())
+        .then(|| {
+            let outer_peak = memory::reset_peak();
+            (AllocSnapshot::now(), outer_peak)
+        });
+    SpanGuard { start, memory }
+}
+
+/// Runs the closure inside a span
+#[inline]
+pub fn in_span<R, F>(name: &'static str, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let _span = span(name);
+    f()
+}
+
+/// Totals the time spent in each distinct stack of spans,
+/// printing them as an indented tree (or folded stacks) once finished
+#[derive(Debug, Default)]
+pub struct FlameSummary {
+    /// When each span path was first opened, its total time and number of calls
+    totals: HashMap<Vec<&'static str>, (Duration, Duration, usize)>,
+    folded: bool,
+    stderr: bool,
+}
+
+impl FlameSummary {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prints folded stacks once finished instead, see [`Self::folded_stacks`]
+    #[must_use]
+    pub fn folded() -> Self {
+        Self {
+            folded: true,
+            ..Self::default()
+        }
+    }
+
+    /// Prints to stderr instead of stdout, to keep it clear for the answers in JSON
+    #[must_use]
+    pub fn on_stderr(self) -> Self {
+        Self {
+            stderr: true,
+            ..self
+        }
+    }
+
+    /// Span paths in the order they were first opened,
+    /// which places every span right after the one enclosing it
+    fn ordered(&self) -> Vec<(&Vec<&'static str>, Duration, usize)> {
+        let mut ordered = self.totals
+            .iter()
+            .map(|(path, &(first, total, calls))| (first, path, total, calls))
+            .collect::<Vec<_>>();
+        ordered.sort();
+
+        ordered
+            .into_iter()
+            .map(|(_, path, total, calls)| (path, total, calls))
+            .collect()
+    }
+
+    /// Formats the summary as one line per span path,
+    /// with each span's share of its outermost span's time
+    #[must_use]
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+
+        for (path, total, calls) in self.ordered() {
+            // a root span still open when the summary is taken has no total of its own yet
+            let root = self.totals
+                .get(&path[..1])
+                .map_or(total, |&(_, root, _)| root);
+            let depth = path.len() - 1;
+            let _ = writeln!(
+                report,
+                "{:indent$}{:<width$} {total:>12.3?} {:>6.1}% {calls:>8} call{}",
+                "",
+                path[depth],
+                100.0 * total.as_secs_f64() / root.as_secs_f64().max(f64::MIN_POSITIVE),
+                if calls == 1 { "" } else { "s" },
+                indent = 2 * depth,
+                width = 32usize.saturating_sub(2 * depth),
+            );
+        }
+        report
+    }
+
+    /// Formats the summary as folded stacks, e.g. `part_one;parse 1234` (in microseconds),
+    /// as taken by flame graph tools
+    #[must_use]
+    pub fn folded_stacks(&self) -> String {
+        self.ordered()
+            .into_iter()
+            .map(|(path, total, _)| {
+                // flame graphs expect the time spent in this span alone
+                let children = self.totals
+                    .iter()
+                    .filter(|(other, _)| other.len() == path.len() + 1 && other.starts_with(path))
+                    .map(|(_, &(_, child, _))| child)
+                    .sum::<Duration>();
+                format!("{} {}\n", path.join(";"), total.saturating_sub(children).as_micros())
+            })
+            .collect()
+    }
+}
+
+impl Subscriber for FlameSummary {
+    fn on_close(&mut self, record: SpanRecord) {
+        let (first, total, calls) = self.totals
+            .entry(record.path)
+            .or_insert((record.start, Duration::ZERO, 0));
+        *first = (*first).min(record.start);
+        *total += record.duration;
+        *calls += 1;
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let summary = if self.folded {
+            self.folded_stacks()
+        } else {
+            self.report()
+        };
+        if self.stderr {
+            eprint!("{summary}");
+        } else {
+            print!("{summary}");
+        }
+        Ok(())
+    }
+}
+
+/// Writes every span as a complete event in the Chrome trace event format,
+/// which can be opened in `chrome://tracing` or Perfetto
+#[cfg(feature = "fs")]
+#[derive(Debug)]
+pub struct ChromeTrace {
+    path: PathBuf,
+    events: Vec<SpanRecord>,
+}
+
+#[cfg(feature = "fs")]
+impl ChromeTrace {
+    #[must_use]
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            events: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let trace = TraceFile {
+            trace_events: self.events
+                .iter()
+                .map(|record| TraceEvent {
+                    name: record.name(),
+                    cat: record.path.join(";"),
+                    ph: "X",
+                    ts: record.start.as_secs_f64() * 1e6,
+                    dur: record.duration.as_secs_f64() * 1e6,
+                    pid: 1,
+                    tid: 1,
+                })
+                .collect(),
+        };
+        serde_json::to_string(&trace).expect("Traces always serialize") + "\n"
+    }
+}
+
+/// A complete event, in microseconds
+#[cfg(feature = "fs")]
+#[derive(serde::Serialize)]
+struct TraceEvent {
+    name: &'static str,
+    cat: String,
+    ph: &'static str,
+    ts: f64,
+    dur: f64,
+    pid: u32,
+    tid: u32,
+}
+
+#[cfg(feature = "fs")]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceFile {
+    trace_events: Vec<TraceEvent>,
+}
+
+#[cfg(feature = "fs")]
+impl Subscriber for ChromeTrace {
+    fn on_close(&mut self, record: SpanRecord) {
+        self.events.push(record);
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        fs::write(&self.path, self.to_json())?;
+        eprintln!("Trace written to {}", self.path.display());
+        Ok(())
+    }
+}
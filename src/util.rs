@@ -1,16 +1,27 @@
+pub use number::*;
+pub use rational::*;
+pub use bigint::*;
+pub use polynomial::*;
+pub use geometry::*;
+pub use grid::*;
+pub use memo::*;
+pub use parse::*;
+pub use linalg::*;
+pub use graph::*;
+pub use cycle::*;
+pub use bitset::*;
+pub use iter::*;
 
-#[must_use]
-pub fn gcd(a: usize, b: usize) -> usize {
-    if b == 0 { a } else { gcd(b, a % b) }
-}
-
-#[must_use]
-pub fn lcm<I>(nums: I) -> usize
-where
-    I: Iterator<Item = usize>,
-{
-    nums.fold(
-        1,
-        |num, ans| num * ans / gcd(num, ans),
-    )
-}
\ No newline at end of file
+pub mod number;
+pub mod rational;
+pub mod bigint;
+pub mod polynomial;
+pub mod geometry;
+pub mod grid;
+pub mod memo;
+pub mod parse;
+pub mod linalg;
+pub mod graph;
+pub mod cycle;
+pub mod bitset;
+pub mod iter;
@@ -0,0 +1,417 @@
+//! A minimal arbitrary-precision signed integer,
+//! just enough for exact arithmetic on values that outgrow `i128`
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Stored as a sign and little-endian `u32` limbs with no trailing zero limbs,
+/// so that zero is always non-negative with no limbs
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+fn trim(mut magnitude: Vec<u32>) -> Vec<u32> {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+    magnitude
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+
+    for (i, &limb) in long
+        .iter()
+        .enumerate()
+    {
+        let sum = u64::from(limb) + u64::from(short.get(i).copied().unwrap_or(0)) + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// `a` must not be smaller than `b`
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+
+    for (i, &limb) in a
+        .iter()
+        .enumerate()
+    {
+        let (diff, overflow_1) = limb.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (diff, overflow_2) = diff.overflowing_sub(borrow);
+        result.push(diff);
+        borrow = u32::from(overflow_1 || overflow_2);
+    }
+    trim(result)
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u32; a.len() + b.len()];
+
+    for (i, &x) in a
+        .iter()
+        .enumerate()
+    {
+        let mut carry = 0;
+        for (j, &y) in b
+            .iter()
+            .enumerate()
+        {
+            let product = u64::from(x) * u64::from(y) + u64::from(result[i + j]) + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    trim(result)
+}
+
+/// Bitwise long division, `b` must be non-zero
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = Vec::new();
+
+    for bit in (0..a.len() * 32).rev() {
+        // remainder = remainder << 1 | next bit of a
+        let mut carry = (a[bit / 32] >> (bit % 32)) & 1;
+        for limb in &mut remainder {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+        if carry > 0 {
+            remainder.push(carry);
+        }
+
+        if cmp_magnitude(&remainder, b) != Ordering::Less {
+            remainder = sub_magnitude(&remainder, b);
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (trim(quotient), remainder)
+}
+
+impl BigInt {
+    #[must_use]
+    fn from_parts(negative: bool, magnitude: Vec<u32>) -> Self {
+        let magnitude = trim(magnitude);
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn zero() -> Self {
+        Self { negative: false, magnitude: Vec::new() }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+
+    /// Truncating division, returning the quotient and remainder
+    /// (where the remainder takes the sign of `self`)
+    ///
+    /// # Panics
+    ///
+    /// If dividing by zero
+    #[must_use]
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "BigInt division by zero");
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        (
+            Self::from_parts(self.negative != other.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        )
+    }
+
+    /// The non-negative greatest common divisor
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.is_zero() {
+            let (_, remainder) = a.div_rem(&b);
+            (a, b) = (b, remainder);
+        }
+        a
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for BigInt {
+            #[allow(unused_comparisons)]
+            fn from(num: $int) -> Self {
+                let mut magnitude = Vec::new();
+                let mut rest = i128::from(num).unsigned_abs();
+                while rest > 0 {
+                    magnitude.push(rest as u32);
+                    rest >>= 32;
+                }
+                Self::from_parts(num < 0, magnitude)
+            }
+        }
+    )*};
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl TryFrom<&BigInt> for i128 {
+    type Error = ();
+
+    fn try_from(num: &BigInt) -> Result<Self, Self::Error> {
+        if num.magnitude.len() > 4 {
+            return Err(());
+        }
+        let magnitude = num.magnitude
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| (acc << 32) | u128::from(limb));
+
+        if num.negative {
+            0i128.checked_sub_unsigned(magnitude).ok_or(())
+        } else {
+            Self::try_from(magnitude).map_err(|_| ())
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+        // peel off 9 decimal digits at a time
+        let billion = [1_000_000_000];
+        let mut chunks = Vec::new();
+        let mut rest = self.magnitude.clone();
+        while !rest.is_empty() {
+            let (quotient, remainder) = div_rem_magnitude(&rest, &billion);
+            chunks.push(remainder.first().copied().unwrap_or(0));
+            rest = quotient;
+        }
+
+        let mut digits = String::new();
+        for (i, chunk) in chunks
+            .iter()
+            .rev()
+            .enumerate()
+        {
+            if i == 0 {
+                digits += &chunk.to_string();
+            } else {
+                digits += &format!("{chunk:09}");
+            }
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: Self) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(self.negative, add_magnitude(&self.magnitude, &other.magnitude));
+        }
+        match cmp_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                other.negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: Self) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: Self) -> BigInt {
+        BigInt::from_parts(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+}
+
+macro_rules! impl_owned_op {
+    ($($op:ident :: $method:ident),*) => {$(
+        impl $op for BigInt {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                $op::$method(&self, &other)
+            }
+        }
+    )*};
+}
+
+impl_owned_op!(Add::add, Sub::sub, Mul::mul);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(num: i128) -> BigInt {
+        BigInt::from(num)
+    }
+
+    /// `2^bits`
+    fn power_of_two(bits: u32) -> BigInt {
+        (0..bits).fold(big(1), |acc, _| &acc + &acc)
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // truncating like `i128`, with the remainder taking the sign of the dividend
+        for (a, b) in [(7, 2), (-7, 2), (7, -2), (-7, -2), (6, -3), (-1, 5), (0, -5), (i128::MAX, -3), (i128::MIN + 1, 7)] {
+            assert_eq!(big(a).div_rem(&big(b)), (big(a / b), big(a % b)), "{a} / {b}");
+        }
+        let (quotient, remainder) = big(-6).div_rem(&big(3));
+        assert!(!remainder.is_negative() && remainder.is_zero());
+        assert!(quotient.is_negative());
+
+        // across several limbs
+        let dividend = &(&power_of_two(100) * &big(-12_345)) + &big(-67);
+        assert_eq!(
+            dividend.div_rem(&power_of_two(100)),
+            (big(-12_345), big(-67)),
+        );
+        assert_eq!(big(12).gcd(&big(-18)), big(6));
+        assert_eq!(big(0).gcd(&big(-5)), big(5));
+    }
+
+    #[test]
+    #[should_panic = "BigInt division by zero"]
+    fn test_div_by_zero() {
+        let _ = big(1).div_rem(&BigInt::zero());
+    }
+
+    #[test]
+    fn test_i128_conversion() {
+        for num in [0, 1, -1, i128::MAX, i128::MIN, i128::MIN + 1, i128::from(u64::MAX), -i128::from(u32::MAX)] {
+            assert_eq!(i128::try_from(&big(num)), Ok(num));
+            assert_eq!(big(num).to_string(), num.to_string());
+        }
+        assert_eq!(i128::try_from(&(&big(i128::MAX) + &big(1))), Err(()));
+        assert_eq!(i128::try_from(&(&big(i128::MIN) - &big(1))), Err(()));
+        assert_eq!(i128::try_from(&power_of_two(128)), Err(()));
+        assert_eq!(i128::try_from(&-power_of_two(127)), Ok(i128::MIN));
+    }
+
+    #[test]
+    fn test_carries() {
+        assert_eq!(&big(u32::MAX.into()) + &big(1), power_of_two(32));
+        assert_eq!(&power_of_two(32) - &big(1), big(u32::MAX.into()));
+
+        // every limb carries into the next, into a new limb
+        let all_ones = &power_of_two(128) - &big(1);
+        assert_eq!(all_ones.to_string(), u128::MAX.to_string());
+        assert_eq!(&all_ones + &big(1), power_of_two(128));
+        assert_eq!(&(&all_ones + &big(1)) - &all_ones, big(1));
+
+        let max = big(u64::MAX.into());
+        assert_eq!((&max * &max).to_string(), (u128::from(u64::MAX) * u128::from(u64::MAX)).to_string());
+        assert_eq!(&(&max * &max) - &(&power_of_two(128) - &power_of_two(65)), big(1));
+        assert_eq!(power_of_two(200).to_string(), "1606938044258990275541962092341162602522202993782792835301376");
+    }
+
+    #[test]
+    fn test_signs() {
+        assert_eq!(&big(5) - &big(8), big(-3));
+        assert_eq!(&big(-5) + &big(8), big(3));
+        assert_eq!(&big(-5) + &big(5), BigInt::zero());
+        assert!(!(&big(-5) + &big(5)).is_negative());
+        assert_eq!(&big(-4) * &big(0), BigInt::zero());
+        assert!(!(&big(-4) * &big(0)).is_negative());
+        assert_eq!(&big(-4) * &big(-3), big(12));
+        assert!(big(-3) < big(2));
+        assert!(big(-3) < big(-2));
+        assert!(-power_of_two(64) < big(i128::from(i64::MIN)));
+        assert_eq!(format!("{:>6}", big(-42)), "   -42");
+        assert_eq!(big(-1_000_000_000_000).to_string(), "-1000000000000");
+    }
+}
@@ -0,0 +1,195 @@
+//! Fixed-capacity dense bitsets, as a cheaper alternative to hashing visited states
+
+/// A set of indices in `0..capacity`, one bit each
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+}
+
+impl BitSet {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(64)],
+            capacity,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns whether the index was not already present
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds
+    #[inline]
+    pub fn insert(&mut self, index: usize) -> bool {
+        assert!(index < self.capacity, "BitSet index {index} out of bounds");
+        let (word, bit) = (index / 64, 1 << (index % 64));
+        let is_new = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        is_new
+    }
+
+    /// Returns whether the index was present
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> bool {
+        if index >= self.capacity {
+            return false;
+        }
+        let (word, bit) = (index / 64, 1 << (index % 64));
+        let was_present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        was_present
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, index: usize) -> bool {
+        index < self.capacity
+            && self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// The number of indices present
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words
+            .iter()
+            .all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Iterates over the present indices in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &word)| {
+                let mut rest = word;
+                std::iter::from_fn(move || {
+                    (rest != 0).then(|| {
+                        let bit = rest.trailing_zeros() as usize;
+                        rest &= rest - 1;
+                        i * 64 + bit
+                    })
+                })
+            })
+    }
+}
+
+/// A [`BitSet`] over `(cell, state)` pairs of a grid,
+/// such as a tile along with the direction it was entered from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridBitSet {
+    bits: BitSet,
+    n_rows: usize,
+    n_cols: usize,
+    n_states: usize,
+}
+
+impl GridBitSet {
+    /// Returns [`None`] if the number of `(cell, state)` pairs would overflow a `usize`
+    #[must_use]
+    pub fn new(n_rows: usize, n_cols: usize, n_states: usize) -> Option<Self> {
+        let capacity = n_rows
+            .checked_mul(n_cols)?
+            .checked_mul(n_states)?;
+        Some(Self {
+            bits: BitSet::new(capacity),
+            n_rows, n_cols, n_states,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    fn index(&self, (row, col): (usize, usize), state: usize) -> Option<usize> {
+        (row < self.n_rows && col < self.n_cols && state < self.n_states)
+            .then(|| (row * self.n_cols + col) * self.n_states + state)
+    }
+
+    /// Returns whether the pair was not already present
+    ///
+    /// # Panics
+    ///
+    /// If the cell or state is out of bounds
+    #[inline]
+    pub fn insert(&mut self, cell: (usize, usize), state: usize) -> bool {
+        let index = self.index(cell, state)
+            .unwrap_or_else(|| panic!("GridBitSet cell {cell:?} with state {state} out of bounds"));
+        self.bits.insert(index)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, cell: (usize, usize), state: usize) -> bool {
+        self.index(cell, state)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+
+    /// The number of `(cell, state)` pairs present
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// The distinct cells present with any state, in row-major order
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut prev = None;
+        self.bits
+            .iter()
+            .map(|index| index / self.n_states)
+            .filter(move |&cell| prev.replace(cell) != Some(cell))
+            .map(|cell| (cell / self.n_cols, cell % self.n_cols))
+    }
+
+    /// The number of distinct cells present with any state
+    #[must_use]
+    pub fn count_cells(&self) -> usize {
+        self.cells().count()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_bitset() {
+        assert_eq!(GridBitSet::new(usize::MAX / 2, 3, 1), None);
+
+        let mut bits = GridBitSet::new(3, 4, 2).unwrap();
+        assert!(bits.insert((2, 3), 1));
+        assert!(bits.insert((2, 3), 0));
+        assert!(!bits.insert((2, 3), 1));
+        assert!(bits.insert((0, 1), 1));
+        assert!(!bits.contains((3, 0), 0));
+        assert!(!bits.contains((0, 1), 2));
+        assert_eq!(bits.len(), 3);
+        assert_eq!(bits.cells().collect::<Vec<(usize, usize)>>(), [(0, 1), (2, 3)]);
+    }
+}
@@ -0,0 +1,142 @@
+//! Events of a deterministic walk through finitely many states,
+//! which must eventually repeat, after which its events do too
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+};
+use super::{crt, next_congruent, CrtError};
+
+/// The steps at which some event happens:
+/// `transient` steps happen once before the walk starts looping,
+/// `periodic` steps repeat every `period` steps from `offset` onwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub offset: u64,
+    pub period: u64,
+    pub transient: Vec<u64>,
+    pub periodic: Vec<u64>,
+}
+
+impl Cycle {
+    /// Walks from `start` until a state repeats,
+    /// where `step` takes each state to the next along with whether the event happens at it
+    ///
+    /// Returns [`None`] if no state repeats within `max_steps`
+    pub fn find<S, F>(start: S, max_steps: u64, mut step: F) -> Option<Self>
+    where
+        S: Hash + Eq + Clone,
+        F: FnMut(&S) -> (S, bool),
+    {
+        let mut seen = HashMap::new();
+        let mut events = Vec::new();
+        let mut state = start;
+
+        for i in 0..=max_steps {
+            if let Some(&offset) = seen.get(&state) {
+                let (transient, periodic) = events
+                    .into_iter()
+                    .partition(|&event| event < offset);
+                return Some(Self {
+                    offset,
+                    period: i - offset,
+                    transient,
+                    periodic,
+                });
+            }
+            let (next, is_event) = step(&state);
+            seen.insert(state, i);
+            if is_event {
+                events.push(i);
+            }
+            state = next;
+        }
+        None
+    }
+
+    #[must_use]
+    pub fn is_event(&self, step: u64) -> bool {
+        if step < self.offset {
+            self.transient.contains(&step)
+        } else {
+            self.periodic.contains(
+                &(self.offset + (step - self.offset) % self.period)
+            )
+        }
+    }
+
+    /// The first step at which every cycle's event happens at once,
+    /// or [`None`] if they never line up
+    ///
+    /// # Errors
+    ///
+    /// If the step overflows
+    pub fn first_common(cycles: &[Self]) -> Result<Option<u64>, CrtError> {
+        let max_offset = cycles
+            .iter()
+            .map(|cycle| cycle.offset)
+            .max()
+            .unwrap_or_default();
+
+        // the events may all line up before every walk is looping
+        if let Some(step) = (0..max_offset)
+            .find(|&step| cycles
+                .iter()
+                .all(|cycle| cycle.is_event(step))
+            )
+        {
+            return Ok(Some(step));
+        }
+
+        let mut solutions = vec![(0, 1)];
+        for cycle in cycles {
+            let mut next = Vec::new();
+            for &solution in &solutions {
+                for &event in &cycle.periodic {
+                    match crt([solution, (i128::from(event), i128::from(cycle.period))]) {
+                        Ok(combined) => next.push(combined),
+                        Err(CrtError::Inconsistent) => {},
+                        Err(err) => return Err(err),
+                    }
+                }
+            }
+            solutions = next;
+        }
+        solutions
+            .into_iter()
+            .map(|(residue, modulus)| {
+                u64::try_from(next_congruent(residue, modulus, i128::from(max_offset)))
+                    .map_err(|_| CrtError::Overflow)
+            })
+            .min_by_key(|&step| step.unwrap_or(u64::MAX))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2, with events on the even states
+        let cycle = Cycle::find(0, 10, |&state| (if state == 4 { 2 } else { state + 1 }, state % 2 == 0))
+            .unwrap();
+        assert_eq!(cycle, Cycle { offset: 2, period: 3, transient: vec![0], periodic: vec![2, 4] });
+        assert!(cycle.is_event(7));
+        assert!(!cycle.is_event(6));
+        assert_eq!(Cycle::find(0_u64, 10, |&state| (state + 1, false)), None);
+    }
+
+    #[test]
+    fn test_first_common() {
+        let every = |period, event| Cycle { offset: 0, period, transient: Vec::new(), periodic: vec![event] };
+        assert_eq!(Cycle::first_common(&[every(3, 2), every(5, 4)]), Ok(Some(14)));
+        // both happen once before looping
+        let once = Cycle { offset: 3, period: 2, transient: vec![1], periodic: Vec::new() };
+        assert_eq!(Cycle::first_common(&[once.clone(), every(3, 1)]), Ok(Some(1)));
+        assert_eq!(Cycle::first_common(&[once, every(3, 2)]), Ok(None));
+        assert_eq!(Cycle::first_common(&[every(4, 1), every(6, 2)]), Ok(None));
+        assert_eq!(Cycle::first_common(&[every(u64::MAX, 0), every(u64::MAX - 1, 0), every(u64::MAX - 2, 1)]), Err(CrtError::Overflow));
+    }
+}
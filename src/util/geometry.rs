@@ -0,0 +1,339 @@
+//! Lattice polygon geometry: shoelace area, Pick's theorem and point-in-polygon tests
+
+use super::{gcd, Rational};
+
+pub type Point = (i64, i64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolygonError {
+    /// Fewer than 3 distinct, non-collinear vertices were provided
+    TooFewVertices,
+    /// Two of the polygon's edges cross, touch or overlap
+    SelfIntersecting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointLocation {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple (non self-intersecting) polygon with integer vertices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+#[inline]
+#[must_use]
+const fn cross((ax, ay): Point, (bx, by): Point, (cx, cy): Point) -> i64 {
+    (bx - ax) * (cy - ay) - (by - ay) * (cx - ax)
+}
+
+#[inline]
+#[must_use]
+const fn dot((ax, ay): Point, (bx, by): Point, (cx, cy): Point) -> i64 {
+    (bx - ax) * (cx - ax) + (by - ay) * (cy - ay)
+}
+
+/// Whether `b` lies on the straight line going from `a` onwards to `c`
+#[inline]
+#[must_use]
+const fn is_straight(a: Point, b: Point, c: Point) -> bool {
+    cross(a, b, c) == 0 && dot(b, a, c) < 0
+}
+
+/// Whether `p` lies on the segment `a`-`b`
+#[inline]
+#[must_use]
+fn on_segment(a: Point, b: Point, p: Point) -> bool {
+    cross(a, b, p) == 0
+        && p.0 >= a.0.min(b.0) && p.0 <= a.0.max(b.0)
+        && p.1 >= a.1.min(b.1) && p.1 <= a.1.max(b.1)
+}
+
+#[must_use]
+fn segments_intersect((p1, p2): (Point, Point), (q1, q2): (Point, Point)) -> bool {
+    if p1.0.max(p2.0) < q1.0.min(q2.0)
+        || q1.0.max(q2.0) < p1.0.min(p2.0)
+        || p1.1.max(p2.1) < q1.1.min(q2.1)
+        || q1.1.max(q2.1) < p1.1.min(p2.1)
+    {
+        return false;
+    }
+    let d1 = cross(q1, q2, p1).signum();
+    let d2 = cross(q1, q2, p2).signum();
+    let d3 = cross(p1, p2, q1).signum();
+    let d4 = cross(p1, p2, q2).signum();
+
+    (d1 * d2 < 0 && d3 * d4 < 0)
+        || on_segment(q1, q2, p1)
+        || on_segment(q1, q2, p2)
+        || on_segment(p1, p2, q1)
+        || on_segment(p1, p2, q2)
+}
+
+impl Polygon {
+    /// Builds a polygon from its vertices in order (either orientation),
+    /// dropping repeated and collinear vertices along the way.
+    /// The polygon is implicitly closed, so repeating the first vertex at the end is optional
+    ///
+    /// # Errors
+    ///
+    /// If the polygon is degenerate or self-intersecting
+    pub fn new<I>(vertices: I) -> Result<Self, PolygonError>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut simplified: Vec<Point> = Vec::new();
+
+        for vertex in vertices {
+            if simplified.last() == Some(&vertex) {
+                continue;
+            }
+            while let [.., a, b] = simplified[..] {
+                if !is_straight(a, b, vertex) {
+                    break;
+                }
+                simplified.pop();
+            }
+            simplified.push(vertex);
+        }
+
+        // close up the seam between the last and first vertices
+        if simplified.len() > 1 && simplified.first() == simplified.last() {
+            simplified.pop();
+        }
+        while let [first, second, .., before_last, last] = simplified[..] {
+            if is_straight(before_last, last, first) {
+                simplified.pop();
+            } else if is_straight(last, first, second) {
+                simplified.remove(0);
+            } else {
+                break;
+            }
+        }
+
+        if simplified.len() < 3 {
+            return Err(PolygonError::TooFewVertices);
+        }
+        let polygon = Self { vertices: simplified };
+
+        if polygon.is_self_intersecting() {
+            Err(PolygonError::SelfIntersecting)
+        } else if polygon.double_area() == 0 {
+            Err(PolygonError::TooFewVertices)
+        } else {
+            Ok(polygon)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Iterates over every edge as a pair of its endpoints
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(
+                self.vertices
+                    .iter()
+                    .copied()
+                    .cycle()
+                    .skip(1)
+            )
+    }
+
+    fn is_self_intersecting(&self) -> bool {
+        let edges = self.edges()
+            .collect::<Vec<(Point, Point)>>();
+        let n = edges.len();
+
+        // adjacent edges share a vertex, and may only overlap if they double back
+        if edges
+            .iter()
+            .zip(edges.iter().cycle().skip(1))
+            .any(|(&(a, shared), &(_, c))|
+                cross(shared, a, c) == 0 && dot(shared, a, c) > 0
+            )
+        {
+            return true;
+        }
+
+        // sweep over the edges from left to right,
+        // only comparing those whose x ranges overlap
+        let mut order = (0..n).collect::<Vec<usize>>();
+        order.sort_unstable_by_key(|&i| edges[i].0.0.min(edges[i].1.0));
+
+        for (k, &i) in order
+            .iter()
+            .enumerate()
+        {
+            let max_x = edges[i].0.0.max(edges[i].1.0);
+
+            for &j in &order[k + 1..] {
+                if edges[j].0.0.min(edges[j].1.0) > max_x {
+                    break;
+                }
+                let is_adjacent = i.abs_diff(j) == 1 || i.abs_diff(j) == n - 1;
+                if !is_adjacent && segments_intersect(edges[i], edges[j]) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Twice the enclosed area, via the shoelace formula
+    #[must_use]
+    pub fn double_area(&self) -> u64 {
+        self.edges()
+            .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+            .sum::<i64>()
+            .unsigned_abs()
+    }
+
+    #[must_use]
+    pub fn area(&self) -> Rational {
+        Rational::new(self.double_area() as i128, 2)
+    }
+
+    /// The number of lattice points lying on the polygon's edges
+    #[must_use]
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|((x1, y1), (x2, y2))| gcd(
+                x1.abs_diff(x2),
+                y1.abs_diff(y2),
+            ))
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside the polygon, via Pick's theorem
+    ///
+    /// `A = i + b/2 - 1`
+    #[must_use]
+    pub fn interior_points(&self) -> u64 {
+        (self.double_area() + 2 - self.boundary_points()) / 2
+    }
+
+    /// The number of lattice points either inside or on the boundary of the polygon
+    #[must_use]
+    pub fn enclosed_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Locates a point relative to the polygon using the crossing number
+    #[must_use]
+    pub fn locate(&self, point: Point) -> PointLocation {
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            if on_segment(a, b, point) {
+                return PointLocation::Boundary;
+            }
+            if (a.1 > point.1) != (b.1 > point.1) {
+                let side = cross(a, b, point);
+                if (b.1 > a.1 && side > 0) || (b.1 < a.1 && side < 0) {
+                    inside = !inside;
+                }
+            }
+        }
+        if inside { PointLocation::Inside } else { PointLocation::Outside }
+    }
+
+    /// Whether a point lies inside or on the boundary of the polygon
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.locate(point) != PointLocation::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted_vertices(polygon: &Polygon) -> Vec<Point> {
+        let mut vertices = polygon.vertices().to_vec();
+        vertices.sort_unstable();
+        vertices
+    }
+
+    #[test]
+    fn test_simplification() {
+        // midpoints along the edges, one of them across the seam, and a repeated vertex
+        let square = Polygon::new([(0, 2), (0, 0), (2, 0), (4, 0), (4, 0), (4, 4), (2, 4), (0, 4), (0, 2)]).unwrap();
+        assert_eq!(sorted_vertices(&square), [(0, 0), (0, 4), (4, 0), (4, 4)]);
+        assert_eq!(square.edges().count(), 4);
+
+        assert_eq!(Polygon::new([(0, 0), (1, 1), (2, 2)]), Err(PolygonError::TooFewVertices));
+        assert_eq!(Polygon::new([(0, 0), (3, 0), (0, 0)]), Err(PolygonError::TooFewVertices));
+        assert_eq!(Polygon::new([]), Err(PolygonError::TooFewVertices));
+    }
+
+    #[test]
+    fn test_self_intersection() {
+        // a bowtie, whose edges cross
+        assert_eq!(Polygon::new([(0, 0), (2, 2), (2, 0), (0, 2)]), Err(PolygonError::SelfIntersecting));
+        // two squares touching at a corner, which the polygon passes through twice
+        assert_eq!(
+            Polygon::new([(0, 0), (2, 0), (2, 2), (4, 2), (4, 4), (2, 4), (2, 2), (0, 2)]),
+            Err(PolygonError::SelfIntersecting),
+        );
+        // an edge doubling back over the one before it
+        assert_eq!(Polygon::new([(0, 0), (4, 0), (2, 0), (2, 2)]), Err(PolygonError::SelfIntersecting));
+        // a vertex touching a non-adjacent edge
+        assert_eq!(
+            Polygon::new([(0, 0), (4, 0), (4, 4), (2, 0), (0, 4)]),
+            Err(PolygonError::SelfIntersecting),
+        );
+    }
+
+    #[test]
+    fn test_picks_theorem() {
+        let rectangle = Polygon::new([(0, 0), (4, 0), (4, 3), (0, 3)]).unwrap();
+        assert_eq!(rectangle.area(), Rational::from(12));
+        assert_eq!(rectangle.boundary_points(), 14);
+        assert_eq!(rectangle.interior_points(), 6);
+        assert_eq!(rectangle.enclosed_points(), 20);
+
+        // clockwise, with a slanted edge
+        let triangle = Polygon::new([(0, 0), (0, 4), (4, 0)]).unwrap();
+        assert_eq!(triangle.area(), Rational::from(8));
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 3);
+
+        let half = Polygon::new([(0, 0), (1, 0), (0, 1)]).unwrap();
+        assert_eq!(half.area(), Rational::new(1, 2));
+        assert_eq!(half.interior_points(), 0);
+        assert_eq!(half.enclosed_points(), 3);
+    }
+
+    #[test]
+    fn test_locate() {
+        let l_shape = Polygon::new([(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]).unwrap();
+        assert_eq!(l_shape.interior_points(), 5);
+
+        assert_eq!(l_shape.locate((1, 3)), PointLocation::Inside);
+        assert_eq!(l_shape.locate((3, 1)), PointLocation::Inside);
+        assert_eq!(l_shape.locate((3, 3)), PointLocation::Outside);
+        assert_eq!(l_shape.locate((2, 3)), PointLocation::Boundary);
+        assert_eq!(l_shape.locate((0, 0)), PointLocation::Boundary);
+        // in line with the edges, though beyond them
+        assert_eq!(l_shape.locate((5, 2)), PointLocation::Outside);
+        assert_eq!(l_shape.locate((-1, 0)), PointLocation::Outside);
+
+        let interior = (-1..=5)
+            .flat_map(|x| (-1..=5).map(move |y| (x, y)))
+            .filter(|&point| l_shape.locate(point) == PointLocation::Inside)
+            .count();
+        assert_eq!(interior as u64, l_shape.interior_points());
+        assert!(l_shape.contains((2, 2)));
+    }
+}
@@ -0,0 +1,373 @@
+//! Small graph toolkit: union-find, an adjacency-list graph, connected components and dominator trees
+
+use std::collections::VecDeque;
+
+/// Disjoint sets over `0..n`, with path compression and union by size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    n_sets: usize,
+}
+
+impl UnionFind {
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+            sizes: vec![1; n],
+            n_sets: n,
+        }
+    }
+
+    /// The representative of the set containing `node`
+    pub fn find(&mut self, node: usize) -> usize {
+        let mut root = node;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut node = node;
+        while self.parents[node] != root {
+            let next = self.parents[node];
+            self.parents[node] = root;
+            node = next;
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`,
+    /// returning whether they were separate beforehand
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.n_sets -= 1;
+        true
+    }
+
+    #[inline]
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The size of the set containing `node`
+    #[inline]
+    pub fn set_size(&mut self, node: usize) -> usize {
+        let root = self.find(node);
+        self.sizes[root]
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn n_sets(&self) -> usize {
+        self.n_sets
+    }
+
+    /// Every set, as its members in ascending order
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut sets = vec![Vec::new(); self.parents.len()];
+        for node in 0..self.parents.len() {
+            let root = self.find(node);
+            sets[root].push(node);
+        }
+        sets.retain(|set| !set.is_empty());
+        sets
+    }
+}
+
+/// A directed graph over the nodes `0..n`, stored as adjacency lists
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        Self { adjacency: vec![Vec::new(); n] }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    /// Adds a new node, returning its index
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(Vec::new());
+        self.adjacency.len() - 1
+    }
+
+    #[inline]
+    pub fn add_edge(&mut self, source: usize, target: usize) {
+        self.adjacency[source].push(target);
+    }
+
+    /// Adds an edge in both directions
+    #[inline]
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize) {
+        self.add_edge(a, b);
+        self.add_edge(b, a);
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(source, targets)| targets
+                .iter()
+                .map(move |&target| (source, target))
+            )
+    }
+
+    /// The same graph with every edge flipped
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        let mut reversed = Self::new(self.len());
+        for (source, target) in self.edges() {
+            reversed.add_edge(target, source);
+        }
+        reversed
+    }
+
+    /// The weakly connected components, ignoring edge directions
+    #[must_use]
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut sets = UnionFind::new(self.len());
+        for (source, target) in self.edges() {
+            sets.union(source, target);
+        }
+        sets.sets()
+    }
+
+    /// Kahn's algorithm, returning [`None`] if the graph has a cycle
+    #[must_use]
+    pub fn topological_order(&self) -> Option<Vec<usize>> {
+        let mut in_degrees = vec![0; self.len()];
+        for (_, target) in self.edges() {
+            in_degrees[target] += 1;
+        }
+
+        let mut to_check = (0..self.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .collect::<VecDeque<usize>>();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(node) = to_check.pop_front() {
+            order.push(node);
+            for &target in self.neighbors(node) {
+                in_degrees[target] -= 1;
+                if in_degrees[target] == 0 {
+                    to_check.push_back(target);
+                }
+            }
+        }
+        (order.len() == self.len())
+            .then_some(order)
+    }
+
+    /// Builds the dominator tree of a DAG from `root`:
+    /// a node `a` dominates `b` when every path from `root` to `b` passes through `a`.
+    ///
+    /// Returns [`None`] if the graph has a cycle
+    #[must_use]
+    pub fn dominator_tree(&self, root: usize) -> Option<DominatorTree> {
+        let order = self.topological_order()?;
+        let predecessors = self.reversed();
+
+        let mut tree = DominatorTree {
+            root,
+            idoms: vec![None; self.len()],
+            depths: vec![None; self.len()],
+            order: Vec::with_capacity(self.len()),
+        };
+        tree.depths[root] = Some(0);
+        tree.order.push(root);
+
+        // every predecessor of a node comes before it in topological order,
+        // so its immediate dominator is the common ancestor of theirs
+        for &node in order
+            .iter()
+            .filter(|&&node| node != root)
+        {
+            let Some(idom) = predecessors
+                .neighbors(node)
+                .iter()
+                .copied()
+                .filter(|&pred| tree.depths[pred].is_some())
+                .reduce(|a, b| tree.common_dominator(a, b))
+            else {
+                continue;
+            };
+            tree.idoms[node] = Some(idom);
+            tree.depths[node] = tree.depths[idom].map(|depth| depth + 1);
+            tree.order.push(node);
+        }
+        Some(tree)
+    }
+}
+
+/// The immediate dominators of every node reachable from a root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DominatorTree {
+    root: usize,
+    idoms: Vec<Option<usize>>,
+    depths: Vec<Option<usize>>,
+    /// reachable nodes, each after its dominators
+    order: Vec<usize>,
+}
+
+impl DominatorTree {
+    #[inline]
+    #[must_use]
+    pub const fn root(&self) -> usize {
+        self.root
+    }
+
+    /// The closest strict dominator of a node,
+    /// which is [`None`] for the root and unreachable nodes
+    #[inline]
+    #[must_use]
+    pub fn immediate_dominator(&self, node: usize) -> Option<usize> {
+        self.idoms[node]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_reachable(&self, node: usize) -> bool {
+        self.depths[node].is_some()
+    }
+
+    /// The deepest node dominating both `a` and `b`, which must be reachable
+    #[must_use]
+    pub fn common_dominator(&self, mut a: usize, mut b: usize) -> usize {
+        while a != b {
+            if self.depths[a] < self.depths[b] {
+                b = self.idoms[b].unwrap_or(self.root);
+            } else {
+                a = self.idoms[a].unwrap_or(self.root);
+            }
+        }
+        a
+    }
+
+    /// Whether every path from the root to `b` passes through `a`
+    #[must_use]
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        self.is_reachable(a)
+            && self.is_reachable(b)
+            && self.common_dominator(a, b) == a
+    }
+
+    /// For every node, the number of other nodes it strictly dominates
+    #[must_use]
+    pub fn dominated_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.idoms.len()];
+        for &node in self.order
+            .iter()
+            .rev()
+        {
+            if let Some(idom) = self.idoms[node] {
+                counts[idom] += counts[node] + 1;
+            }
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `0 -> {1, 2} -> 3 -> 4`, with 5 on its own
+    fn diamond() -> Graph {
+        let mut graph = Graph::new(6);
+        for (source, target) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+            graph.add_edge(source, target);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(5);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(!sets.union(1, 0));
+        assert!(sets.connected(0, 1));
+        assert!(!sets.connected(1, 2));
+        assert_eq!(sets.set_size(1), 2);
+        assert_eq!(sets.n_sets(), 3);
+
+        let mut members = sets.sets();
+        members.sort_unstable();
+        assert_eq!(members, [vec![0, 1], vec![2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut components = diamond().connected_components();
+        components.sort_unstable();
+        assert_eq!(components, [vec![0, 1, 2, 3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let graph = diamond();
+        let order = graph
+            .topological_order()
+            .unwrap();
+        let position = |node| order
+            .iter()
+            .position(|&other| other == node)
+            .unwrap();
+        assert_eq!(order.len(), graph.len());
+        assert!(graph
+            .edges()
+            .all(|(source, target)| position(source) < position(target))
+        );
+
+        let mut cyclic = graph;
+        cyclic.add_edge(4, 0);
+        assert_eq!(cyclic.topological_order(), None);
+        assert_eq!(cyclic.dominator_tree(0), None);
+    }
+
+    #[test]
+    fn test_dominators() {
+        let tree = diamond()
+            .dominator_tree(0)
+            .unwrap();
+        let idoms = (0..6)
+            .map(|node| tree.immediate_dominator(node))
+            .collect::<Vec<Option<usize>>>();
+        // neither side of the diamond dominates where it joins up
+        assert_eq!(idoms, [None, Some(0), Some(0), Some(0), Some(3), None]);
+        assert!(tree.dominates(3, 4));
+        assert!(tree.dominates(0, 4));
+        assert!(!tree.dominates(1, 3));
+        assert!(!tree.is_reachable(5));
+        assert!(!tree.dominates(0, 5));
+        assert_eq!(tree.common_dominator(1, 4), 0);
+        assert_eq!(tree.dominated_counts(), [4, 0, 0, 1, 0, 0]);
+    }
+}
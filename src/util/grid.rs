@@ -0,0 +1,214 @@
+//! Compressing corridor mazes into small weighted graphs between their junctions
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use serde::Serialize;
+
+pub type Coord = (usize, usize);
+
+/// A weighted graph over a grid's junctions, with nodes densely indexed from `0`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompressedGraph {
+    nodes: Vec<Coord>,
+    /// The inverse of `nodes`, left out when serialized
+    #[serde(skip)]
+    indices: HashMap<Coord, usize>,
+    adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl CompressedGraph {
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The grid cell a node sits on
+    #[inline]
+    #[must_use]
+    pub fn coord(&self, node: usize) -> Coord {
+        self.nodes[node]
+    }
+
+    /// The node sitting on a grid cell, if any
+    #[inline]
+    #[must_use]
+    pub fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.indices
+            .get(&coord)
+            .copied()
+    }
+
+    /// The `(node, distance)` pairs reachable from a node without passing through any other node
+    #[inline]
+    #[must_use]
+    pub fn neighbors(&self, node: usize) -> &[(usize, usize)] {
+        &self.adjacency[node]
+    }
+
+    /// Every directed `(source, target, distance)` edge,
+    /// ready to be fed into other graph libraries
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(source, targets)| targets
+                .iter()
+                .map(move |&(target, distance)| (source, target, distance))
+            )
+    }
+}
+
+/// Finds the passable orthogonal neighbors of a cell
+#[must_use]
+pub fn get_passable_neighbors<P>(
+    grid: &[Vec<u8>],
+    (row, col): Coord,
+    is_passable: &P,
+) -> Vec<Coord>
+where
+    P: Fn(u8) -> bool,
+{
+    [
+        (row, col + 1),
+        (row, col.wrapping_sub(1)),
+        (row + 1, col),
+        (row.wrapping_sub(1), col),
+    ]
+    .into_iter()
+    .filter(|&(i, j)|
+        grid
+            .get(i)
+            .and_then(|row| row.get(j))
+            .is_some_and(|&tile| is_passable(tile))
+    )
+    .collect()
+}
+
+/// Compresses a grid of corridors into a weighted graph.
+///
+/// Nodes are placed on every junction (a passable cell with 3 or more passable neighbors),
+/// as well as on each of the `extra_nodes` (such as the start and end),
+/// which are given the first indices in the order provided.
+/// Edges connect nodes along corridors with their walking distance as the weight.
+///
+/// `can_move(from, to)` can optionally restrict which steps are allowed,
+/// such as for one-way slopes, making the resulting edges directed.
+#[must_use]
+pub fn compress_grid<P>(
+    grid: &[Vec<u8>],
+    extra_nodes: &[Coord],
+    is_passable: P,
+    can_move: Option<&dyn Fn(Coord, Coord) -> bool>,
+) -> CompressedGraph
+where
+    P: Fn(u8) -> bool,
+{
+    let mut nodes = extra_nodes.to_vec();
+    nodes.extend(
+        grid.iter()
+            .enumerate()
+            .flat_map(|(i, row)| row
+                .iter()
+                .enumerate()
+                .map(move |(j, &tile)| ((i, j), tile))
+            )
+            .filter(|&(coord, tile)|
+                is_passable(tile)
+                && !extra_nodes.contains(&coord)
+                && get_passable_neighbors(grid, coord, &is_passable).len() >= 3
+            )
+            .map(|(coord, _)| coord)
+    );
+
+    let indices = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &coord)| (coord, i))
+        .collect::<HashMap<Coord, usize>>();
+
+    let adjacency = nodes
+        .iter()
+        .map(|&starting_node| {
+            let mut edges = Vec::new();
+            let mut to_check = VecDeque::from([(starting_node, 0)]);
+            let mut seen = HashSet::from([starting_node]);
+
+            while let Some((node, distance)) = to_check.pop_front() {
+                if let Some(&index) = indices
+                    .get(&node)
+                    .filter(|_| distance > 0)
+                {
+                    edges.push((index, distance));
+                    continue;
+                }
+                for next_node in get_passable_neighbors(grid, node, &is_passable) {
+                    if can_move.is_none_or(|can_move| can_move(node, next_node))
+                        && seen.insert(next_node)
+                    {
+                        to_check.push_back((next_node, distance + 1));
+                    }
+                }
+            }
+            edges
+        })
+        .collect();
+
+    CompressedGraph { nodes, indices, adjacency }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two corridors of the same length between the junctions at `(1, 1)` and `(3, 5)`
+    const MAZE: [&str; 5] = [
+        "#.#####",
+        "#.....#",
+        "#.###.#",
+        "#.....#",
+        "#####.#",
+    ];
+
+    fn maze() -> Vec<Vec<u8>> {
+        MAZE.iter()
+            .map(|row| row.bytes().collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_compress_grid() {
+        let (start, end) = ((0, 1), (4, 5));
+        let graph = compress_grid(&maze(), &[start, end], |tile| tile != b'#', None);
+
+        // the extra nodes come first, then the junctions in reading order
+        assert_eq!(graph.len(), 4);
+        assert_eq!((0..4).map(|node| graph.coord(node)).collect::<Vec<Coord>>(), [start, end, (1, 1), (3, 5)]);
+        assert_eq!(graph.index_of((3, 5)), Some(3));
+        assert_eq!(graph.index_of((1, 3)), None);
+
+        assert_eq!(graph.neighbors(0), [(2, 1)]);
+        assert_eq!(graph.neighbors(1), [(3, 1)]);
+        assert_eq!(graph.neighbors(2), [(0, 1), (3, 6)]);
+        assert_eq!(graph.neighbors(3), [(1, 1), (2, 6)]);
+        assert_eq!(graph.edges().count(), 6);
+    }
+
+    #[test]
+    fn test_compress_grid_one_way() {
+        let (start, end) = ((0, 1), (4, 5));
+        // never stepping back up, like the slopes of Day 23
+        let downhill = |from: Coord, to: Coord| to.0 >= from.0;
+        let graph = compress_grid(&maze(), &[start, end], |tile| tile != b'#', Some(&downhill));
+
+        assert_eq!(graph.neighbors(0), [(2, 1)]);
+        assert_eq!(graph.neighbors(1), []);
+        assert_eq!(graph.neighbors(2), [(3, 6)]);
+        assert_eq!(graph.neighbors(3), [(1, 1)]);
+    }
+}
@@ -0,0 +1,72 @@
+//! Stable stand-ins for nightly-only iterator adapters
+
+use std::array;
+
+/// Iterates over the items `N` at a time, like the nightly `Iterator::array_chunks`,
+/// leaving out any items at the end that don't fill a chunk
+#[derive(Debug, Clone)]
+pub struct ArrayChunks<I, const N: usize> {
+    iter: I,
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = array::from_fn::<_, N, _>(|_| self.iter.next());
+        chunk
+            .iter()
+            .all(Option::is_some)
+            .then(|| chunk.map(Option::unwrap))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|upper| upper / N))
+    }
+}
+
+pub trait IteratorExt: Iterator + Sized {
+    /// See [`ArrayChunks`], named apart from the nightly adapter to not collide with it
+    ///
+    /// # Panics
+    ///
+    /// If `N` is `0`
+    fn chunked<const N: usize>(self) -> ArrayChunks<Self, N> {
+        assert!(N != 0, "Chunk size must be non-zero");
+        ArrayChunks { iter: self }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunked() {
+        let mut chunks = (0..8).chunked::<3>();
+        assert_eq!(chunks.size_hint(), (2, Some(2)));
+        assert_eq!(chunks.next(), Some([0, 1, 2]));
+        assert_eq!(chunks.next(), Some([3, 4, 5]));
+        // 6 and 7 don't fill a chunk
+        assert_eq!(chunks.next(), None);
+
+        assert_eq!((0..6).chunked::<2>().collect::<Vec<_>>(), [[0, 1], [2, 3], [4, 5]]);
+        assert_eq!((0..2).chunked::<3>().count(), 0);
+        assert_eq!((0..5).chunked::<1>().count(), 5);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_chunked_matches_nightly() {
+        assert!((0..20).chunked::<3>().eq((0..20).array_chunks::<3>()));
+    }
+
+    #[test]
+    #[should_panic = "Chunk size must be non-zero"]
+    fn test_chunked_empty() {
+        let _ = (0..4).chunked::<0>();
+    }
+}
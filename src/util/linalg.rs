@@ -0,0 +1,239 @@
+//! Exact solving of integer linear systems using fraction-free (Bareiss) elimination
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::BigInt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearSystemError {
+    /// The matrix is not square or does not match the length of the right-hand side
+    DimensionMismatch,
+    /// The equations contradict each other, so there is no solution
+    Inconsistent,
+    /// The equations are not independent, so there is no unique solution
+    Degenerate,
+}
+
+impl Display for LinearSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::DimensionMismatch => "matrix dimensions do not match",
+            Self::Inconsistent => "linear system is inconsistent",
+            Self::Degenerate => "linear system does not have a unique solution",
+        })
+    }
+}
+
+impl Error for LinearSystemError {}
+
+/// The unique solution of a linear system, as numerators over a common denominator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearSolution {
+    numerators: Vec<BigInt>,
+    denominator: BigInt,
+}
+
+impl LinearSolution {
+    #[inline]
+    #[must_use]
+    pub fn numerators(&self) -> &[BigInt] {
+        &self.numerators
+    }
+
+    /// The (non-zero) determinant of the system's matrix, up to sign
+    #[inline]
+    #[must_use]
+    pub const fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    /// Returns the solution if every value is an integer that fits into an `i128`
+    #[must_use]
+    pub fn to_integers(&self) -> Option<Vec<i128>> {
+        self.numerators
+            .iter()
+            .map(|numerator| {
+                let (quotient, remainder) = numerator.div_rem(&self.denominator);
+                remainder
+                    .is_zero()
+                    .then(|| i128::try_from(&quotient).ok())
+                    .flatten()
+            })
+            .collect()
+    }
+}
+
+/// The rank of a matrix, via row reduction where each row is kept primitive by its gcd
+fn rank(mut rows: Vec<Vec<BigInt>>) -> usize {
+    let n_cols = rows
+        .first()
+        .map_or(0, Vec::len);
+    let mut rank = 0;
+
+    for col in 0..n_cols {
+        let Some(pivot) = (rank..rows.len())
+            .find(|&row| !rows[row][col].is_zero())
+        else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        for row in rank + 1..rows.len() {
+            let factor = rows[row][col].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            let pivot_value = rows[rank][col].clone();
+            let reduced = rows[row]
+                .iter()
+                .zip(&rows[rank])
+                .map(|(value, pivot_row_value)|
+                    &(&pivot_value * value) - &(&factor * pivot_row_value)
+                )
+                .collect::<Vec<BigInt>>();
+
+            let content = reduced
+                .iter()
+                .fold(BigInt::zero(), |acc, value| acc.gcd(value));
+            rows[row] = if content.is_zero() {
+                reduced
+            } else {
+                reduced
+                    .iter()
+                    .map(|value| value.div_rem(&content).0)
+                    .collect()
+            };
+        }
+        rank += 1;
+    }
+    rank
+}
+
+/// Solves `matrix * x = rhs` exactly for a square integer matrix
+///
+/// # Errors
+///
+/// If the dimensions do not line up,
+/// or if the system has either no solution or infinitely many
+pub fn solve_linear_system<M>(matrix: &[M], rhs: &[i128]) -> Result<LinearSolution, LinearSystemError>
+where
+    M: AsRef<[i128]>,
+{
+    let n = matrix.len();
+    if rhs.len() != n || matrix
+        .iter()
+        .any(|row| row.as_ref().len() != n)
+    {
+        return Err(LinearSystemError::DimensionMismatch);
+    }
+
+    // the augmented matrix [matrix | rhs]
+    let mut augmented = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &value)| row
+            .as_ref()
+            .iter()
+            .chain([value].iter())
+            .map(|&entry| BigInt::from(entry))
+            .collect::<Vec<BigInt>>()
+        )
+        .collect::<Vec<Vec<BigInt>>>();
+
+    let mut previous_pivot = BigInt::from(1);
+
+    for k in 0..n {
+        let Some(pivot) = (k..n)
+            .find(|&row| !augmented[row][k].is_zero())
+        else {
+            let coefficients = augmented
+                .iter()
+                .map(|row| row[..n].to_vec())
+                .collect();
+            return Err(
+                if rank(coefficients) < rank(augmented) {
+                    LinearSystemError::Inconsistent
+                } else {
+                    LinearSystemError::Degenerate
+                }
+            );
+        };
+        augmented.swap(k, pivot);
+
+        // fraction-free gauss-jordan: every division by the previous pivot is exact,
+        // leaving `det * I` on the left once done
+        let pivot_row = augmented[k].clone();
+        for (i, row) in augmented
+            .iter_mut()
+            .enumerate()
+        {
+            if i == k {
+                continue;
+            }
+            let factor = row[k].clone();
+            for (value, pivot_row_value) in row
+                .iter_mut()
+                .zip(&pivot_row)
+            {
+                let (quotient, remainder) = (
+                    &(&pivot_row[k] * value) - &(&factor * pivot_row_value)
+                )
+                .div_rem(&previous_pivot);
+                debug_assert!(remainder.is_zero());
+                *value = quotient;
+            }
+        }
+        previous_pivot = pivot_row[k].clone();
+    }
+
+    Ok(LinearSolution {
+        numerators: augmented
+            .iter()
+            .map(|row| row[n].clone())
+            .collect(),
+        denominator: previous_pivot,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solved() {
+        let solution = solve_linear_system(&[[2, 1], [1, 3]], &[5, 10]).unwrap();
+        assert_eq!(solution.to_integers(), Some(vec![1, 3]));
+        // the first pivot is zero, so rows have to be swapped
+        let solution = solve_linear_system(&[[0, 1, 1], [1, 0, 1], [1, 1, 0]], &[5, 4, 3]).unwrap();
+        assert_eq!(solution.to_integers(), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_non_integer() {
+        let solution = solve_linear_system(&[[2, 0], [0, 1]], &[1, 1]).unwrap();
+        assert_eq!(solution.to_integers(), None);
+        // x = 1/2 and y = 1
+        assert_eq!(&solution.numerators()[0] * &BigInt::from(2), *solution.denominator());
+        assert_eq!(solution.numerators()[1], *solution.denominator());
+    }
+
+    #[test]
+    fn test_degenerate() {
+        // the second equation is twice the first
+        assert_eq!(solve_linear_system(&[[1, 2], [2, 4]], &[3, 6]), Err(LinearSystemError::Degenerate));
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        // the second equation contradicts twice the first
+        assert_eq!(solve_linear_system(&[[1, 2], [2, 4]], &[3, 7]), Err(LinearSystemError::Inconsistent));
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        assert_eq!(solve_linear_system(&[[1, 2]], &[3]), Err(LinearSystemError::DimensionMismatch));
+    }
+}
@@ -0,0 +1,152 @@
+//! Memoization for recursive solvers
+
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub size: usize,
+    /// The most values cached at once, across clears
+    pub peak_size: usize,
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} cached (at most {})",
+            self.hits, self.misses, self.size, self.peak_size,
+        )
+    }
+}
+
+/// A keyed cache for recursive functions, which passes itself back into the computation
+/// so that the recursive calls can share it.
+///
+/// Keys may borrow from the input, such as `(&[u8], &[usize])`, as long as the input outlives the cache.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+    peak_size: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+            peak_size: 0,
+        }
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached value for `key`,
+    /// otherwise computes it with `compute`, which is handed the memo to recurse with
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        self.peak_size = self.peak_size.max(self.cache.len());
+        value
+    }
+
+    /// Looks up a cached value without computing or counting it
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.cache.get(key)
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.cache.len(),
+            peak_size: self.peak_size,
+        }
+    }
+
+    /// Clears the cached values, such as between unrelated inputs,
+    /// while keeping the hit and miss counts and the peak size
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Resets the hit and miss counts and the peak size
+    pub fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+        self.peak_size = self.cache.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        memo.get_or_compute(n, |memo| match n {
+            0 | 1 => n,
+            _ => fibonacci(n - 1, memo) + fibonacci(n - 2, memo),
+        })
+    }
+
+    #[test]
+    fn test_get_or_compute() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(50, &mut memo), 12_586_269_025);
+        // each of 0..=50 is computed once, and the call for `n - 2` is a hit once `n` is above 2
+        assert_eq!(memo.stats(), CacheStats { hits: 48, misses: 51, size: 51, peak_size: 51 });
+        assert_eq!(memo.get(&10), Some(&55));
+
+        assert_eq!(fibonacci(50, &mut memo), 12_586_269_025);
+        assert_eq!(memo.stats().hits, 49);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut memo = Memo::new();
+        fibonacci(10, &mut memo);
+        memo.clear();
+        assert_eq!(memo.get(&10), None);
+        assert_eq!(memo.stats(), CacheStats { hits: 8, misses: 11, size: 0, peak_size: 11 });
+
+        // the values are computed afresh
+        fibonacci(5, &mut memo);
+        assert_eq!(memo.stats(), CacheStats { hits: 11, misses: 17, size: 6, peak_size: 11 });
+
+        memo.reset_stats();
+        assert_eq!(memo.stats(), CacheStats { hits: 0, misses: 0, size: 6, peak_size: 6 });
+    }
+}
@@ -0,0 +1,236 @@
+//! Overflow-aware number theory helpers:
+//! gcd / lcm, extended euclid, modular inverses and the chinese remainder theorem
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[must_use]
+pub const fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[must_use]
+pub const fn gcd_u128(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd_u128(b, a % b) }
+}
+
+/// Computes the lcm of all the provided numbers,
+/// dividing before multiplying and returning [`None`] if the result would overflow
+#[must_use]
+pub fn checked_lcm<I>(nums: I) -> Option<u64>
+where
+    I: IntoIterator<Item = u64>,
+{
+    nums.into_iter()
+        .try_fold(
+            1u64,
+            |ans, num| match num {
+                0 => Some(0),
+                _ => (ans / gcd(ans, num)).checked_mul(num),
+            },
+        )
+}
+
+/// 128-bit variant of [`checked_lcm`]
+#[must_use]
+pub fn checked_lcm_u128<I>(nums: I) -> Option<u128>
+where
+    I: IntoIterator<Item = u128>,
+{
+    nums.into_iter()
+        .try_fold(
+            1u128,
+            |ans, num| match num {
+                0 => Some(0),
+                _ => (ans / gcd_u128(ans, num)).checked_mul(num),
+            },
+        )
+}
+
+/// # Panics
+///
+/// If the lcm overflows a `u64`
+#[must_use]
+pub fn lcm<I>(nums: I) -> u64
+where
+    I: Iterator<Item = u64>,
+{
+    checked_lcm(nums)
+        .expect("lcm overflowed u64")
+}
+
+/// # Panics
+///
+/// If the lcm overflows a `u128`
+#[must_use]
+pub fn lcm_u128<I>(nums: I) -> u128
+where
+    I: Iterator<Item = u128>,
+{
+    checked_lcm_u128(nums)
+        .expect("lcm overflowed u128")
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g`,
+/// where `g` is the non-negative gcd of `a` and `b`
+#[must_use]
+pub const fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns the inverse of `a` modulo `modulus` in `0..modulus`,
+/// or [`None`] if `modulus` is not positive or `a` and `modulus` are not coprime
+#[must_use]
+pub const fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+    match extended_gcd(a.rem_euclid(modulus), modulus) {
+        (1, x, _) => Some(x.rem_euclid(modulus)),
+        _ => None,
+    }
+}
+
+/// Why a system of congruences has no solution that [`crt`] can give
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// A modulus is not positive
+    InvalidModulus,
+    /// The congruences contradict each other
+    Inconsistent,
+    /// The combined modulus, or the solution on the way to it, would overflow an `i128`
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidModulus => "modulus is not positive",
+            Self::Inconsistent => "congruences are inconsistent",
+            Self::Overflow => "solution overflows an i128",
+        })
+    }
+}
+
+impl Error for CrtError {}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`,
+/// where the moduli do not need to be pairwise coprime.
+///
+/// Returns `(x, m)` where every solution is `x + k * m` and `0 <= x < m`;
+/// an empty system yields `(0, 1)`.
+///
+/// # Errors
+///
+/// If a modulus is not positive, the system is inconsistent,
+/// or the combined modulus would overflow an `i128`
+pub fn crt<I>(congruences: I) -> Result<(i128, i128), CrtError>
+where
+    I: IntoIterator<Item = (i128, i128)>,
+{
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            if m2 <= 0 {
+                return Err(CrtError::InvalidModulus);
+            }
+            let r2 = r2.rem_euclid(m2);
+            let (g, _, _) = extended_gcd(m1, m2);
+            let diff = r2 - r1;
+
+            if diff % g != 0 {
+                return Err(CrtError::Inconsistent);
+            }
+            let step = m2 / g;
+            // `m1 / g` and `step` are coprime, so the inverse always exists
+            let inverse = mod_inverse(m1 / g, step)
+                .ok_or(CrtError::Inconsistent)?;
+            let k = (diff / g)
+                .rem_euclid(step)
+                .checked_mul(inverse)
+                .ok_or(CrtError::Overflow)?
+                .rem_euclid(step);
+            let modulus = (m1 / g)
+                .checked_mul(m2)
+                .ok_or(CrtError::Overflow)?;
+
+            Ok((
+                m1.checked_mul(k)
+                    .and_then(|x| x.checked_add(r1))
+                    .ok_or(CrtError::Overflow)?
+                    .rem_euclid(modulus),
+                modulus,
+            ))
+        })
+}
+
+/// Returns the smallest `x >= min` with `x ≡ residue (mod modulus)`
+#[must_use]
+pub const fn next_congruent(residue: i128, modulus: i128, min: i128) -> i128 {
+    min + (residue - min).rem_euclid(modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crt() {
+        // 6 and 4 share a factor of 2, and both residues are even
+        assert_eq!(crt([(2, 6), (0, 4)]), Ok((8, 12)));
+        assert_eq!(crt([(3, 5), (2, 7), (1, 3)]), Ok((58, 105)));
+        // an even and an odd residue modulo even moduli
+        assert_eq!(crt([(1, 6), (0, 4)]), Err(CrtError::Inconsistent));
+        // residues are reduced into `0..modulus` first
+        assert_eq!(crt([(-1, 5), (-2, 7)]), Ok((19, 35)));
+        assert_eq!(crt([(3, 0)]), Err(CrtError::InvalidModulus));
+        assert_eq!(crt([]), Ok((0, 1)));
+        assert_eq!(crt([(0, i128::MAX), (1, 2)]), Err(CrtError::Overflow));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 6), None);
+        assert_eq!(mod_inverse(0, 5), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_inverse(3, -7), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (240, -46), (0, 5), (5, 0), (0, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(a * x + b * y, g);
+            assert!(g >= 0);
+            assert_eq!(g.unsigned_abs(), gcd_u128(a.unsigned_abs(), b.unsigned_abs()));
+        }
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(checked_lcm([4, 6, 10]), Some(60));
+        assert_eq!(checked_lcm([4, 0]), Some(0));
+        assert_eq!(checked_lcm([u64::MAX, u64::MAX - 1]), None);
+        assert_eq!(next_congruent(3, 5, 11), 13);
+        assert_eq!(next_congruent(3, 5, 13), 13);
+    }
+}
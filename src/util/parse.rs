@@ -0,0 +1,357 @@
+//! A small parsing toolkit whose errors point at the line and column of the offending input
+
+use std::{
+    any::type_name,
+    error::Error,
+    fmt::{self, Display},
+    slice::SliceIndex,
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A slice of the input which remembers where it came from,
+/// so that errors can be reported with a (1-indexed) line and column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+impl<'a> Span<'a> {
+    #[must_use]
+    pub const fn new(source: &'a str) -> Self {
+        Self { source, text: source }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// `text` must be a subslice of this span's source
+    #[inline]
+    #[must_use]
+    const fn sub(&self, text: &'a str) -> Self {
+        Self { source: self.source, text }
+    }
+
+    /// The line and column this span starts at
+    #[must_use]
+    pub fn position(&self) -> (usize, usize) {
+        let offset = self.text.as_ptr() as usize - self.source.as_ptr() as usize;
+        let before = &self.source[..offset];
+
+        let line_start = before
+            .rfind('\n')
+            .map_or(0, |i| i + 1);
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// Creates an error pointing at the start of this span
+    #[must_use]
+    pub fn error<M: Display>(&self, message: M) -> ParseError {
+        let (line, column) = self.position();
+        ParseError {
+            line, column,
+            message: message.to_string(),
+        }
+    }
+
+    /// Narrows the span down to a byte range within it
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds or not on a char boundary
+    #[must_use]
+    pub fn slice<R>(&self, range: R) -> Self
+    where
+        R: SliceIndex<str, Output = str>,
+    {
+        self.sub(&self.text[range])
+    }
+
+    #[must_use]
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Self> + '_ {
+        self.text
+            .lines()
+            .map(|line| self.sub(line))
+    }
+
+    /// Splits the span into sections separated by blank lines
+    pub fn sections(&self) -> impl Iterator<Item = Self> + '_ {
+        let mut lines = self.text
+            .lines()
+            .peekable();
+
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+                last = line;
+            }
+            let start = first.as_ptr() as usize - self.text.as_ptr() as usize;
+            let end = last.as_ptr() as usize - self.text.as_ptr() as usize + last.len();
+
+            Some(self.sub(&self.text[start..end]))
+        })
+    }
+
+    /// Splits the span on every occurrence of `delimiter`
+    pub fn split<'b>(&self, delimiter: &'b str) -> impl Iterator<Item = Self> + 'b
+    where
+        'a: 'b,
+    {
+        let span = *self;
+        self.text
+            .split(delimiter)
+            .map(move |part| span.sub(part))
+    }
+
+    /// Splits the span on whitespace
+    pub fn words(&self) -> impl Iterator<Item = Self> + '_ {
+        self.text
+            .split_whitespace()
+            .map(|word| self.sub(word))
+    }
+
+    /// # Errors
+    ///
+    /// If `delimiter` does not occur in the span
+    pub fn split_once(&self, delimiter: &str) -> Result<(Self, Self), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .map(|(left, right)| (self.sub(left), self.sub(right)))
+            .ok_or_else(|| self.error(format!("expected `{delimiter}` in `{}`", self.text)))
+    }
+
+    /// Splits the span into a trimmed key and value around `separator`
+    ///
+    /// # Errors
+    ///
+    /// If `separator` does not occur in the span or the key is empty
+    pub fn key_value(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        let (key, value) = self.split_once(separator)?;
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(key.error(format!("expected a key before `{separator}`")));
+        }
+        Ok((key, value.trim()))
+    }
+
+    /// # Errors
+    ///
+    /// If the span does not start with `prefix`
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|text| self.sub(text))
+            .ok_or_else(|| self.error(format!("expected `{prefix}`")))
+    }
+
+    /// # Errors
+    ///
+    /// If the span does not end with `suffix`
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Self, ParseError> {
+        self.text
+            .strip_suffix(suffix)
+            .map(|text| self.sub(text))
+            .ok_or_else(|| self.sub(&self.text[self.text.len()..])
+                .error(format!("expected `{suffix}`"))
+            )
+    }
+
+    /// Parses the trimmed span into any type implementing [`FromStr`]
+    ///
+    /// # Errors
+    ///
+    /// If the conversion fails
+    pub fn parse<F>(&self) -> Result<F, ParseError>
+    where
+        F: FromStr,
+        F::Err: Display,
+    {
+        let trimmed = self.trim();
+        trimmed.text
+            .parse::<F>()
+            .map_err(|err| trimmed.error(format!(
+                "invalid {} `{}`: {err}",
+                type_name::<F>(),
+                trimmed.text,
+            )))
+    }
+
+    /// Parses every field separated by `delimiter`, ignoring empty fields
+    ///
+    /// # Errors
+    ///
+    /// If any of the fields fail to parse
+    pub fn fields<F>(&self, delimiter: &str) -> Result<Vec<F>, ParseError>
+    where
+        F: FromStr,
+        F::Err: Display,
+    {
+        self.split(delimiter)
+            .filter(|field| !field.text.trim().is_empty())
+            .map(|field| field.parse::<F>())
+            .collect()
+    }
+
+    /// Extracts every (optionally signed) integer from the span,
+    /// ignoring whatever is in between them
+    ///
+    /// # Errors
+    ///
+    /// If an integer does not fit into `F`
+    pub fn integers<F>(&self) -> Result<Vec<F>, ParseError>
+    where
+        F: FromStr,
+        F::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut integers = Vec::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let is_signed = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+
+            if is_signed || bytes[i].is_ascii_digit() {
+                let start = i;
+                i += 1;
+                while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                    i += 1;
+                }
+                integers.push(self.sub(&self.text[start..i]).parse()?);
+            } else {
+                i += 1;
+            }
+        }
+        Ok(integers)
+    }
+
+    /// Reads the span as a grid of ASCII tiles, one row per line,
+    /// checking that it has a row, that every row is as wide as the first and that every tile is one of `tiles`
+    ///
+    /// # Errors
+    ///
+    /// If the grid is empty, ragged or has any other tile
+    pub fn grid(&self, tiles: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        let mut rows = Vec::<Vec<u8>>::new();
+        for line in self.lines() {
+            if let Some((i, tile)) = line.text
+                .char_indices()
+                .find(|&(_, tile)| !tiles.contains(tile))
+            {
+                return Err(line.slice(i..).error(format!("unexpected tile `{tile}`, expected one of `{tiles}`")));
+            }
+            if let Some(width) = rows.first().map(Vec::len).filter(|&width| width != line.text.len()) {
+                return Err(line.error(format!("expected a row of {width} tiles, found {}", line.text.len())));
+            }
+            rows.push(line.text.as_bytes().to_vec());
+        }
+        if rows.first().is_none_or(Vec::is_empty) {
+            return Err(self.error("expected a grid"));
+        }
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14\n\nsoil: 50 -98 x7\nwater: é=3, b=four\n";
+
+    #[test]
+    fn test_positions() {
+        let inp = Span::new(INPUT);
+        assert_eq!(inp.position(), (1, 1));
+
+        let sections = inp.sections().collect::<Vec<Span>>();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1].position(), (3, 1));
+
+        let lines = sections[1].lines().collect::<Vec<Span>>();
+        let (key, values) = lines[0].key_value(":").unwrap();
+        assert_eq!((key.as_str(), key.position()), ("soil", (3, 1)));
+        assert_eq!(values.position(), (3, 7));
+        assert_eq!(values.words().nth(1).unwrap().position(), (3, 10));
+
+        // columns count chars rather than bytes
+        let (_, assignments) = lines[1].split_once(":").unwrap();
+        let fields = assignments.split(",").collect::<Vec<Span>>();
+        assert_eq!(fields[1].trim().position(), (4, 13));
+        assert_eq!(
+            fields[1].key_value("=").unwrap().1.parse::<u8>().unwrap_err(),
+            ParseError {
+                line: 4,
+                column: 15,
+                message: "invalid u8 `four`: invalid digit found in string".to_string(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let line = Span::new(INPUT).lines().nth(2).unwrap();
+        assert_eq!(line.split_once("=").unwrap_err().to_string(), "line 3, column 1: expected `=` in `soil: 50 -98 x7`");
+        assert_eq!(line.strip_suffix("!").unwrap_err().column, 16);
+        assert_eq!(line.strip_prefix("water").unwrap_err().column, 1);
+        assert_eq!(line.slice(6..).key_value("50").unwrap_err().column, 7);
+        assert_eq!(line.slice(6..).fields::<i8>(" ").unwrap_err().column, 14);
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = Span::new(INPUT).lines().nth(2).unwrap();
+        assert_eq!(line.integers::<i64>(), Ok(vec![50, -98, 7]));
+        assert_eq!(line.integers::<u64>().unwrap_err().column, 10);
+        assert_eq!(Span::new("a-b--3, 4-5").integers::<i64>(), Ok(vec![-3, 4, -5]));
+        assert_eq!(Span::new("none").integers::<i64>(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(Span::new("#.\n.#\n").grid(".#"), Ok(vec![b"#.".to_vec(), b".#".to_vec()]));
+        assert_eq!(Span::new("#.\n.#.").grid(".#").unwrap_err().message, "expected a row of 2 tiles, found 3");
+        assert_eq!(Span::new("#.\n.é").grid(".#").unwrap_err().column, 2);
+        assert!(Span::new("").grid(".#").is_err());
+        assert!(Span::new("\n").grid(".#").is_err());
+    }
+}
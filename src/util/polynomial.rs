@@ -0,0 +1,174 @@
+//! Exact polynomial interpolation in newton form
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+use super::Rational;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolynomialError {
+    /// Two points share the same `x`
+    RepeatedNode,
+    /// A divided difference or value does not fit in an `i128` fraction
+    Overflow,
+}
+
+impl Display for PolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::RepeatedNode => "interpolation points share an x coordinate",
+            Self::Overflow => "polynomial arithmetic overflowed",
+        })
+    }
+}
+
+impl Error for PolynomialError {}
+
+/// The minimal-degree polynomial passing through a set of points,
+/// stored as newton divided differences over its interpolation nodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    nodes: Vec<Rational>,
+    coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    /// Fits the minimal-degree polynomial through the provided `(x, y)` points.
+    ///
+    /// # Errors
+    ///
+    /// If any two points share the same `x`, or the divided differences overflow
+    pub fn interpolate<I, X, Y>(points: I) -> Result<Self, PolynomialError>
+    where
+        I: IntoIterator<Item = (X, Y)>,
+        X: Into<Rational>,
+        Y: Into<Rational>,
+    {
+        let (nodes, mut coefficients) = points
+            .into_iter()
+            .map(|(x, y)| (x.into(), y.into()))
+            .unzip::<Rational, Rational, Vec<_>, Vec<_>>();
+
+        for order in 1..nodes.len() {
+            for i in (order..nodes.len()).rev() {
+                let width = nodes[i]
+                    .checked_sub(nodes[i - order])
+                    .ok_or(PolynomialError::Overflow)?;
+                if width.is_zero() {
+                    return Err(PolynomialError::RepeatedNode);
+                }
+                coefficients[i] = coefficients[i]
+                    .checked_sub(coefficients[i - 1])
+                    .and_then(|diff| diff.checked_div(width))
+                    .ok_or(PolynomialError::Overflow)?;
+            }
+        }
+
+        // higher order divided differences vanish past the true degree
+        while coefficients
+            .last()
+            .is_some_and(Rational::is_zero)
+        {
+            coefficients.pop();
+        }
+        Ok(Self { nodes, coefficients })
+    }
+
+    /// Fits the minimal-degree polynomial to a sequence,
+    /// where the `i`th term is the value at `x = i`
+    ///
+    /// # Errors
+    ///
+    /// If the divided differences overflow
+    pub fn fit_sequence<I, Y>(sequence: I) -> Result<Self, PolynomialError>
+    where
+        I: IntoIterator<Item = Y>,
+        Y: Into<Rational>,
+    {
+        Self::interpolate(
+            sequence
+                .into_iter()
+                .zip(0i128..)
+                .map(|(y, x)| (x, y))
+        )
+    }
+
+    /// Returns [`None`] for the zero polynomial
+    #[inline]
+    #[must_use]
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients
+            .len()
+            .checked_sub(1)
+    }
+
+    /// Evaluates the polynomial at any point,
+    /// inside or outside (forwards or backwards) of the fitted range
+    ///
+    /// # Errors
+    ///
+    /// If the value overflows along the way
+    pub fn evaluate<X>(&self, x: X) -> Result<Rational, PolynomialError>
+    where
+        X: Into<Rational>,
+    {
+        let x = x.into();
+        self.coefficients
+            .iter()
+            .zip(&self.nodes)
+            .rev()
+            .try_fold(Rational::ZERO, |acc, (&coefficient, &node)| x
+                .checked_sub(node)
+                .and_then(|offset| acc.checked_mul(offset))
+                .and_then(|acc| acc.checked_add(coefficient))
+            )
+            .ok_or(PolynomialError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolation() {
+        // 2x^2 - 3x + 1
+        let polynomial = Polynomial::fit_sequence([1, 0, 3, 10, 21]).unwrap();
+        assert_eq!(polynomial.degree(), Some(2));
+        assert_eq!(polynomial.evaluate(5), Ok(Rational::from(36)));
+        assert_eq!(polynomial.evaluate(-1), Ok(Rational::from(6)));
+        assert_eq!(polynomial.evaluate(-3), Ok(Rational::from(28)));
+        assert_eq!(polynomial.evaluate(Rational::new(1, 2)), Ok(Rational::ZERO));
+
+        // day 9's example, backwards
+        assert_eq!(Polynomial::fit_sequence([10, 13, 16, 21, 30, 45]).unwrap().evaluate(-1), Ok(Rational::from(5)));
+    }
+
+    #[test]
+    fn test_degree() {
+        // the trailing divided differences are all zero for a line
+        assert_eq!(Polynomial::fit_sequence([3, 5, 7, 9, 11, 13]).unwrap().degree(), Some(1));
+        assert_eq!(Polynomial::fit_sequence([4, 4, 4]).unwrap().degree(), Some(0));
+        assert_eq!(Polynomial::fit_sequence([0, 0, 0]).unwrap().degree(), None);
+        assert_eq!(Polynomial::fit_sequence([0, 0, 0]).unwrap().evaluate(7), Ok(Rational::ZERO));
+        assert_eq!(Polynomial::fit_sequence(Vec::<i64>::new()).unwrap().degree(), None);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let polynomial = Polynomial::interpolate([(-2, 4), (3, 9), (1, 1)]).unwrap();
+        assert_eq!(polynomial.degree(), Some(2));
+        assert_eq!(polynomial.evaluate(-5), Ok(Rational::from(25)));
+        assert_eq!(Polynomial::interpolate([(1, 2), (1, 3)]), Err(PolynomialError::RepeatedNode));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(Polynomial::fit_sequence([i128::MIN + 1, i128::MAX]), Err(PolynomialError::Overflow));
+        // a line that leaves the range of an `i128` further along
+        let line = Polynomial::fit_sequence([0, i128::MAX / 4]).unwrap();
+        assert_eq!(line.evaluate(4), Ok(Rational::from(i128::MAX / 4 * 4)));
+        assert_eq!(line.evaluate(5), Err(PolynomialError::Overflow));
+    }
+}
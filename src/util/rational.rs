@@ -0,0 +1,310 @@
+//! Exact fractions over `i128`, always kept in lowest terms with a positive denominator
+//!
+//! The operators panic on overflow, while the `checked_` methods return [`None`]
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+#[must_use]
+const fn gcd_i128(a: i128, b: i128) -> i128 {
+    if b == 0 { a.abs() } else { gcd_i128(b, a % b) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { num: 0, den: 1 };
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    /// # Panics
+    ///
+    /// If the denominator is zero
+    #[must_use]
+    pub const fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "Rational with a zero denominator");
+        let divisor = gcd_i128(num, den);
+        let sign = if den < 0 { -1 } else { 1 };
+        Self {
+            num: sign * num / divisor,
+            den: sign * den / divisor,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// Returns the value as an integer if the denominator is one
+    #[inline]
+    #[must_use]
+    pub const fn to_integer(&self) -> Option<i128> {
+        if self.den == 1 { Some(self.num) } else { None }
+    }
+
+    /// # Panics
+    ///
+    /// If the value is zero
+    #[inline]
+    #[must_use]
+    pub const fn recip(&self) -> Self {
+        Self::new(self.den, self.num)
+    }
+
+    /// Like [`Rational::new`], but [`None`] if the denominator is zero
+    /// or either part is `i128::MIN`, whose magnitude does not fit in an `i128`
+    #[must_use]
+    pub const fn checked_new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 || num == i128::MIN || den == i128::MIN {
+            None
+        } else {
+            Some(Self::new(num, den))
+        }
+    }
+
+    #[must_use]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.num.checked_neg() {
+            Some(num) => Some(Self { num, den: self.den }),
+            None => None,
+        }
+    }
+
+    /// Returns [`None`] if the sum does not fit in an `i128` fraction
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let divisor = gcd_i128(self.den, other.den);
+        Self::checked_new(
+            self.num
+                .checked_mul(other.den / divisor)?
+                .checked_add(other.num.checked_mul(self.den / divisor)?)?,
+            (self.den / divisor).checked_mul(other.den)?,
+        )
+    }
+
+    /// Returns [`None`] if the difference does not fit in an `i128` fraction
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /// Returns [`None`] if the product does not fit in an `i128` fraction
+    #[must_use]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // cross-reduce first to keep the intermediate products small
+        let left = gcd_i128(self.num.checked_abs()?, other.den).max(1);
+        let right = gcd_i128(other.num.checked_abs()?, self.den).max(1);
+        Self::checked_new(
+            (self.num / left).checked_mul(other.num / right)?,
+            (self.den / right).checked_mul(other.den / left)?,
+        )
+    }
+
+    /// Returns [`None`] if dividing by zero, or the quotient does not fit in an `i128` fraction
+    #[must_use]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        self.checked_mul(other.recip())
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Rational {
+            fn from(num: $int) -> Self {
+                Self { num: i128::from(num), den: 1 }
+            }
+        }
+    )*};
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// Compares `a_num / a_den` with `b_num / b_den`, where both denominators are positive,
+/// by cross-multiplying when that fits in an `i128`,
+/// and otherwise by their integer parts and then the reciprocals of what remains, as in euclid's algorithm
+fn cmp_fractions(a_num: i128, a_den: i128, b_num: i128, b_den: i128) -> Ordering {
+    if let (Some(left), Some(right)) = (a_num.checked_mul(b_den), b_num.checked_mul(a_den)) {
+        return left.cmp(&right);
+    }
+    let (a_int, b_int) = (a_num.div_euclid(a_den), b_num.div_euclid(b_den));
+    if a_int != b_int {
+        return a_int.cmp(&b_int);
+    }
+    match (a_num.rem_euclid(a_den), b_num.rem_euclid(b_den)) {
+        (0, 0) => Ordering::Equal,
+        (0, _) => Ordering::Less,
+        (_, 0) => Ordering::Greater,
+        // the larger remainder has the smaller reciprocal
+        (a_rem, b_rem) => cmp_fractions(b_den, b_rem, a_den, a_rem),
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_fractions(self.num, self.den, other.num, other.den)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the numerator is `i128::MIN`
+    fn neg(self) -> Self {
+        self.checked_neg().expect("Rational overflow")
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the sum does not fit in an `i128` fraction
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("Rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the difference does not fit in an `i128` fraction
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("Rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the product does not fit in an `i128` fraction
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other).expect("Rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If dividing by zero, or the quotient does not fit in an `i128` fraction
+    fn div(self, other: Self) -> Self {
+        Mul::mul(self, other.recip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(Rational::new(6, 4), Rational::new(3, 2));
+        assert_eq!(Rational::new(3, -6), Rational::new(-1, 2));
+        assert_eq!(Rational::new(-3, -6), Rational::new(1, 2));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+
+        let half = Rational::new(-4, -8);
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        let negative = Rational::new(4, -8);
+        assert_eq!((negative.numerator(), negative.denominator()), (-1, 2));
+        assert_eq!(negative.recip(), Rational::from(-2));
+        assert_eq!(Rational::new(10, -5).to_integer(), Some(-2));
+        assert_eq!(negative.to_integer(), None);
+        assert_eq!(negative.to_string(), "-1/2");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Rational::new(1, 6), Rational::new(-3, 4));
+        assert_eq!(a + b, Rational::new(-7, 12));
+        assert_eq!(a - b, Rational::new(11, 12));
+        assert_eq!(a * b, Rational::new(-1, 8));
+        assert_eq!(a / b, Rational::new(-2, 9));
+        assert_eq!(b / b, Rational::ONE);
+        assert_eq!(a.checked_div(Rational::ZERO), None);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = Rational::from(i128::MAX);
+        assert_eq!(big.checked_add(Rational::ONE), None);
+        assert_eq!(big.checked_sub(-Rational::ONE), None);
+        assert_eq!(big.checked_mul(Rational::from(2)), None);
+        assert_eq!(big.checked_div(Rational::new(1, 2)), None);
+        assert_eq!(Rational::new(1, i128::MAX).checked_add(Rational::new(1, i128::MAX - 1)), None);
+        assert_eq!(Rational::from(i128::MIN).checked_neg(), None);
+
+        // reducing first keeps these in range
+        assert_eq!(big.checked_mul(Rational::new(2, i128::MAX)), Some(Rational::from(2)));
+        assert_eq!(big.checked_sub(big), Some(Rational::ZERO));
+        assert_eq!(Rational::new(1, i128::MAX).checked_add(Rational::new(1, i128::MAX)), Some(Rational::new(2, i128::MAX)));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+        assert!(Rational::new(1, -2) < Rational::ZERO);
+        assert!(Rational::new(2, 3) > Rational::new(3, 5));
+
+        // cross-multiplying these overflows an `i128`
+        let big = i128::MAX / 3;
+        assert!(Rational::new(big, big - 1) > Rational::new(big - 1, big - 2).recip());
+        assert!(Rational::new(big - 1, big) < Rational::new(big, big + 1));
+        assert!(Rational::new(-big, big - 1) < Rational::new(-(big - 1), big - 2).recip());
+        assert_eq!(Rational::new(big, big - 1).cmp(&Rational::new(big, big - 1)), Ordering::Equal);
+        assert!(Rational::from(i128::MAX) > Rational::new(i128::MAX, 2));
+    }
+}
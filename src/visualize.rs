@@ -0,0 +1,212 @@
+//! Terminal rendering of grid puzzle states with ANSI colours and highlighted overlays
+
+use std::fmt::{self, Display};
+use crate::{Image, Palette};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    #[must_use]
+    const fn ansi_code(self) -> u8 {
+        match self {
+            Self::Black => 30,
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+            Self::White => 37,
+            Self::Gray => 90,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Option<Color>,
+    pub bold: bool,
+}
+
+impl Cell {
+    #[must_use]
+    pub const fn new(glyph: char) -> Self {
+        Self { glyph, color: None, bold: false }
+    }
+}
+
+/// A grid of styled characters to be drawn to the terminal
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Canvas {
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    /// Creates a blank canvas filled with the glyph
+    #[must_use]
+    pub fn new(n_rows: usize, n_cols: usize, glyph: char) -> Self {
+        Self {
+            rows: vec![vec![Cell::new(glyph); n_cols]; n_rows],
+        }
+    }
+
+    /// Creates a canvas directly from a puzzle's byte grid
+    #[must_use]
+    pub fn from_grid<G>(grid: &[G]) -> Self
+    where
+        G: AsRef<[u8]>,
+    {
+        Self {
+            rows: grid
+                .iter()
+                .map(|row| row
+                    .as_ref()
+                    .iter()
+                    .map(|&tile| Cell::new(tile as char))
+                    .collect()
+                )
+                .collect(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn n_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn n_cols(&self) -> usize {
+        self.rows
+            .first()
+            .map_or(0, Vec::len)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&Cell> {
+        self.rows
+            .get(row)
+            .and_then(|cells| cells.get(col))
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> Option<&mut Cell> {
+        self.rows
+            .get_mut(row)
+            .and_then(|cells| cells.get_mut(col))
+    }
+
+    /// Replaces the glyph of every cell, such as to swap in box-drawing characters
+    pub fn map_glyphs<F>(&mut self, mut map: F)
+    where
+        F: FnMut((usize, usize), char) -> char,
+    {
+        for (i, row) in self.rows
+            .iter_mut()
+            .enumerate()
+        {
+            for (j, cell) in row
+                .iter_mut()
+                .enumerate()
+            {
+                cell.glyph = map((i, j), cell.glyph);
+            }
+        }
+    }
+
+    /// Colours every cell holding one of the glyphs
+    pub fn paint_glyphs(&mut self, glyphs: &[char], color: Color) {
+        for cell in self.rows
+            .iter_mut()
+            .flatten()
+            .filter(|cell| glyphs.contains(&cell.glyph))
+        {
+            cell.color = Some(color);
+        }
+    }
+
+    /// Colours the provided cells, ignoring any out of bounds
+    pub fn paint<I>(&mut self, cells: I, color: Color)
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        for coord in cells {
+            if let Some(cell) = self.get_mut(coord) {
+                cell.color = Some(color);
+            }
+        }
+    }
+
+    /// Colours the provided cells in bold, ignoring any out of bounds
+    pub fn highlight<I>(&mut self, cells: I, color: Color)
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        for coord in cells {
+            if let Some(cell) = self.get_mut(coord) {
+                cell.color = Some(color);
+                cell.bold = true;
+            }
+        }
+    }
+
+    /// Draws the canvas, with ANSI escape codes if `colored`
+    #[must_use]
+    pub fn render(&self, colored: bool) -> String {
+        let mut output = String::new();
+
+        for row in &self.rows {
+            let mut style = (None, false);
+            for cell in row {
+                if colored && (cell.color, cell.bold) != style {
+                    output += "\x1b[0m";
+                    if cell.bold {
+                        output += "\x1b[1m";
+                    }
+                    if let Some(color) = cell.color {
+                        output += &format!("\x1b[{}m", color.ansi_code());
+                    }
+                    style = (cell.color, cell.bold);
+                }
+                output.push(cell.glyph);
+            }
+            if colored && style != (None, false) {
+                output += "\x1b[0m";
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl Display for Canvas {
+    /// Renders with colours, unless the `NO_COLOR` environment variable is set
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(std::env::var_os("NO_COLOR").is_none()))
+    }
+}
+
+pub trait Visualize {
+    /// Draws the puzzle's grid state after solving it
+    fn visualize<T: Display>(&self, inp: T) -> Canvas;
+
+    /// Draws the puzzle's state as an image,
+    /// by default with each cell of [`Self::visualize`] as a `scale` by `scale` block
+    fn rasterize<T: Display>(&self, inp: T, palette: &Palette, scale: usize) -> Image {
+        Image::from_canvas(&self.visualize(inp), palette, scale)
+    }
+}
@@ -0,0 +1,30 @@
+//! Seeds for `wasm32-unknown-unknown`, which has no source of randomness of its own
+//!
+//! Nothing here needs actual randomness, only rustworkx-core's hash map seeds,
+//! which it reads through both versions of `getrandom`:
+//! 0.2 through its `custom` feature and 0.3 through the `getrandom_backend = "custom"` cfg set in `.cargo/config.toml`
+
+use std::slice;
+
+fn fixed_seed(dest: &mut [u8]) -> Result<(), getrandom::Error> {
+    for (i, byte) in dest
+        .iter_mut()
+        .enumerate()
+    {
+        *byte = i.to_le_bytes()[0].wrapping_mul(0x9D);
+    }
+    Ok(())
+}
+
+getrandom::register_custom_getrandom!(fixed_seed);
+
+/// See [`fixed_seed`], under the name getrandom 0.3 looks for
+///
+/// # Safety
+///
+/// `dest` must be valid for writes of `len` bytes, as getrandom guarantees
+#[no_mangle]
+unsafe extern "Rust" fn __getrandom_v03_custom(dest: *mut u8, len: usize) -> Result<(), getrandom_v03::Error> {
+    fixed_seed(slice::from_raw_parts_mut(dest, len))
+        .map_err(|_| getrandom_v03::Error::UNSUPPORTED)
+}
@@ -0,0 +1,95 @@
+// JS bindings for the solutions built by `cargo build --release` in this directory,
+// see `src/lib.rs` for how strings cross into and out of the module
+
+const STATUS_SOLVED = 0;
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+export class SolveError extends Error {
+    constructor(message, { day, part, cause } = {}) {
+        super(message, { cause });
+        this.name = "SolveError";
+        this.day = day;
+        this.part = part;
+    }
+}
+
+/**
+ * Loads the solutions from the bytes of `aoc_2023_wasm.wasm`, a `Response` fetching them,
+ * or an already compiled `WebAssembly.Module`
+ *
+ * @param {BufferSource | Response | Promise<Response> | WebAssembly.Module} source
+ * @returns {Promise<Solver>}
+ */
+export async function load(source) {
+    source = await source;
+    let module;
+    if (source instanceof WebAssembly.Module) {
+        module = source;
+    } else if (typeof Response !== "undefined" && source instanceof Response) {
+        module = await WebAssembly.compileStreaming(source);
+    } else {
+        module = await WebAssembly.compile(source);
+    }
+    return new Solver(module, await WebAssembly.instantiate(module));
+}
+
+export class Solver {
+    #module;
+    #exports;
+
+    constructor(module, instance) {
+        this.#module = module;
+        this.#exports = instance.exports;
+    }
+
+    /**
+     * The solved days with their puzzle names, in order
+     *
+     * @returns {{ day: number, name: string }[]}
+     */
+    listDays() {
+        return JSON.parse(this.#result(this.#exports.list_days()));
+    }
+
+    /**
+     * Solves the part of the day for the input, returning the answer as a string,
+     * as answers can be too large for a `number`
+     *
+     * @param {number} day
+     * @param {1 | 2} part
+     * @param {string} input
+     * @returns {string}
+     * @throws {SolveError} If the day has no solution, the part is invalid or the solution panics
+     */
+    solve(day, part, input) {
+        const bytes = encoder.encode(input);
+        // `solve` takes ownership of the buffer, even if it fails
+        const ptr = this.#exports.alloc(bytes.length);
+        new Uint8Array(this.#exports.memory.buffer, ptr, bytes.length).set(bytes);
+
+        let status;
+        try {
+            status = this.#exports.solve(day, part, ptr, bytes.length);
+        } catch (err) {
+            if (!(err instanceof WebAssembly.RuntimeError)) {
+                throw err;
+            }
+            // the panic hook has left its message, but the instance is unusable after a trap
+            const message = this.#result(this.#exports.result_len());
+            this.#exports = new WebAssembly.Instance(this.#module).exports;
+            throw new SolveError(message || err.message, { day, part, cause: err });
+        }
+
+        const result = this.#result(this.#exports.result_len());
+        if (status !== STATUS_SOLVED) {
+            throw new SolveError(result, { day, part });
+        }
+        return result;
+    }
+
+    #result(len) {
+        return decoder.decode(new Uint8Array(this.#exports.memory.buffer, this.#exports.result_ptr(), len));
+    }
+}
@@ -0,0 +1,128 @@
+//! The solutions built for `wasm32-unknown-unknown`, for embedding in web pages through `aoc-2023.mjs`
+//!
+//! Strings cross into the module through its memory:
+//! the caller writes the input into a buffer from [`alloc`], which [`solve`] takes ownership of,
+//! then reads the answer, the days or an error message back from [`result_ptr`] and [`result_len`].
+//! A panicking solver traps, leaving its message as the result.
+//!
+//! The days and their registry are the library's own, see [`aoc_2023::days`]
+
+use std::{
+    panic,
+    ptr,
+    sync::{Mutex, Once},
+};
+use aoc_2023::{days, solve_part, Part};
+use serde::Serialize;
+
+/// Whatever [`solve`] or [`list_days`] last wrote out
+static RESULT: Mutex<String> = Mutex::new(String::new());
+
+fn set_result(result: String) {
+    *RESULT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = result;
+}
+
+/// What [`solve`] returns, with its result being either the answer or why there is none
+#[repr(u32)]
+pub enum Status {
+    Solved = 0,
+    NotFound = 1,
+    Invalid = 2,
+}
+
+/// Room for `len` bytes, to be passed to [`solve`] or freed with [`dealloc`]
+#[no_mangle]
+pub extern "C" fn alloc(len: usize) -> *mut u8 {
+    Box::into_raw(vec![0_u8; len].into_boxed_slice()).cast()
+}
+
+/// # Safety
+///
+/// The buffer must come from [`alloc`] with the same length, and not be used again
+#[no_mangle]
+pub unsafe extern "C" fn dealloc(ptr: *mut u8, len: usize) {
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(ptr, len)));
+}
+
+#[no_mangle]
+pub extern "C" fn result_ptr() -> *const u8 {
+    RESULT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .as_ptr()
+}
+
+#[no_mangle]
+pub extern "C" fn result_len() -> usize {
+    RESULT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .len()
+}
+
+/// An entry of [`list_days`]
+#[derive(Serialize)]
+struct ListedDay {
+    day: u8,
+    name: &'static str,
+}
+
+/// Writes out the solved days as JSON, `[{"day":1,"name":"Trebuchet?!"},...]`, returning its length
+#[no_mangle]
+pub extern "C" fn list_days() -> usize {
+    let days = days()
+        .into_iter()
+        .map(|(day, name)| ListedDay { day, name })
+        .collect::<Vec<ListedDay>>();
+    set_result(serde_json::to_string(&days).expect("Days always serialize"));
+    result_len()
+}
+
+/// Solves the part of the day for the UTF-8 input, writing out its answer
+///
+/// # Safety
+///
+/// The input must come from [`alloc`] with the same length, and not be used again
+#[no_mangle]
+pub unsafe extern "C" fn solve(day: u32, part: u32, input: *mut u8, len: usize) -> Status {
+    static PANIC_HOOK: Once = Once::new();
+    PANIC_HOOK.call_once(|| panic::set_hook(Box::new(|info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| info.to_string());
+        set_result(message);
+    })));
+
+    let input = Box::from_raw(ptr::slice_from_raw_parts_mut(input, len));
+    let Ok(input) = String::from_utf8(input.into_vec()) else {
+        set_result("The input is not valid UTF-8".to_string());
+        return Status::Invalid;
+    };
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => {
+            set_result(format!("Invalid part `{part}`, expected 1 or 2"));
+            return Status::Invalid;
+        },
+    };
+
+    match u8::try_from(day)
+        .ok()
+        .and_then(|day| solve_part(day, part, &input))
+    {
+        Some(answer) => {
+            set_result(answer.to_string());
+            Status::Solved
+        },
+        None => {
+            set_result(format!("Solution does not exist yet for day {day}"));
+            Status::NotFound
+        },
+    }
+}
@@ -0,0 +1,94 @@
+// Checks that the wasm build reproduces the native answers for every day with an input in `inputs/`
+//
+// Build both first, then run from the repository root:
+//
+//     cargo build --release
+//     (cd wasm && cargo build --release)
+//     node wasm/test.mjs
+//
+// Both builds are looked for under `$CARGO_TARGET_DIR` if set,
+// and in `target/` and `wasm/target/` otherwise
+
+import { execFileSync } from "node:child_process";
+import { existsSync, readFileSync } from "node:fs";
+import { dirname, join } from "node:path";
+import { fileURLToPath } from "node:url";
+import { load } from "./aoc-2023.mjs";
+
+const root = join(dirname(fileURLToPath(import.meta.url)), "..");
+const targetDir = process.env.CARGO_TARGET_DIR;
+const nativeDir = join(targetDir ?? join(root, "target"), "release");
+const wasmPath = join(
+    targetDir ?? join(root, "wasm", "target"),
+    "wasm32-unknown-unknown",
+    "release",
+    "aoc_2023_wasm.wasm",
+);
+
+/**
+ * The native answer, kept as a string as it can be too large for a `number`,
+ * or `null` if the native solution fails
+ */
+function nativeAnswer(day, part, inputPath) {
+    let output;
+    try {
+        output = execFileSync(
+            join(nativeDir, `day${day}`),
+            ["--format", "json", "--part", `${part}`, "--input", inputPath],
+            { cwd: root, encoding: "utf8", stdio: ["ignore", "pipe", "ignore"] },
+        );
+    } catch {
+        return null;
+    }
+    const key = part === 1 ? "part_one" : "part_two";
+    const answer = output.match(new RegExp(`"${key}":(\\d+)`));
+    if (!answer) {
+        throw new Error(`No answer to part ${part} in the native output of day ${day}: ${output}`);
+    }
+    return answer[1];
+}
+
+const solver = await load(readFileSync(wasmPath));
+let failures = 0;
+let checked = 0;
+
+for (const { day, name } of solver.listDays()) {
+    const inputPath = join("inputs", `day${day}.txt`);
+    if (!existsSync(join(root, inputPath))) {
+        console.log(`Day ${day}: skipped, no input`);
+        continue;
+    }
+    const input = readFileSync(join(root, inputPath), "utf8");
+
+    for (const part of [1, 2]) {
+        const expected = nativeAnswer(day, part, inputPath);
+        let actual;
+        try {
+            actual = solver.solve(day, part, input);
+        } catch (err) {
+            // both failing, such as on a part without a solution, is a match
+            actual = expected === null ? null : `error: ${err.message}`;
+        }
+        checked++;
+        if (actual === expected) {
+            console.log(`Day ${day} part ${part} (${name}): ${actual ?? "fails natively and in wasm"}`);
+        } else {
+            failures++;
+            console.log(`Day ${day} part ${part} (${name}): expected ${expected ?? "a failure"}, got ${actual}`);
+        }
+    }
+}
+
+for (const [day, part, input] of [[0, 1, ""], [1, 3, ""]]) {
+    checked++;
+    try {
+        solver.solve(day, part, input);
+        failures++;
+        console.log(`Day ${day} part ${part}: expected an error`);
+    } catch (err) {
+        console.log(`Day ${day} part ${part}: ${err.message}`);
+    }
+}
+
+console.log(`${checked - failures}/${checked} passed`);
+process.exitCode = failures === 0 ? 0 : 1;